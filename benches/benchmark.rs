@@ -0,0 +1,229 @@
+//! 基准测试
+//! * 🎯为「以性能为目的的改动」提供可比较的数据
+//! * 📌报告的指标
+//!   * 📍每秒周期数
+//!   * 📍每周期导出的任务数（取自推理器的[运行统计](ReasonerStatistics)，不受音量、输出订阅影响）
+//!   * 📍记忆区峰值规模（概念数、信念数）
+//!   * 📍「直接推理」「概念推理」两阶段的耗时（取自推理器的[运行统计](ReasonerStatistics)）
+//!   * 📍推理引擎各入口函数的耗时：直接推理、转换推理、匹配推理、概念推理
+//! * 📌标准工作负载
+//!   * 📍NAL-1~6：逐组运行「逻辑稳定性」中的NAL示例
+//!   * 📍合成本体：以固定种子生成的大型继承层级
+//! * 🚩运行方式：`cargo bench`；可附加工作负载名的片段以筛选，如`cargo bench -- NAL-5`
+//! * ℹ️推理器内部的随机数生成器亦使用固定种子，结果可重复
+
+use anyhow::Result;
+use narust_158::{
+    control::{
+        ReasonContextConcept, ReasonContextDirect, ReasonContextTransform, Reasoner,
+        ReasonerStatistics,
+    },
+    inference::{match_task_and_belief, process_direct, reason, transform_task, InferenceEngine},
+    parameters::DEFAULT_PARAMETERS,
+};
+use navm::cmd::Cmd;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{
+    cell::RefCell,
+    time::{Duration, Instant},
+};
+
+#[path = "../src/inference/tests/nal_examples.rs"]
+mod nal_examples;
+
+/// 推理引擎各入口函数的名称
+/// * 📌顺序与[`EngineTimers`]一致
+const ENGINE_ENTRY_NAMES: [&str; 4] = ["direct", "transform", "matching", "reason"];
+
+/// 推理引擎各入口函数的累计耗时
+type EngineTimers = [Duration; 4];
+
+thread_local! {
+    /// 当前线程中，推理引擎各入口函数的累计耗时
+    /// * 🚩推理引擎只接受函数指针，无法捕获计时器⇒使用线程局部变量
+    static ENGINE_TIMERS: RefCell<EngineTimers> = const { RefCell::new([Duration::ZERO; 4]) };
+}
+
+/// 为入口函数计时
+fn timed(index: usize, f: impl FnOnce()) {
+    let start = Instant::now();
+    f();
+    let elapsed = start.elapsed();
+    ENGINE_TIMERS.with_borrow_mut(|timers| timers[index] += elapsed);
+}
+
+/// 带计时的推理引擎
+/// * 🚩调用与标准推理引擎相同的入口函数，额外记录各自的耗时
+const ENGINE_TIMED: InferenceEngine = {
+    fn direct(context: &mut ReasonContextDirect) {
+        timed(0, || process_direct(context))
+    }
+    fn transform(context: &mut ReasonContextTransform) {
+        timed(1, || transform_task(context))
+    }
+    fn matching(context: &mut ReasonContextConcept) {
+        timed(2, || match_task_and_belief(context))
+    }
+    fn concept_reason(context: &mut ReasonContextConcept) {
+        timed(3, || reason(context))
+    }
+    InferenceEngine::new(direct, transform, matching, concept_reason)
+};
+
+/// 一次基准测试的结果
+#[derive(Debug, Clone, Default)]
+struct BenchmarkResult {
+    /// 推理器的运行统计：周期数、导出任务数、各阶段耗时
+    statistics: ReasonerStatistics,
+    /// 峰值概念数
+    peak_concepts: usize,
+    /// 峰值信念数
+    peak_beliefs: usize,
+    /// 周期总耗时
+    cycle_time: Duration,
+    /// 推理引擎各入口函数的耗时
+    engine_time: EngineTimers,
+}
+
+impl BenchmarkResult {
+    fn cycles_per_second(&self) -> f64 {
+        self.statistics.cycles as f64 / self.cycle_time.as_secs_f64()
+    }
+
+    fn derivations_per_cycle(&self) -> f64 {
+        self.statistics.derived_tasks as f64 / self.statistics.cycles as f64
+    }
+
+    /// 打印结果
+    fn print(&self, name: &str) {
+        let percent = |d: Duration| 100.0 * d.as_secs_f64() / self.cycle_time.as_secs_f64();
+        println!("[{name}]");
+        println!(
+            "  cycles: {} in {:.3}s ({:.1} cycles/s)",
+            self.statistics.cycles,
+            self.cycle_time.as_secs_f64(),
+            self.cycles_per_second()
+        );
+        println!(
+            "  derived tasks: {} ({:.3} per cycle)",
+            self.statistics.derived_tasks,
+            self.derivations_per_cycle()
+        );
+        println!(
+            "  peak memory: {} concepts, {} beliefs",
+            self.peak_concepts, self.peak_beliefs
+        );
+        let phases = [
+            ("process_direct", self.statistics.direct_time),
+            ("process_reason", self.statistics.reason_time),
+        ];
+        for (name, time) in phases {
+            println!(
+                "  {name}: {:.3}s ({:.1}%)",
+                time.as_secs_f64(),
+                percent(time)
+            );
+        }
+        for (name, time) in ENGINE_ENTRY_NAMES.iter().zip(self.engine_time) {
+            println!(
+                "    engine.{name}: {:.3}s ({:.1}%)",
+                time.as_secs_f64(),
+                percent(time)
+            );
+        }
+    }
+}
+
+/// 记录记忆区的峰值规模
+fn sample_memory(reasoner: &Reasoner, result: &mut BenchmarkResult) {
+    let memory = reasoner.memory();
+    let concepts = memory.iter_concepts().count();
+    let beliefs = memory.iter_concepts().map(|c| c.beliefs().count()).sum();
+    result.peak_concepts = result.peak_concepts.max(concepts);
+    result.peak_beliefs = result.peak_beliefs.max(beliefs);
+}
+
+/// 运行一个工作负载
+/// * 🚩逐行输入NAVM指令；仅统计`CYC`指令的耗时
+/// * 📌推理器保持静音：输出不影响统计
+fn run_workload(inputs: impl AsRef<str>) -> Result<BenchmarkResult> {
+    let mut reasoner = Reasoner::new("benchmark", DEFAULT_PARAMETERS, ENGINE_TIMED);
+    ENGINE_TIMERS.set([Duration::ZERO; 4]);
+    let mut result = BenchmarkResult::default();
+    for line in inputs.as_ref().lines().map(str::trim) {
+        if line.is_empty() {
+            continue;
+        }
+        match Cmd::parse(line)? {
+            Cmd::NSE(task) => reasoner.input_task(task),
+            Cmd::CYC(steps) => {
+                let start = Instant::now();
+                reasoner.cycle(steps);
+                result.cycle_time += start.elapsed();
+                sample_memory(&reasoner, &mut result);
+            }
+            cmd => return Err(anyhow::anyhow!("Unsupported workload command: {cmd}")),
+        }
+        // * 🚩丢弃输出，避免其在推理器中堆积
+        while reasoner.take_output().is_some() {}
+    }
+    result.statistics = *reasoner.statistics();
+    result.engine_time = ENGINE_TIMERS.take();
+    Ok(result)
+}
+
+/// 合成本体的生成种子
+const SYNTHETIC_ONTOLOGY_SEED: u64 = 0x137442;
+
+/// 生成「合成本体」工作负载
+/// * 🚩随机的继承层级 + 随机的属性 + 对层级的提问
+/// * 📌固定种子⇒每次生成的输入相同
+fn synthetic_ontology(n_categories: usize, n_properties: usize, n_questions: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(SYNTHETIC_ONTOLOGY_SEED);
+    let mut lines = vec![];
+    for i in 1..n_categories {
+        let parent = rng.gen_range(0..i);
+        lines.push(format!("nse <c{i} --> c{parent}>."));
+        if i % 20 == 0 {
+            lines.push("cyc 10".into());
+        }
+    }
+    for _ in 0..n_properties {
+        let category = rng.gen_range(0..n_categories);
+        let property = rng.gen_range(0..n_properties / 4 + 1);
+        lines.push(format!("nse <c{category} --> [p{property}]>."));
+    }
+    for _ in 0..n_questions {
+        let category = rng.gen_range(1..n_categories);
+        lines.push(format!("nse <c{category} --> c0>?"));
+        lines.push("cyc 20".into());
+    }
+    lines.push("cyc 500".into());
+    lines.join("\n")
+}
+
+/// 所有标准工作负载
+/// * 🚩NAL-1~6：每组示例在同一推理器中运行，每个示例后运行50步
+fn workloads() -> impl Iterator<Item = (&'static str, String)> {
+    let nal_groups = nal_examples::nal_test_groups().map(|(name, tests)| {
+        let inputs = tests.iter().map(|test| format!("{test}\ncyc 50\n"));
+        (name, inputs.collect())
+    });
+    let synthetic = ("synthetic ontology", synthetic_ontology(1000, 600, 50));
+    nal_groups.chain([synthetic])
+}
+
+/// 入口
+/// * 🚩命令行中不以`--`开头的参数⇒按名称片段筛选工作负载
+///   * 📌`cargo bench`会附加`--bench`参数，故忽略以`--`开头者
+fn main() -> Result<()> {
+    let filters = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("--"))
+        .collect::<Vec<_>>();
+    let selected = |name: &str| filters.is_empty() || filters.iter().any(|f| name.contains(f));
+    for (name, inputs) in workloads().filter(|(name, _)| selected(name)) {
+        run_workload(inputs)?.print(name);
+    }
+    Ok(())
+}
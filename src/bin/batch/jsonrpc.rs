@@ -0,0 +1,386 @@
+//! 批处理程序的「JSON-RPC」模式
+//! * 🎯让编辑器插件、类LSP客户端以结构化的请求/响应驱动推理器，而无需解析文本
+//! * 🚩启用方式：`narust_158_batch --jsonrpc`
+//! * 📌协议：JSON-RPC 2.0，每行一个JSON值
+//!   * 📍请求：`{"jsonrpc":"2.0","id":1,"method":"tell","params":{"narsese":"<A --> B>."}}`
+//!   * 📍响应：`{"jsonrpc":"2.0","id":1,"result":{"outputs":[...]}}`，以`id`与请求对应
+//!   * 📍通知：`{"jsonrpc":"2.0","method":"output","params":{...}}`，承载推理器派生的`OUT`、`ANSWER`等输出
+//!     * ℹ️通知先于对应请求的响应发出
+//!   * 📍无`id`的请求视作通知：照常执行，但不发送响应
+//!     * ⚠️仅限格式有效的请求：格式无效者无论有无`id`，均以`"id": null`响应错误
+//!   * 📍不支持批量请求：JSON数组⇒响应「请求无效」
+//!
+//! ## 方法
+//!
+//! | 方法 | 参数 | 对应NAVM指令 |
+//! | :-- | :-- | :-- |
+//! | `tell` | `narsese`：判断/目标 | `NSE` |
+//! | `ask` | `narsese`：问题 | `NSE` |
+//! | `cycle` | `steps`（默认为1） | `CYC` |
+//! | `info` | `query`（默认为空） | `INF` |
+//! | `save` | `target`、`path`（可选） | `SAV` |
+//! | `load` | `target`、`path`或`data` | `LOA` |
+//!
+//! * 📌`save`未指定路径时，所保存的数据放在响应的`data`字段中
+
+use super::{batch_intercept_output, try_load_file_content};
+use anyhow::Result;
+use narsese::{
+    conversion::string::impl_lexical::format_instances::FORMAT_ASCII, lexical::Task as LexicalTask,
+};
+use narust_158::vm::alpha::SavCallback;
+use navm::{cmd::Cmd, output::Output, vm::VmRuntime};
+use serde_json::{json, Map, Value};
+
+/// JSON-RPC标准错误码：无法解析JSON
+const PARSE_ERROR: i64 = -32700;
+/// JSON-RPC标准错误码：请求格式无效
+const INVALID_REQUEST: i64 = -32600;
+/// JSON-RPC标准错误码：方法不存在
+const METHOD_NOT_FOUND: i64 = -32601;
+/// JSON-RPC标准错误码：参数无效
+const INVALID_PARAMS: i64 = -32602;
+/// 自定义错误码：推理器报错
+/// * 📌推理器输出了`ERROR`
+const REASONER_ERROR: i64 = -32000;
+
+/// 一次请求处理中的错误
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// JSON-RPC模式的主循环
+/// * 🚩逐行读取请求，逐行写出通知与响应
+pub fn jsonrpc(
+    mut runtime: impl VmRuntime,
+    inputs: impl Iterator<Item = Result<Option<String>>>,
+) -> Result<()> {
+    for input in inputs {
+        // EOF⇒结束
+        let Some(input) = input? else { break };
+        let input = input.trim();
+        if input.is_empty() {
+            continue;
+        }
+        for message in handle_request(&mut runtime, input) {
+            println!("{message}");
+        }
+    }
+    Ok(())
+}
+
+/// 处理一行请求，返回所有待发送的消息（通知与响应）
+fn handle_request(runtime: &mut impl VmRuntime, input: &str) -> Vec<Value> {
+    // 解析JSON
+    let request = match serde_json::from_str::<Value>(input) {
+        Ok(request) => request,
+        Err(e) => return vec![error_response(Value::Null, PARSE_ERROR, e.to_string())],
+    };
+    // 检查请求格式
+    // * 🚩格式无效⇒总是响应错误：此时无法判断是否为通知
+    let (id, method, params) = match parse_request(&request) {
+        Ok(parsed) => parsed,
+        Err((id, RpcError { code, message })) => return vec![error_response(id, code, message)],
+    };
+    let empty = Map::new();
+    let params = params.unwrap_or(&empty);
+    // 不带`id`⇒通知：不发送响应
+    let mut messages = vec![];
+    let result = execute(runtime, method, params, &mut messages);
+    if let Some(id) = id {
+        messages.push(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(RpcError { code, message }) => error_response(id, code, message),
+        });
+    }
+    messages
+}
+
+/// 格式有效的请求：`id`、方法、参数（可选）
+type ParsedRequest<'a> = (Option<Value>, &'a str, Option<&'a Map<String, Value>>);
+
+/// 检查请求格式，拆分出`id`、方法与参数
+/// * 📌`id`：无⇒通知
+/// * 🚩格式无效⇒返回错误，以及响应所用的`id`（无法确定时为`null`）
+fn parse_request(request: &Value) -> Result<ParsedRequest<'_>, (Value, RpcError)> {
+    let invalid = |id: Option<&Value>, message: &str| {
+        let id = id.cloned().unwrap_or(Value::Null);
+        Err((id, RpcError::new(INVALID_REQUEST, message)))
+    };
+    let request = match request {
+        Value::Object(request) => request,
+        Value::Array(..) => return invalid(None, "Batch requests are not supported"),
+        _ => return invalid(None, "Request must be an object"),
+    };
+    let id = match request.get("id") {
+        None => None,
+        Some(id @ (Value::Null | Value::Number(..) | Value::String(..))) => Some(id),
+        Some(_) => return invalid(None, "`id` must be a string, number or null"),
+    };
+    if request.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+        return invalid(id, "`jsonrpc` must be \"2.0\"");
+    }
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        return invalid(id, "Missing method");
+    };
+    let params = match request.get("params") {
+        None => None,
+        Some(Value::Object(params)) => Some(params),
+        Some(_) => {
+            let id = id.cloned().unwrap_or(Value::Null);
+            let error = RpcError::new(INVALID_PARAMS, "Params must be an object");
+            return Err((id, error));
+        }
+    };
+    Ok((id.cloned(), method, params))
+}
+
+/// 执行请求：转换成NAVM指令，输入推理器，并整理输出
+/// * 🚩派生的输出⇒作为通知加入`messages`
+/// * 🚩其余输出⇒作为响应结果返回
+fn execute(
+    runtime: &mut impl VmRuntime,
+    method: &str,
+    params: &Map<String, Value>,
+    messages: &mut Vec<Value>,
+) -> Result<Value, RpcError> {
+    // 输入指令
+    let cmd = method_to_cmd(method, params)?;
+    runtime
+        .input_cmd(cmd)
+        .map_err(|e| RpcError::new(REASONER_ERROR, e.to_string()))?;
+    // 拉取输出
+    let mut outputs = vec![];
+    let mut data = None;
+    let mut errors = vec![];
+    while let Ok(Some(output)) = runtime.try_fetch_output() {
+        // 未指定路径的「SAV」回调⇒放入`data`字段
+        let output = match output.try_into_sav_callback() {
+            Ok((path, saved)) if path.is_empty() => {
+                data = Some(saved);
+                continue;
+            }
+            Ok((path, saved)) => Output::format_sav_callback(path, saved),
+            Err(output) => output,
+        };
+        let Ok(Some(output)) = batch_intercept_output(output) else {
+            continue;
+        };
+        match output {
+            // 派生的输出⇒通知
+            Output::OUT { .. }
+            | Output::ANSWER { .. }
+            | Output::ACHIEVED { .. }
+            | Output::EXE { .. } => messages.push(json!({
+                "jsonrpc": "2.0",
+                "method": "output",
+                "params": output,
+            })),
+            // 报错⇒记录
+            Output::ERROR { ref description } => {
+                errors.push(description.clone());
+                outputs.push(output);
+            }
+            // 其它⇒响应结果
+            _ => outputs.push(output),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(RpcError::new(REASONER_ERROR, errors.join("\n")));
+    }
+    let mut result = json!({ "outputs": outputs });
+    if let Some(data) = data {
+        result["data"] = Value::String(data);
+    }
+    Ok(result)
+}
+
+/// 将方法与参数转换为NAVM指令
+fn method_to_cmd(method: &str, params: &Map<String, Value>) -> Result<Cmd, RpcError> {
+    let cmd = match method {
+        "tell" => {
+            let task = param_narsese(params)?;
+            if task.sentence.punctuation == "?" {
+                return Err(RpcError::new(INVALID_PARAMS, "Use `ask` for questions"));
+            }
+            Cmd::NSE(task)
+        }
+        "ask" => {
+            let task = param_narsese(params)?;
+            if task.sentence.punctuation != "?" {
+                return Err(RpcError::new(INVALID_PARAMS, "`ask` expects a question"));
+            }
+            Cmd::NSE(task)
+        }
+        "cycle" => {
+            let steps = match params.get("steps") {
+                None => 1,
+                Some(steps) => steps
+                    .as_u64()
+                    .ok_or_else(|| RpcError::new(INVALID_PARAMS, "`steps` must be a number"))?,
+            };
+            Cmd::CYC(steps as usize)
+        }
+        "info" => Cmd::INF {
+            source: param_str(params, "query")?.unwrap_or_default(),
+        },
+        "save" => Cmd::SAV {
+            target: param_target(params)?,
+            path: param_str(params, "path")?.unwrap_or_default(),
+        },
+        "load" => {
+            let target = param_target(params)?;
+            // * 🚩路径⇒读取文件内容；否则直接使用数据
+            let data = match (param_str(params, "path")?, param_str(params, "data")?) {
+                (Some(path), _) => try_load_file_content(path)
+                    .map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))?,
+                (None, Some(data)) => data,
+                (None, None) => {
+                    return Err(RpcError::new(INVALID_PARAMS, "Missing `path` or `data`"))
+                }
+            };
+            Cmd::LOA { target, path: data }
+        }
+        other => {
+            return Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Unknown method: {other:?}"),
+            ))
+        }
+    };
+    Ok(cmd)
+}
+
+/// 获取可选的字符串参数
+fn param_str(params: &Map<String, Value>, name: &str) -> Result<Option<String>, RpcError> {
+    match params.get(name) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.clone())),
+        Some(_) => Err(RpcError::new(
+            INVALID_PARAMS,
+            format!("`{name}` must be a string"),
+        )),
+    }
+}
+
+/// 获取必需的「目标」参数
+fn param_target(params: &Map<String, Value>) -> Result<String, RpcError> {
+    param_str(params, "target")?.ok_or_else(|| RpcError::new(INVALID_PARAMS, "Missing `target`"))
+}
+
+/// 获取并解析必需的Narsese任务参数
+fn param_narsese(params: &Map<String, Value>) -> Result<LexicalTask, RpcError> {
+    let narsese = param_str(params, "narsese")?
+        .ok_or_else(|| RpcError::new(INVALID_PARAMS, "Missing `narsese`"))?;
+    FORMAT_ASCII
+        .parse(&narsese)
+        .map_err(|e| e.to_string())
+        .and_then(|value| {
+            value
+                .try_into_task_compatible()
+                .map_err(|_| "Not a task".into())
+        })
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid Narsese {narsese:?}: {e}")))
+}
+
+/// 构造错误响应
+fn error_response(id: Value, code: i64, message: impl Into<String>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message.into() },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_launcher;
+    use navm::vm::VmLauncher;
+
+    /// 处理一行请求，返回所有消息
+    fn request(input: &str) -> Vec<Value> {
+        let mut runtime = create_launcher().launch().unwrap();
+        handle_request(&mut runtime, input)
+    }
+
+    /// 获取唯一一条消息中的错误码与`id`
+    fn error_of(messages: &[Value]) -> (i64, Value) {
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        let message = &messages[0];
+        (
+            message["error"]["code"].as_i64().unwrap(),
+            message["id"].clone(),
+        )
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(error_of(&request("{")), (PARSE_ERROR, Value::Null));
+    }
+
+    #[test]
+    fn invalid_request() {
+        // * 🚩格式无效⇒即便没有`id`，也以`null`响应
+        for input in [
+            "[1,2]",
+            "[]",
+            "42",
+            r#"{"jsonrpc":"2.0"}"#,
+            r#"{"jsonrpc":"1.0","method":"cycle"}"#,
+            r#"{"jsonrpc":"2.0","method":"cycle","id":{}}"#,
+        ] {
+            assert_eq!(
+                error_of(&request(input)),
+                (INVALID_REQUEST, Value::Null),
+                "{input}"
+            );
+        }
+        // * 🚩有`id`⇒以之响应
+        let messages = request(r#"{"jsonrpc":"2.0","id":7}"#);
+        assert_eq!(error_of(&messages), (INVALID_REQUEST, json!(7)));
+        // * 🚩参数不是对象⇒参数无效
+        let messages = request(r#"{"jsonrpc":"2.0","method":"cycle","params":[1]}"#);
+        assert_eq!(error_of(&messages), (INVALID_PARAMS, Value::Null));
+    }
+
+    #[test]
+    fn notification() {
+        // * 🚩格式有效、没有`id`⇒照常执行，不响应
+        assert!(request(r#"{"jsonrpc":"2.0","method":"cycle"}"#).is_empty());
+        // * 🚩执行出错亦不响应
+        assert!(request(r#"{"jsonrpc":"2.0","method":"unknown"}"#).is_empty());
+    }
+
+    #[test]
+    fn call() {
+        let messages = request(r#"{"jsonrpc":"2.0","id":1,"method":"cycle","params":{"steps":2}}"#);
+        let response = messages.last().unwrap();
+        assert_eq!(response["id"], json!(1));
+        assert!(response["result"]["outputs"].is_array(), "{response}");
+        // * 🚩方法不存在
+        let messages = request(r#"{"jsonrpc":"2.0","id":"a","method":"unknown"}"#);
+        assert_eq!(error_of(&messages), (METHOD_NOT_FOUND, json!("a")));
+        // * 🚩问题不能用`tell`
+        let input = r#"{"jsonrpc":"2.0","id":2,"method":"tell","params":{"narsese":"<A --> B>?"}}"#;
+        assert_eq!(error_of(&request(input)), (INVALID_PARAMS, json!(2)));
+    }
+
+    #[test]
+    fn save_without_path() {
+        let messages =
+            request(r#"{"jsonrpc":"2.0","id":1,"method":"save","params":{"target":"memory"}}"#);
+        let response = messages.last().unwrap();
+        assert!(response["result"]["data"].is_string(), "{response}");
+    }
+}
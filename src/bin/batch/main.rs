@@ -1,17 +1,24 @@
 //! 标准NAVM「批处理」程序
 //! * 🚩以[NAVM指令](Cmd)作为输入，以JSON格式输出[NAVM输出](Output)
 //! * 🎯对接BabelNAR「原生转译器」接口
+//! * 🚩`--jsonrpc`：以JSON-RPC请求/响应的方式交互，参见[`jsonrpc`]
+//! * 🚩`--auto-step [每秒周期数]`：推理器在后台持续运行，输出随时打印
 
 use anyhow::Result;
 use narust_158::{
     inference::{match_task_and_belief, process_direct, reason, transform_task, InferenceEngine},
     parameters::DEFAULT_PARAMETERS,
-    vm::alpha::{LauncherAlpha, SavCallback},
+    vm::alpha::{self, AutoStepConfig, FileAccess, LauncherAlpha, RuntimeAlphaAuto},
 };
 use navm::{cmd::Cmd, output::Output, vm::VmLauncher, vm::VmRuntime};
-use std::{io::Write, path::Path};
+use std::{
+    sync::mpsc::{channel, RecvTimeoutError},
+    time::Duration,
+};
+
+mod jsonrpc;
 
-fn create_runtime() -> Result<impl VmRuntime> {
+fn create_launcher() -> LauncherAlpha {
     // * 🚩【2024-07-09 16:52:40】目前除了「概念推理」均俱全
     const ENGINE: InferenceEngine = InferenceEngine::new(
         process_direct,
@@ -19,8 +26,7 @@ fn create_runtime() -> Result<impl VmRuntime> {
         match_task_and_belief,
         reason,
     );
-    let vm = LauncherAlpha::new("demo_158", DEFAULT_PARAMETERS, ENGINE);
-    vm.launch()
+    LauncherAlpha::new("demo_158", DEFAULT_PARAMETERS, ENGINE)
 }
 
 fn batch(
@@ -60,89 +66,117 @@ fn batch(
     }
 }
 
-/// 从输入中「提前解释」指令
-/// * 💡可以从中对指令作预处理
-///   * 📄绕过生硬的NAVM指令语法，像OpenNARS那样直接输入Narsese与推理步数
-///   * 📄截获解析出的`SAV` `LOA`等指令，解释为其它指令语法
-///     * 💡如：`LOA`指令⇒前端请求文件并读取内容⇒内联到新的`LOA`中⇒虚拟机Alpha实现内容加载
-fn interpret_cmd(input: &str) -> Option<Cmd> {
-    // 尝试作为普通NAVM指令解析
-    if let Ok(cmd) = Cmd::parse(input) {
-        match cmd {
-            // `LOA`指令转译：路径→文件内容
-            Cmd::LOA { target, path } => {
-                let data = match try_load_file_content(path) {
-                    Ok(data) => data,
-                    Err(err) => {
-                        eprintln!("NAVM LOA cmd load error: {err}");
-                        return None;
-                    }
-                };
-                return Some(Cmd::LOA { target, path: data });
+/// 后台自动步进的批处理
+/// * 🚩输入在单独的线程中读取，主线程轮流转发输入与输出
+///   * 📌推理器在两次输入之间仍持续运行，其输出随时打印
+/// * 🚩输入结束（EOF）⇒结束工作线程，打印剩余的输出后退出
+/// * 🚩`output`：打印输出
+fn batch_auto_step(
+    mut runtime: RuntimeAlphaAuto,
+    inputs: impl Iterator<Item = Result<Option<String>>> + Send + 'static,
+    mut output: impl FnMut(Output),
+) -> Result<()> {
+    /// 无输入时，转发输出的间隔
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+    let (input_sender, input_receiver) = channel();
+    std::thread::spawn(move || {
+        for input in inputs {
+            let end = !matches!(input, Ok(Some(..)));
+            if input_sender.send(input).is_err() || end {
+                break;
+            }
+        }
+    });
+    let result = loop {
+        // in
+        match input_receiver.recv_timeout(POLL_INTERVAL) {
+            // 正常获取
+            Ok(Ok(Some(input))) => {
+                let input = input.trim();
+                if let Some(cmd) = (!input.is_empty()).then(|| interpret_cmd(input)).flatten() {
+                    runtime.input_cmd(cmd)?;
+                }
+            }
+            // 异常结束
+            Ok(Err(e)) => break Err(e),
+            // EOF
+            Ok(Ok(None)) => {
+                eprintln!("Program exited with EOF.");
+                break Ok(());
             }
-            // 自定义指令：忽略
-            // * 避免解析范围的扩大，导致输入`A.`不通过
-            Cmd::Custom { .. } => {}
-            // 其它⇒解析成功
-            _ => return Some(cmd),
+            // 正常结束
+            Err(RecvTimeoutError::Disconnected) => break Ok(()),
+            // 暂无输入
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+        // out
+        batch_auto_step_outputs(&mut runtime, &mut output)?;
+    };
+    // * 🚩结束工作线程，打印剩余的输出
+    runtime.terminate()?;
+    batch_auto_step_outputs(&mut runtime, &mut output)?;
+    result
+}
+
+/// 转发「后台自动步进」运行时已产生的所有输出
+fn batch_auto_step_outputs(
+    runtime: &mut RuntimeAlphaAuto,
+    output: &mut impl FnMut(Output),
+) -> Result<()> {
+    while let Some(out) = runtime.try_fetch_output()? {
+        if let Some(out) = batch_intercept_output(out)? {
+            output(out);
         }
     }
-    // 最终仍然解析失败
-    eprintln!("NAVM cmd parse error: {input:?}");
-    None
+    Ok(())
+}
+
+/// 从命令行参数解析「后台自动步进」的配置
+/// * 🚩`--auto-step`⇒不限速；`--auto-step <每秒周期数>`⇒限速
+/// * 📌未指定⇒`None`
+/// * ⚠️不能与`--jsonrpc`同时使用：JSON-RPC模式按请求步进
+fn parse_auto_step(args: &[String]) -> Result<Option<AutoStepConfig>> {
+    let Some(i) = args.iter().position(|arg| arg == "--auto-step") else {
+        return Ok(None);
+    };
+    if args.iter().any(|arg| arg == "--jsonrpc") {
+        return Err(anyhow::anyhow!("--auto-step cannot be used with --jsonrpc"));
+    }
+    let cycles_per_second = match args.get(i + 1) {
+        Some(arg) if !arg.starts_with("--") => Some(
+            arg.parse()
+                .map_err(|e| anyhow::anyhow!("Invalid cycles per second {arg:?}: {e}"))?,
+        ),
+        _ => None,
+    };
+    let cycles_per_second = alpha::normalize_throttle(cycles_per_second)?;
+    Ok(Some(AutoStepConfig {
+        cycles_per_second,
+        start_paused: false,
+    }))
+}
+
+/// 从输入中「提前解释」指令
+/// * 📌批处理程序只在本地使用：文件读写不受限制
+/// * 🚩解释失败⇒打印错误消息，跳过输入
+fn interpret_cmd(input: &str) -> Option<Cmd> {
+    alpha::interpret_cmd(input, &FileAccess::Unrestricted)
+        .map_err(|err| eprintln!("{err}"))
+        .ok()
 }
 
 /// 尝试读取本地文件，将内容作为`LOA`指令的path参数
 fn try_load_file_content(path: impl AsRef<str>) -> anyhow::Result<String> {
-    // * 🚩尝试读取本地文件
-    let path = path.as_ref();
-    if Path::new(path).exists() {
-        let content = std::fs::read_to_string(path)?;
-        return Ok(content);
-    }
-    Err(anyhow::anyhow!("File not found: {path}"))
+    alpha::try_load_file_content(path, &FileAccess::Unrestricted)
 }
 
 /// 终端拦截输出
 /// * 🎯根据「有路径的SAV」输出文件
 fn batch_intercept_output(output: Output) -> anyhow::Result<Option<Output>> {
-    // * 🚩拦截「SAV」回调
-    let output = match output.try_into_sav_callback() {
-        // 空路径⇒不保存⇒重组回「消息」并继续（输出到终端）
-        Ok((path, data)) if path.is_empty() => Output::format_sav_callback(path, data),
-        // 有路径⇒保存到文件
-        Ok((path, data)) => {
-            // * 🚩尝试保存文件
-            let result = save_file(&path, &data);
-            // * 🚩将终端输出重定向到文件
-            let message = match result {
-                // * 🚩生成「已保存」的消息
-                Ok(..) => format!(
-                    "Data has been saved to {path:?} with {} bytes",
-                    data.as_bytes().len()
-                ),
-                // * 🚩或报错消息
-                Err(e) => format!("Failed to save data to {path:?}! Error: {e}"),
-            };
-            // * 🚩替换为「已保存」的回显
-            let out = Output::INFO { message };
-            return Ok(Some(out));
-        }
-        // 未消耗⇒继续
-        Err(output) => output,
-    };
-    // 正常未消耗输出
+    let output = alpha::intercept_sav_output(output, &FileAccess::Unrestricted);
     Ok(Some(output))
 }
 
-/// 路径+数据→保存文件
-fn save_file(path: impl Into<String>, data: &str) -> Result<()> {
-    use std::fs::File;
-    let mut file = File::create(path.into())?;
-    file.write_all(data.as_bytes())?;
-    Ok(())
-}
-
 /// 输出：仅打印JSON
 fn batch_output(output: Output) {
     println!("{}", output.to_json_string());
@@ -178,9 +212,57 @@ pub fn batch_iter_stdin() -> impl Iterator<Item = Result<Option<String>>> {
 }
 
 pub fn main() -> Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    // * 🚩后台自动步进
+    if let Some(config) = parse_auto_step(&args)? {
+        let runtime = create_launcher().launch_auto_step(config);
+        return batch_auto_step(runtime, batch_iter_stdin(), batch_output);
+    }
     // * 🚩创建
-    let runtime = create_runtime()?;
-    // * 🚩交互
-    batch(runtime, batch_iter_stdin())?;
+    let runtime = create_launcher().launch()?;
+    // * 🚩交互：按参数选择模式
+    match args.iter().any(|arg| arg == "--jsonrpc") {
+        true => jsonrpc::jsonrpc(runtime, batch_iter_stdin())?,
+        false => batch(runtime, batch_iter_stdin())?,
+    }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn auto_step_args() -> Result<()> {
+        assert!(parse_auto_step(&args("--jsonrpc"))?.is_none());
+        let config = parse_auto_step(&args("--auto-step"))?.unwrap();
+        assert_eq!(config.cycles_per_second, None);
+        let config = parse_auto_step(&args("--auto-step 20"))?.unwrap();
+        assert_eq!(config.cycles_per_second, Some(20.0));
+        assert!(parse_auto_step(&args("--auto-step fast")).is_err());
+        assert!(parse_auto_step(&args("--auto-step 1e-20")).is_err());
+        assert!(parse_auto_step(&args("--auto-step 20 --jsonrpc")).is_err());
+        assert!(parse_auto_step(&args("--jsonrpc --auto-step")).is_err());
+        Ok(())
+    }
+
+    /// 输入结束即退出，期间的输出均已打印
+    #[test]
+    fn auto_step_exits_on_eof() -> Result<()> {
+        let runtime = create_launcher().launch_auto_step(AutoStepConfig::default());
+        let inputs = batch_iter_inputs(["<A --> B>.".to_string()]).chain([Ok(None)]);
+        let mut outputs = vec![];
+        batch_auto_step(runtime, inputs, |output| outputs.push(output))?;
+        assert!(
+            outputs
+                .iter()
+                .any(|output| matches!(output, Output::IN { .. })),
+            "{outputs:?}"
+        );
+        Ok(())
+    }
+}
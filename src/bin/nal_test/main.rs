@@ -0,0 +1,485 @@
+//! `.nal`测试文件运行器
+//! * 🎯无需外部测试框架，即可对推理器做回归检查
+//! * 🚩读取OpenNARS风格的`.nal`文件，在[`RuntimeAlpha`]中运行，并以CSV格式报告结果
+//!   * 📌CSV格式与`docs/test_results/group_result-*.csv`一致，便于对比
+//!
+//! ## `.nal`文件格式
+//!
+//! * 📍Narsese任务：作为`NSE`指令输入
+//! * 📍纯数字：推理器步进指定周期
+//! * 📍`''outputMustContain('...')`：预期输出（不论出现在文件何处，均对整个文件生效）
+//!   * ⚠️格式有误（如缺少括号、引号）⇒报错，而非当作注释
+//! * 📍`'`开头：注释
+//! * 📍`*`开头：OpenNARS的运行时设置（如`*volume=0`），忽略
+//! * 📍其它：尝试作为NAVM指令输入
+//!
+//! ## 预期的匹配
+//!
+//! * 🚩只匹配推理器派生的输出（`OUT`、`ANSWER`、`ACHIEVED`）
+//! * 🚩词项：转换为内部词项后判等，以忽略可交换词项的顺序
+//! * 🚩真值：按预期中给出的小数位数比较（如`0.81`⇒误差不超过`0.005`）
+//! * 📌预期无法解析为Narsese时，退化为「输出内容包含预期字符串」
+//!
+//! ## 用法
+//!
+//! ```plaintext
+//! narust_158_nal_test [--group <测试组名>] <文件或目录>...
+//! ```
+//! * 📌目录⇒运行其中所有`.nal`文件（按文件名排序）
+//! * 📌所有预期均满足⇒退出码为0；否则为1
+
+use anyhow::Result;
+use narsese::{
+    api::GetTerm,
+    conversion::string::impl_lexical::format_instances::FORMAT_ASCII,
+    lexical::{Narsese, Sentence as LexicalSentence},
+};
+use narust_158::{
+    inference::{match_task_and_belief, process_direct, reason, transform_task, InferenceEngine},
+    language::Term,
+    parameters::DEFAULT_PARAMETERS,
+    vm::alpha::LauncherAlpha,
+};
+use navm::{
+    cmd::Cmd,
+    output::Output,
+    vm::{VmLauncher, VmRuntime},
+};
+use std::{
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+/// CSV中的「推理器」名
+const REASONER_NAME: &str = "NARust-158";
+
+/// CSV表头
+/// * 📌与`group_result-*.csv`一致
+const CSV_HEADER: &str = "Serial,Test group,Reasoner,Test,Success or not,Cycles,Time Elapsed(s)";
+
+/// 「预期」所用的指令
+/// * 🎯识别格式有误的预期
+const EXPECTATION_HEAD: &str = "''outputMustContain";
+
+/// 「预期」的前缀
+const EXPECTATION_PREFIX: &str = "''outputMustContain('";
+
+/// 「预期」的后缀
+const EXPECTATION_SUFFIX: &str = "')";
+
+fn create_runtime() -> Result<impl VmRuntime> {
+    // * 🚩【2024-07-09 16:52:40】目前除了「概念推理」均俱全
+    const ENGINE: InferenceEngine = InferenceEngine::new(
+        process_direct,
+        transform_task,
+        match_task_and_belief,
+        reason,
+    );
+    let vm = LauncherAlpha::new("nal_test", DEFAULT_PARAMETERS, ENGINE);
+    vm.launch()
+}
+
+/// `.nal`文件中的一行
+#[derive(Debug)]
+enum Line {
+    /// 输入NAVM指令
+    Cmd(Cmd),
+    /// 步进指定周期
+    Cycles(usize),
+}
+
+/// 一条「预期输出」
+#[derive(Debug)]
+struct Expectation {
+    /// 原始字符串
+    raw: String,
+    /// 解析出的语句
+    /// * 📌解析失败⇒按原始字符串匹配
+    sentence: Option<ExpectedSentence>,
+}
+
+/// 预期中的语句
+#[derive(Debug)]
+struct ExpectedSentence {
+    term: Term,
+    punctuation: String,
+    /// 真值：`(预期值, 允许误差)`
+    truth: Vec<(f64, f64)>,
+}
+
+impl Expectation {
+    fn new(raw: &str) -> Self {
+        let sentence = FORMAT_ASCII
+            .parse(raw)
+            .ok()
+            .and_then(|narsese| match narsese {
+                Narsese::Sentence(sentence) => Some(sentence),
+                Narsese::Task(task) => Some(task.sentence),
+                Narsese::Term(..) => None,
+            })
+            .and_then(ExpectedSentence::from_lexical);
+        Self {
+            raw: raw.to_owned(),
+            sentence,
+        }
+    }
+
+    /// 判断某个输出是否满足预期
+    fn matches(&self, output: &Output) -> bool {
+        // 只匹配派生的输出
+        if !matches!(
+            output,
+            Output::OUT { .. } | Output::ANSWER { .. } | Output::ACHIEVED { .. }
+        ) {
+            return false;
+        }
+        let Some(ref expected) = self.sentence else {
+            return output.get_content().contains(&self.raw);
+        };
+        let sentence = match output.get_narsese() {
+            Some(Narsese::Sentence(sentence)) => sentence,
+            Some(Narsese::Task(task)) => &task.sentence,
+            _ => return false,
+        };
+        expected.matches(sentence)
+    }
+}
+
+impl ExpectedSentence {
+    fn from_lexical(sentence: LexicalSentence) -> Option<Self> {
+        let truth = sentence
+            .truth
+            .iter()
+            .map(|value| {
+                let digits = value.split_once('.').map_or(0, |(_, d)| d.len());
+                let tolerance = 0.5 * 10f64.powi(-(digits as i32)) + f64::EPSILON;
+                value.parse().ok().map(|v| (v, tolerance))
+            })
+            .collect::<Option<_>>()?;
+        Some(Self {
+            term: Term::from_lexical(sentence.term).ok()?,
+            punctuation: sentence.punctuation,
+            truth,
+        })
+    }
+
+    fn matches(&self, sentence: &LexicalSentence) -> bool {
+        if sentence.punctuation != self.punctuation {
+            return false;
+        }
+        let truth_matches =
+            self.truth
+                .iter()
+                .zip(&sentence.truth)
+                .all(|((expected, tolerance), value)| {
+                    value
+                        .parse::<f64>()
+                        .is_ok_and(|value| (value - expected).abs() <= *tolerance)
+                });
+        if !truth_matches || sentence.truth.len() < self.truth.len() {
+            return false;
+        }
+        Term::from_lexical(sentence.get_term().clone()).is_ok_and(|term| term == self.term)
+    }
+}
+
+/// 解析`.nal`文件内容
+/// * 📌返回`(各行, 所有预期)`
+fn parse_nal(content: &str) -> Result<(Vec<Line>, Vec<Expectation>)> {
+    let mut lines = vec![];
+    let mut expectations = vec![];
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('*') {
+            continue;
+        }
+        // 预期
+        if line.starts_with(EXPECTATION_HEAD) {
+            let expected = line
+                .strip_prefix(EXPECTATION_PREFIX)
+                .and_then(|rest| rest.strip_suffix(EXPECTATION_SUFFIX))
+                .ok_or_else(|| anyhow::anyhow!("Malformed expectation {line:?}"))?;
+            expectations.push(Expectation::new(expected));
+            continue;
+        }
+        // 注释
+        if line.starts_with('\'') || line.starts_with("//") {
+            continue;
+        }
+        // 周期数
+        if let Ok(cycles) = line.parse() {
+            lines.push(Line::Cycles(cycles));
+            continue;
+        }
+        // Narsese任务
+        if let Ok(Ok(task)) = FORMAT_ASCII
+            .parse(line)
+            .map(|value| value.try_into_task_compatible())
+        {
+            lines.push(Line::Cmd(Cmd::NSE(task)));
+            continue;
+        }
+        // NAVM指令
+        let cmd = Cmd::parse(line).map_err(|e| anyhow::anyhow!("Invalid line {line:?}: {e}"))?;
+        lines.push(Line::Cmd(cmd));
+    }
+    Ok((lines, expectations))
+}
+
+/// 单个测试的结果
+#[derive(Debug)]
+struct TestResult {
+    /// 各预期被满足时的周期数（自测试开始计）
+    met_cycles: Vec<Option<usize>>,
+    /// 运行耗时（秒）
+    time_elapsed: f64,
+}
+
+impl TestResult {
+    fn success(&self) -> bool {
+        self.met_cycles.iter().all(Option::is_some)
+    }
+
+    /// CSV中的「周期数」一栏
+    /// * 📌多个预期之间以全角逗号分隔，与`group_result-*.csv`一致
+    /// * 📌存在未满足的预期⇒留空，亦与之一致
+    ///   * ℹ️未满足的预期另行打印到标准错误
+    fn cycles_field(&self) -> String {
+        if !self.success() {
+            return String::new();
+        }
+        self.met_cycles
+            .iter()
+            .flatten()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join("，")
+    }
+}
+
+/// 运行一个测试
+/// * 🚩逐周期步进，以便记录各预期被满足的周期
+fn run_test(lines: Vec<Line>, expectations: &[Expectation]) -> Result<TestResult> {
+    let start = Instant::now();
+    let mut runtime = create_runtime()?;
+    let mut met_cycles = vec![None; expectations.len()];
+    let mut cycles = 0;
+    // 检查所有新输出
+    let mut check_outputs = |runtime: &mut dyn FnMut() -> Result<Option<Output>>, cycles| {
+        while let Some(output) = runtime()? {
+            for (met, expectation) in met_cycles.iter_mut().zip(expectations) {
+                if met.is_none() && expectation.matches(&output) {
+                    *met = Some(cycles);
+                }
+            }
+        }
+        anyhow::Ok(())
+    };
+    // * 🚩最大音量：输出所有派生结论
+    runtime.input_cmd(Cmd::VOL(100))?;
+    for line in lines {
+        match line {
+            Line::Cmd(cmd) => {
+                runtime.input_cmd(cmd)?;
+                check_outputs(&mut || runtime.try_fetch_output(), cycles)?;
+            }
+            Line::Cycles(n) => {
+                for _ in 0..n {
+                    runtime.input_cmd(Cmd::CYC(1))?;
+                    cycles += 1;
+                    check_outputs(&mut || runtime.try_fetch_output(), cycles)?;
+                }
+            }
+        }
+    }
+    Ok(TestResult {
+        met_cycles,
+        time_elapsed: start.elapsed().as_secs_f64(),
+    })
+}
+
+/// 收集所有测试文件
+/// * 📌目录⇒其中所有`.nal`文件，按文件名排序
+fn collect_files(paths: &[String]) -> Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for path in paths.iter().map(Path::new) {
+        if path.is_dir() {
+            let mut entries = std::fs::read_dir(path)?
+                .map(|entry| entry.map(|e| e.path()))
+                .collect::<Result<Vec<_>, _>>()?;
+            entries.retain(|p| p.extension().is_some_and(|ext| ext == "nal"));
+            entries.sort();
+            files.extend(entries);
+        } else {
+            files.push(path.to_owned());
+        }
+    }
+    Ok(files)
+}
+
+/// 从测试名推断测试组
+/// * 📄`NAL-1.0` => `NAL-1`
+/// * 📄`NAL-6 communicative findSubstitute` => `NAL-6`
+fn infer_group(test_name: &str) -> &str {
+    test_name
+        .split([' ', '.'])
+        .next()
+        .filter(|group| !group.is_empty())
+        .unwrap_or(test_name)
+}
+
+/// 转义CSV字段
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_owned(),
+    }
+}
+
+pub fn main() -> Result<()> {
+    // * 🚩解析参数
+    let mut group = None;
+    let mut paths = vec![];
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--group" => group = args.next(),
+            _ => paths.push(arg),
+        }
+    }
+    if paths.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Usage: narust_158_nal_test [--group <name>] <file or directory>..."
+        ));
+    }
+    // * 🚩逐个运行并输出CSV
+    println!("{CSV_HEADER}");
+    let mut all_success = true;
+    for (serial, file) in collect_files(&paths)?.into_iter().enumerate() {
+        let test_name = file
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let test_group = group.as_deref().unwrap_or_else(|| infer_group(&test_name));
+        let content = std::fs::read_to_string(&file)?;
+        let (lines, expectations) = parse_nal(&content)?;
+        let result = run_test(lines, &expectations)?;
+        all_success &= result.success();
+        println!(
+            "{},{},{},{},{},{},{}",
+            serial + 1,
+            csv_field(test_group),
+            REASONER_NAME,
+            csv_field(&test_name),
+            if result.success() { "Y" } else { "N" },
+            csv_field(&result.cycles_field()),
+            result.time_elapsed,
+        );
+        // * 🚩未满足的预期⇒打印到标准错误，便于排查
+        for (met, expectation) in result.met_cycles.iter().zip(&expectations) {
+            if met.is_none() {
+                eprintln!("[{test_name}] Expectation not met: {}", expectation.raw);
+            }
+        }
+    }
+    if !all_success {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 构造一个派生的输出
+    fn out(narsese: &str) -> Output {
+        Output::OUT {
+            content_raw: narsese.to_owned(),
+            narsese: Some(FORMAT_ASCII.parse(narsese).unwrap()),
+        }
+    }
+
+    #[test]
+    fn parse_lines() -> Result<()> {
+        let (lines, expectations) = parse_nal(
+            "
+            '注释
+            *volume=0
+            <A --> B>.
+            5
+            ''outputMustContain('<A --> B>. %1.00;0.90%')
+            vol 0
+            ",
+        )?;
+        assert!(matches!(
+            lines[..],
+            [
+                Line::Cmd(Cmd::NSE(..)),
+                Line::Cycles(5),
+                Line::Cmd(Cmd::VOL(0))
+            ]
+        ));
+        assert_eq!(expectations.len(), 1);
+        assert_eq!(expectations[0].raw, "<A --> B>. %1.00;0.90%");
+        assert!(expectations[0].sentence.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_malformed() {
+        for content in [
+            "''outputMustContain('<A --> B>.",
+            "''outputMustContain(<A --> B>.)",
+        ] {
+            assert!(parse_nal(content).is_err(), "{content}");
+        }
+    }
+
+    #[test]
+    fn match_expectation() {
+        let expectation = Expectation::new("<(&,A,B) --> C>. %1.00;0.81%");
+        // * 🚩可交换词项的顺序不影响匹配；真值在精度内
+        assert!(expectation.matches(&out("<(&,B,A) --> C>. %1.0000;0.8100%")));
+        assert!(expectation.matches(&out("<(&,A,B) --> C>. %0.9990;0.8140%")));
+        // * 🚩真值超出精度、标点不同、词项不同
+        assert!(!expectation.matches(&out("<(&,A,B) --> C>. %1.0000;0.8200%")));
+        assert!(!expectation.matches(&out("<(&,A,B) --> C>? ")));
+        assert!(!expectation.matches(&out("<(&,A,D) --> C>. %1.0000;0.8100%")));
+        // * 🚩只匹配派生的输出
+        let input = Output::IN {
+            content: "<(&,A,B) --> C>. %1.0000;0.8100%".into(),
+            narsese: None,
+        };
+        assert!(!expectation.matches(&input));
+        // * 🚩无法解析⇒按字符串包含匹配
+        let expectation = Expectation::new("--> C>");
+        assert!(expectation.sentence.is_none());
+        assert!(expectation.matches(&out("<A --> C>.")));
+    }
+
+    #[test]
+    fn cycles_field() {
+        let result = |met_cycles| TestResult {
+            met_cycles,
+            time_elapsed: 0.0,
+        };
+        assert_eq!(result(vec![Some(1), Some(5)]).cycles_field(), "1，5");
+        // * 🚩未满足⇒留空
+        assert_eq!(result(vec![Some(1), None]).cycles_field(), "");
+    }
+
+    #[test]
+    fn run_deduction() -> Result<()> {
+        let (lines, expectations) = parse_nal(
+            "
+            <A --> B>.
+            <B --> C>.
+            10
+            ''outputMustContain('<A --> C>. %1.00;0.81%')
+            ",
+        )?;
+        let result = run_test(lines, &expectations)?;
+        assert!(result.success(), "{result:?}");
+        Ok(())
+    }
+}
@@ -0,0 +1,373 @@
+//! 标准NAVM「服务器」程序
+//! * 🚩在本地TCP端口或Unix域套接字上监听，以[NAVM指令](Cmd)行作为输入，以JSON格式输出[NAVM输出](Output)
+//!   * 📌每行输入、每行输出的格式与「批处理」程序一致
+//! * 🎯供Python、Web等前端与长期运行的推理器交互
+//! * 📌两种会话模式
+//!   * 📍独立会话（默认）：每个连接拥有自己的推理器
+//!   * 📍共享推理器（`--shared`）：所有连接共用同一推理器，各连接只收到自己指令的输出
+//!
+//! ## 用法
+//!
+//! ```plaintext
+//! narust_158_server [--tcp <地址>] [--unix <路径>] [--shared] [--file-root <目录>]
+//! ```
+//! * 📄默认：`--tcp 127.0.0.1:5158`
+//! * 📌`--file-root`：允许`LOA`/`SAV`读写该目录下的文件
+//!   * ⚠️默认禁止：客户端不得借服务器读写本地文件
+//!   * 📍只接受目录内的相对路径；未指定路径的`SAV`仍将数据直接发回客户端
+//! * 📌Unix域套接字文件在服务器退出时删除
+
+use anyhow::Result;
+use narust_158::{
+    inference::{match_task_and_belief, process_direct, reason, transform_task, InferenceEngine},
+    parameters::DEFAULT_PARAMETERS,
+    vm::alpha::{intercept_sav_output, interpret_cmd, FileAccess, LauncherAlpha},
+};
+use navm::{cmd::Cmd, output::Output, vm::VmLauncher, vm::VmRuntime};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{
+        mpsc::{channel, Sender},
+        Arc,
+    },
+    thread,
+};
+
+/// 默认的监听地址
+const DEFAULT_TCP_ADDRESS: &str = "127.0.0.1:5158";
+
+fn create_runtime() -> Result<impl VmRuntime> {
+    // * 🚩【2024-07-09 16:52:40】目前除了「概念推理」均俱全
+    const ENGINE: InferenceEngine = InferenceEngine::new(
+        process_direct,
+        transform_task,
+        match_task_and_belief,
+        reason,
+    );
+    let vm = LauncherAlpha::new("demo_158", DEFAULT_PARAMETERS, ENGINE);
+    vm.launch()
+}
+
+/// 监听的位置
+#[derive(Debug, Clone)]
+enum Address {
+    /// 本地TCP地址
+    Tcp(String),
+    /// Unix域套接字路径
+    #[cfg(unix)]
+    Unix(String),
+}
+
+/// 服务器配置
+#[derive(Debug, Clone)]
+struct Config {
+    /// 监听的位置
+    address: Address,
+    /// 是否让所有连接共享同一推理器
+    shared: bool,
+    /// `LOA`/`SAV`对本地文件的访问权限
+    /// * 📌默认禁止
+    files: FileAccess,
+}
+
+/// 从命令行参数解析配置
+/// * 🚩未知参数⇒报错
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Config> {
+    let mut config = Config {
+        address: Address::Tcp(DEFAULT_TCP_ADDRESS.into()),
+        shared: false,
+        files: FileAccess::Denied,
+    };
+    while let Some(arg) = args.next() {
+        let mut value = |name| {
+            args.next()
+                .ok_or_else(|| anyhow::anyhow!("Missing value for argument {name}"))
+        };
+        match arg.as_str() {
+            "--tcp" => config.address = Address::Tcp(value("--tcp")?),
+            #[cfg(unix)]
+            "--unix" => config.address = Address::Unix(value("--unix")?),
+            "--shared" => config.shared = true,
+            "--file-root" => config.files = FileAccess::confined(value("--file-root")?)?,
+            other => return Err(anyhow::anyhow!("Unknown argument: {other:?}")),
+        }
+    }
+    Ok(config)
+}
+
+/// 「推理器」的请求：一行输入，以及「回传输出」的通道
+/// * 🎯共享模式下，在各连接线程与推理器线程间传递
+/// * ℹ️推理器内部使用非线程安全的共享引用，只能在单一线程中运行
+struct Request {
+    input: String,
+    reply: Sender<Vec<String>>,
+}
+
+/// 各连接共用的文件访问权限
+type Files = Arc<FileAccess>;
+
+/// 共享会话：将输入转交推理器线程，并等待其输出
+fn process_shared(sender: &Sender<Request>, input: String) -> Vec<String> {
+    let (reply, outputs) = channel();
+    // * 🚩推理器线程已退出⇒无输出
+    if sender.send(Request { input, reply }).is_err() {
+        return vec![];
+    }
+    outputs.recv().unwrap_or_default()
+}
+
+/// 处理一行输入：解释成指令、输入推理器、拉取输出
+fn process_input(runtime: &mut impl VmRuntime, input: &str, files: &FileAccess) -> Vec<String> {
+    let mut outputs = vec![];
+    // 尝试预先解释输入
+    // * 🚩解释失败⇒将错误回传客户端，而非打印在服务器上
+    let result = interpret_cmd(input, files).and_then(|cmd| {
+        runtime
+            .input_cmd(cmd)
+            .map_err(|e| format!("NAVM cmd input error: {e}"))
+    });
+    if let Err(description) = result {
+        outputs.push(Output::ERROR { description }.to_json_string());
+    }
+    // 拉取所有输出
+    while let Ok(Some(output)) = runtime.try_fetch_output() {
+        // 提前解释输出：有路径的`SAV`⇒保存到文件
+        let output = intercept_sav_output(output, files);
+        outputs.push(output.to_json_string());
+    }
+    outputs
+}
+
+/// 处理一个连接
+/// * 🚩逐行读取输入，逐行写回输出
+/// * 🚩`EXI`指令⇒仅关闭当前连接，不退出服务器
+/// * 📌`process`：处理一行输入，返回所有输出（JSON字符串）
+///   * 🎯统一「独立会话」与「共享推理器」两种模式
+fn handle_connection<S>(stream: S, mut process: impl FnMut(String) -> Vec<String>) -> Result<()>
+where
+    for<'s> &'s S: Read + Write,
+{
+    let reader = BufReader::new(&stream);
+    let mut writer = &stream;
+    for line in reader.lines() {
+        let line = line?;
+        let input = line.trim();
+        if input.is_empty() {
+            continue;
+        }
+        if let Ok(Cmd::EXI { .. }) = Cmd::parse(input) {
+            break;
+        }
+        for output in process(input.to_owned()) {
+            writeln!(writer, "{output}")?;
+        }
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+/// 启动「推理器线程」
+/// * 🎯共享模式：所有连接共用同一推理器
+fn spawn_shared_reasoner(files: Files) -> Sender<Request> {
+    let (sender, requests) = channel::<Request>();
+    thread::spawn(move || -> Result<()> {
+        let mut runtime = create_runtime()?;
+        for Request { input, reply } in requests {
+            let outputs = process_input(&mut runtime, &input, &files);
+            // * 🚩连接已断开⇒忽略
+            let _ = reply.send(outputs);
+        }
+        Ok(())
+    });
+    sender
+}
+
+/// 为一个新连接开启线程
+/// * 🚩共享模式⇒转交推理器线程；否则⇒在连接线程中新建推理器
+fn spawn_connection<S>(
+    stream: S,
+    shared: Option<&Sender<Request>>,
+    files: &Files,
+) -> thread::JoinHandle<()>
+where
+    S: Send + 'static,
+    for<'s> &'s S: Read + Write,
+{
+    let shared = shared.cloned();
+    let files = files.clone();
+    thread::spawn(move || {
+        let result = match shared {
+            Some(sender) => handle_connection(stream, |input| process_shared(&sender, input)),
+            None => create_runtime().and_then(|mut runtime| {
+                handle_connection(stream, |input| process_input(&mut runtime, &input, &files))
+            }),
+        };
+        if let Err(e) = result {
+            eprintln!("Connection error: {e}");
+        }
+    })
+}
+
+/// 开始监听，并为每个连接开启线程
+fn serve(config: Config) -> Result<()> {
+    let files = Arc::new(config.files);
+    let shared = config.shared.then(|| spawn_shared_reasoner(files.clone()));
+    match config.address {
+        Address::Tcp(address) => {
+            let listener = TcpListener::bind(&address)?;
+            eprintln!("Listening on tcp://{}", listener.local_addr()?);
+            for stream in listener.incoming() {
+                spawn_connection(stream?, shared.as_ref(), &files);
+            }
+        }
+        #[cfg(unix)]
+        Address::Unix(path) => {
+            let (listener, _socket) = unix_socket::bind(&path)?;
+            eprintln!("Listening on unix://{path}");
+            #[cfg(feature = "server_signal")]
+            unix_socket::remove_on_interrupt(path)?;
+            for stream in listener.incoming() {
+                spawn_connection(stream?, shared.as_ref(), &files);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Unix域套接字的绑定与清理
+#[cfg(unix)]
+mod unix_socket {
+    use anyhow::Result;
+    use std::os::unix::net::UnixListener;
+
+    /// 套接字文件的守卫：析构时删除文件
+    /// * 🎯服务器退出后不留下套接字文件，以便下次在同一路径上绑定
+    pub struct SocketFile(String);
+
+    impl Drop for SocketFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    /// 绑定到指定路径
+    /// * 🚩返回的守卫析构时删除套接字文件
+    pub fn bind(path: &str) -> Result<(UnixListener, SocketFile)> {
+        let listener = UnixListener::bind(path)?;
+        Ok((listener, SocketFile(path.to_owned())))
+    }
+
+    /// 被`Ctrl+C`中断时删除套接字文件
+    /// * 📝进程被信号终止时不会运行析构函数
+    #[cfg(feature = "server_signal")]
+    pub fn remove_on_interrupt(path: String) -> Result<()> {
+        ctrlc::set_handler(move || {
+            let _ = std::fs::remove_file(&path);
+            std::process::exit(0);
+        })?;
+        Ok(())
+    }
+}
+
+pub fn main() -> Result<()> {
+    // * 🚩解析参数
+    let config = parse_args(std::env::args().skip(1))?;
+    // * 🚩开始服务
+    serve(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{io::BufReader, net::TcpStream};
+
+    fn args(args: &str) -> impl Iterator<Item = String> + '_ {
+        args.split_whitespace().map(str::to_owned)
+    }
+
+    #[test]
+    fn parse_config() -> Result<()> {
+        // * 🚩默认：本地TCP、独立会话、禁止文件读写
+        let config = parse_args(args(""))?;
+        assert!(matches!(config.address, Address::Tcp(ref a) if a == DEFAULT_TCP_ADDRESS));
+        assert!(!config.shared);
+        assert_eq!(config.files, FileAccess::Denied);
+        // * 🚩指定参数
+        let root = std::env::temp_dir();
+        let config = parse_args(args(&format!(
+            "--tcp 0.0.0.0:1234 --shared --file-root {}",
+            root.display()
+        )))?;
+        assert!(matches!(config.address, Address::Tcp(ref a) if a == "0.0.0.0:1234"));
+        assert!(config.shared);
+        assert_eq!(config.files, FileAccess::confined(root)?);
+        // * 🚩错误参数
+        assert!(parse_args(args("--tcp")).is_err());
+        assert!(parse_args(args("--unknown")).is_err());
+        assert!(parse_args(args("--file-root /path/not/existed")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn file_access_denied_by_default() -> Result<()> {
+        let mut runtime = create_runtime()?;
+        let files = FileAccess::Denied;
+        // * 🚩`LOA`不读取文件
+        let outputs = process_input(&mut runtime, "LOA memory /etc/passwd", &files);
+        assert!(outputs.iter().any(|o| o.contains("ERROR")), "{outputs:?}");
+        // * 🚩有路径的`SAV`不写入文件
+        let outputs = process_input(&mut runtime, "SAV memory memory.json", &files);
+        assert!(
+            outputs.iter().any(|o| o.contains("Failed to save")),
+            "{outputs:?}"
+        );
+        // * 🚩无路径的`SAV`照常回传数据
+        let outputs = process_input(&mut runtime, "SAV memory", &files);
+        assert!(!outputs.iter().any(|o| o.contains("Failed")), "{outputs:?}");
+        Ok(())
+    }
+
+    /// 独立会话：经TCP连接输入、读取输出，`EXI`只关闭连接
+    #[test]
+    fn tcp_session() -> Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let client = TcpStream::connect(listener.local_addr()?)?;
+        let (stream, _) = listener.accept()?;
+        let files = Arc::new(FileAccess::Denied);
+        let handle = spawn_connection(stream, None, &files);
+
+        let mut writer = &client;
+        let mut reader = BufReader::new(&client);
+        writeln!(writer, "<A --> B>.")?;
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        assert!(line.contains("IN"), "{line}");
+        // * 🚩解析失败⇒错误回传客户端
+        writeln!(writer, "<A --> B.")?;
+        line.clear();
+        reader.read_line(&mut line)?;
+        assert!(line.contains("ERROR"), "{line}");
+        // * 🚩`EXI`⇒服务端关闭连接
+        writeln!(writer, "EXI")?;
+        handle.join().unwrap();
+        line.clear();
+        assert_eq!(reader.read_line(&mut line)?, 0);
+        Ok(())
+    }
+
+    /// 套接字文件在守卫析构后删除
+    #[cfg(unix)]
+    #[test]
+    fn unix_socket_removed() -> Result<()> {
+        let path =
+            std::env::temp_dir().join(format!("narust_158_server_{}.sock", std::process::id()));
+        let path = path.to_str().unwrap();
+        let (listener, socket) = unix_socket::bind(path)?;
+        assert!(std::path::Path::new(path).exists());
+        drop(listener);
+        drop(socket);
+        assert!(!std::path::Path::new(path).exists());
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+//! 交互式终端的行编辑器
+//! * 🎯为每天长时间使用终端的用户提供便利
+//!   * 📍持久化的历史记录：保存于用户主目录下的[历史文件](HISTORY_FILE_NAME)
+//!   * 📍Tab补全：NAVM指令头、`INF`/`HLP`/`SAV`/`LOA`的目标、记忆区中已有概念的词项
+//!   * 📍多行输入：括号未闭合、或行末为`\`时，继续读取下一行
+//! * 🚩基于`rustyline`实现
+
+use super::is_input_complete;
+use anyhow::Result;
+use rustyline::{
+    completion::Completer,
+    error::ReadlineError,
+    highlight::Highlighter,
+    hint::Hinter,
+    history::FileHistory,
+    validate::{ValidationContext, ValidationResult, Validator},
+    Context, Editor, Helper,
+};
+use std::{cell::RefCell, path::PathBuf, rc::Rc};
+
+/// 历史文件的文件名
+const HISTORY_FILE_NAME: &str = ".narust_158_history";
+
+/// 指定历史文件路径的环境变量
+const HISTORY_FILE_ENV: &str = "NARUST_158_HISTORY";
+
+/// 所有NAVM指令头
+/// * 📌与[`navm::cmd::Cmd::head`]一致
+/// * 🆕含虚拟机Alpha的自定义指令`DEF`
+const CMD_HEADS: &[&str] = &[
+    "SAV", "LOA", "RES", "NSE", "NEW", "DEL", "CYC", "VOL", "REG", "INF", "HLP", "REM", "EXI",
+    "DEF",
+];
+
+/// 各指令的目标
+/// * 📌与虚拟机Alpha的指令分派一致
+fn cmd_targets(head: &str) -> &'static [&'static str] {
+    match head.to_uppercase().as_str() {
+        "INF" => &[
+            "parameters",
+            "tasks",
+            "beliefs",
+            "questions",
+            "concepts",
+            "links",
+            "summary",
+            "revisions",
+            "#parameters",
+            "#tasks",
+            "#beliefs",
+            "#questions",
+            "#concepts",
+            "#links",
+        ],
+        "HLP" => &["inf", "examples"],
+        "SAV" => &[
+            "memory",
+            "status",
+            "memory_cbor",
+            "memory_cbor_deflate",
+            "status_cbor",
+            "status_cbor_deflate",
+            "narsese",
+            "narsese_with_questions",
+            "delta",
+            "graph",
+        ],
+        "LOA" => &[
+            "memory",
+            "status",
+            "memory_cbor",
+            "status_cbor",
+            "merge",
+            "delta",
+            "replay",
+        ],
+        _ => &[],
+    }
+}
+
+/// 记忆区中已有概念的词项
+/// * 🚩由终端在每次输入后刷新
+pub type ConceptTerms = Rc<RefCell<Vec<String>>>;
+
+/// 终端的行编辑辅助者
+/// * 🚩提供补全与多行输入判断
+struct ShellHelper {
+    /// 用于补全的概念词项
+    concepts: ConceptTerms,
+}
+
+impl ShellHelper {
+    /// 补全词项
+    /// * 🚩从最长的后缀开始尝试：有匹配的概念⇒使用该后缀
+    ///   * 📄`<A`⇒优先匹配复合词项`<A --> B>`，其次匹配原子词项`A`
+    /// * 📌后缀的起点：行首、空白或括号、分隔符之后
+    fn complete_term(&self, line: &str) -> (usize, Vec<String>) {
+        let concepts = self.concepts.borrow();
+        let starts = std::iter::once(0).chain(
+            line.char_indices()
+                .filter(|(_, c)| c.is_whitespace() || "<>(){}[],".contains(*c))
+                .map(|(i, c)| i + c.len_utf8()),
+        );
+        for start in starts {
+            let prefix = &line[start..];
+            if prefix.is_empty() {
+                continue;
+            }
+            let candidates = concepts
+                .iter()
+                .filter(|term| term.starts_with(prefix))
+                .cloned()
+                .collect::<Vec<_>>();
+            if !candidates.is_empty() {
+                return (start, candidates);
+            }
+        }
+        (line.len(), vec![])
+    }
+}
+
+impl Completer for ShellHelper {
+    type Candidate = String;
+
+    /// 按光标前的内容补全
+    /// * 🚩第一个词⇒指令头
+    /// * 🚩`INF`等指令的第二个词⇒指令目标
+    /// * 🚩其它⇒概念词项
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let line = &line[..pos];
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let ends_with_space = line.ends_with(char::is_whitespace);
+        let word_start = line.rfind(char::is_whitespace).map_or(0, |i| {
+            i + line[i..].chars().next().map_or(1, char::len_utf8)
+        });
+        let word = &line[word_start..];
+        // 指令头
+        if words.len() <= 1 && !ends_with_space {
+            let upper = word.to_uppercase();
+            let heads = CMD_HEADS
+                .iter()
+                .filter(|head| head.starts_with(&upper))
+                .map(|head| head.to_string())
+                .collect::<Vec<_>>();
+            if !heads.is_empty() {
+                return Ok((word_start, heads));
+            }
+        }
+        // 指令目标
+        let n_completed_words = words.len().saturating_sub(!ends_with_space as usize);
+        if n_completed_words == 1 {
+            let targets = cmd_targets(words[0])
+                .iter()
+                .filter(|target| target.starts_with(&word.to_lowercase()))
+                .map(|target| target.to_string())
+                .collect::<Vec<_>>();
+            if !targets.is_empty() {
+                return Ok((word_start, targets));
+            }
+        }
+        // 概念词项
+        Ok(self.complete_term(line))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {
+    /// 输入未完整⇒继续读取下一行
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        Ok(match is_input_complete(ctx.input()) {
+            true => ValidationResult::Valid(None),
+            false => ValidationResult::Incomplete,
+        })
+    }
+}
+
+impl Helper for ShellHelper {}
+
+/// 历史文件的路径
+/// * 🚩优先使用环境变量，其次为用户主目录，最后为当前目录
+fn history_path() -> PathBuf {
+    if let Some(path) = std::env::var_os(HISTORY_FILE_ENV) {
+        return path.into();
+    }
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"));
+    match home {
+        Some(home) => PathBuf::from(home).join(HISTORY_FILE_NAME),
+        None => PathBuf::from(HISTORY_FILE_NAME),
+    }
+}
+
+/// 从行编辑器读取输入
+/// * 🚩每次输入后立即保存历史：`EXI`指令会直接结束进程
+/// * 🚩`Ctrl+C`⇒放弃当前输入；`Ctrl+D`⇒EOF
+pub fn shell_iter_line_editor(
+    concepts: ConceptTerms,
+) -> Result<impl Iterator<Item = Result<Option<String>>>> {
+    let mut editor = Editor::<ShellHelper, FileHistory>::new()?;
+    editor.set_helper(Some(ShellHelper { concepts }));
+    let history_path = history_path();
+    // * 🚩首次使用时无历史文件
+    let _ = editor.load_history(&history_path);
+    Ok(std::iter::from_fn(move || {
+        let input = match editor.readline("> ") {
+            Ok(input) => input,
+            Err(ReadlineError::Interrupted) => return Some(Ok(Some(String::new()))),
+            Err(ReadlineError::Eof) => return Some(Ok(None)),
+            Err(e) => return Some(Err(e.into())),
+        };
+        if !input.trim().is_empty() {
+            let _ = editor.add_history_entry(input.as_str());
+            if let Err(e) = editor.save_history(&history_path) {
+                eprintln!("Failed to save history to {history_path:?}: {e}");
+            }
+        }
+        Some(Ok(Some(input)))
+    }))
+}
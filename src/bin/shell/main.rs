@@ -1,19 +1,27 @@
+//! NARust-158的交互式终端
+//! * 🚩纯数字⇒`CYC`指令；NAVM指令；Narsese任务⇒`NSE`指令
+//! * 📌启用特性`shell_line_editor`时：历史记录、Tab补全、多行输入
+//! * 📌输出按类型着色；设置环境变量`NO_COLOR`、或输出不是终端时不着色
+//! * 📌子命令`WATCH`：实时监视推理器状态，详见[`watch`]
+
 use anyhow::Result;
 use narsese::conversion::string::impl_lexical::format_instances::FORMAT_ASCII;
 use narust_158::{
     inference::{match_task_and_belief, process_direct, reason, transform_task, InferenceEngine},
     parameters::DEFAULT_PARAMETERS,
-    vm::alpha::{LauncherAlpha, SavCallback},
+    vm::alpha::{self, FileAccess, LauncherAlpha, RuntimeAlpha, SavCallback},
 };
 use navm::{
     cmd::Cmd,
     output::Output,
     vm::{VmLauncher, VmRuntime},
 };
-use std::{
-    io::{stdout, Write},
-    path::Path,
-};
+use std::io::{stdout, IsTerminal, Write};
+
+#[cfg(feature = "shell_line_editor")]
+mod line_editor;
+
+mod watch;
 
 pub fn launcher_void() -> impl VmLauncher {
     LauncherAlpha::new("nar_158", DEFAULT_PARAMETERS, InferenceEngine::VOID)
@@ -23,7 +31,7 @@ pub fn launcher_echo() -> impl VmLauncher {
     LauncherAlpha::new("nar_158", DEFAULT_PARAMETERS, InferenceEngine::ECHO)
 }
 
-pub fn launcher_dev() -> impl VmLauncher {
+pub fn launcher_dev() -> LauncherAlpha {
     // * 🚩【2024-07-09 16:52:40】目前除了「概念推理」均俱全
     const ENGINE: InferenceEngine = InferenceEngine::new(
         process_direct,
@@ -34,15 +42,24 @@ pub fn launcher_dev() -> impl VmLauncher {
     LauncherAlpha::new("nar_158", DEFAULT_PARAMETERS, ENGINE)
 }
 
-fn create_runtime() -> Result<impl VmRuntime> {
+fn create_runtime() -> Result<RuntimeAlpha> {
     let vm = launcher_dev();
     vm.launch()
 }
 
+/// 交互主循环
+/// * 🚩`after_input`：每次处理完输入与输出后调用
+///   * 🎯刷新补全用的概念词项
+/// * 🚩多行输入（仅行编辑器）⇒拼接为一行后再解释
+///   * 📌行编辑器会等到输入完整才交出
+///   * ⚠️其它来源的输入逐行解释：不完整（如括号未闭合）⇒报错并跳过，而不吞掉后续行
+/// * 🚩`WATCH`子命令⇒进入监视模式，中断后返回
 fn shell(
-    mut runtime: impl VmRuntime,
+    mut runtime: RuntimeAlpha,
     mut inputs: impl Iterator<Item = Result<Option<String>>>,
+    mut after_input: impl FnMut(&RuntimeAlpha),
 ) -> Result<()> {
+    let colored = use_color();
     loop {
         // in
         let input = match inputs.next() {
@@ -58,77 +75,86 @@ fn shell(
             // 正常获取
             Some(Ok(Some(input))) => input,
         };
+        // 多行输入
+        if !is_input_complete(&input) {
+            eprintln!("Unterminated input: {:?}", input.trim());
+            continue;
+        }
+        let input = join_lines(&input);
         let input = input.trim();
         if input.is_empty() {
             continue;
         }
-        // 尝试预先解释输入
-        if let Some(cmd) = interpret_cmd(input) {
-            runtime.input_cmd(cmd)?;
+        match watch::parse_watch(input) {
+            // 监视模式
+            // * 📌期间的输出已由监视模式消耗
+            Some(Ok(config)) => watch::watch(&mut runtime, config)?,
+            Some(Err(e)) => eprintln!("{e}"),
+            // 尝试预先解释输入
+            None => {
+                if let Some(cmd) = interpret_cmd(input) {
+                    runtime.input_cmd(cmd)?;
+                }
+            }
         }
         // out
         while let Some(output) = runtime.try_fetch_output()? {
             if let Some(output) = shell_intercept_output(output)? {
-                shell_print_output(output);
+                shell_print_output(output, colored);
             }
         }
+        after_input(&runtime);
+    }
+}
+
+/// 判断输入是否完整
+/// * 🚩行末（忽略空白）为`\`⇒未完整
+/// * 🚩括号未闭合⇒未完整
+///   * ⚠️预先去除含括号的系词，避免`-->`等被当作括号
+pub fn is_input_complete(input: &str) -> bool {
+    if input.trim_end().ends_with('\\') {
+        return false;
     }
+    /// 含括号的系词
+    const COPULAS: &[&str] = &[
+        "-->", "<->", "==>", "<=>", "=/>", "=|>", "=\\>", "</>", "<|>", "{--", "--]", "{-]",
+    ];
+    let stripped = COPULAS
+        .iter()
+        .fold(input.to_string(), |s, copula| s.replace(copula, " "));
+    let depth = stripped.chars().fold(0isize, |depth, c| match c {
+        '<' | '(' | '[' | '{' => depth + 1,
+        '>' | ')' | ']' | '}' => depth - 1,
+        _ => depth,
+    });
+    depth <= 0
+}
+
+/// 将多行输入拼接为一行
+/// * 🚩去掉续行符`\`，以空格连接各行
+fn join_lines(input: &str) -> String {
+    input
+        .lines()
+        .map(|line| line.trim().trim_end_matches('\\').trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 /// 从输入中「提前解释」指令
 /// * 💡可以从中对指令作预处理
 ///   * 📄绕过生硬的NAVM指令语法，像OpenNARS那样直接输入Narsese与推理步数
-///   * 📄截获解析出的`SAV` `LOA`等指令，解释为其它指令语法
-///     * 💡如：`LOA`指令⇒前端请求文件并读取内容⇒内联到新的`LOA`中⇒虚拟机Alpha实现内容加载
+/// * 🚩纯数字⇒`CYC`指令；其它⇒交给[前端程序共用的解释](alpha::interpret_cmd)
+///   * 📌交互式终端只在本地使用：文件读写不受限制
+/// * 🚩解释失败⇒打印错误消息，跳过输入
 fn interpret_cmd(input: &str) -> Option<Cmd> {
     // 纯数字⇒尝试默认成`CYC`指令
     if let Ok(n) = input.parse::<usize>() {
         return Some(Cmd::CYC(n));
     }
-    // 尝试作为普通NAVM指令解析
-    if let Ok(cmd) = Cmd::parse(input) {
-        match cmd {
-            // `LOA`指令转译：路径→文件内容
-            Cmd::LOA { target, path } => {
-                let data = match try_load_file_content(path) {
-                    Ok(data) => data,
-                    Err(err) => {
-                        eprintln!("NAVM LOA cmd load error: {err}");
-                        return None;
-                    }
-                };
-                return Some(Cmd::LOA { target, path: data });
-            }
-            // 自定义指令：忽略
-            // * 避免解析范围的扩大，导致输入`A.`不通过
-            Cmd::Custom { .. } => {}
-            // 其它⇒解析成功
-            _ => return Some(cmd),
-        }
-    }
-    // 若能解析成词法Narsese任务⇒尝试默认成`NSE`指令
-    // * ⚠️此解析方法容易把范围扩大，因此放到后边
-    //   * 📄已知问题：`nse <A --> B>.`被当作指令`NSE nse.`
-    if let Ok(Ok(task)) = FORMAT_ASCII
-        .parse(input)
-        .map(|value| value.try_into_task_compatible())
-    {
-        return Some(Cmd::NSE(task));
-    }
-    // 最终仍然解析失败
-    eprintln!("NAVM cmd parse error: {input:?}");
-    None
-}
-
-/// 尝试读取本地文件，将内容作为`LOA`指令的path参数
-fn try_load_file_content(path: impl AsRef<str>) -> anyhow::Result<String> {
-    // * 🚩尝试读取本地文件
-    let path = path.as_ref();
-    if Path::new(path).exists() {
-        let content = std::fs::read_to_string(path)?;
-        return Ok(content);
-    }
-    Err(anyhow::anyhow!("File not found: {path}"))
+    alpha::interpret_cmd(input, &FileAccess::Unrestricted)
+        .map_err(|err| eprintln!("{err}"))
+        .ok()
 }
 
 /// 终端拦截输出
@@ -171,9 +197,40 @@ fn save_file(path: impl Into<String>, data: &str) -> Result<()> {
     Ok(())
 }
 
+/// 是否为输出着色
+/// * 🚩标准输出是终端，且未设置环境变量`NO_COLOR`
+/// * 🔗<https://no-color.org>
+fn use_color() -> bool {
+    stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+/// 各类输出的ANSI颜色代码
+fn output_color(output: &Output) -> &'static str {
+    use Output::*;
+    match output {
+        IN { .. } => "36",                          // 青
+        OUT { .. } => "34",                         // 蓝
+        ANSWER { .. } | ACHIEVED { .. } => "1;32",  // 亮绿
+        EXE { .. } => "35",                         // 品红
+        ERROR { .. } | TERMINATED { .. } => "1;31", // 亮红
+        INFO { .. } => "33",                        // 黄
+        COMMENT { .. } => "2",                      // 暗淡
+        OTHER { .. } | UNCLASSIFIED { .. } => "0",  // 默认
+    }
+}
+
 /// 终端打印输出
-fn shell_print_output(output: Output) {
+/// * 🚩`colored`⇒按输出类型为类型标签着色
+fn shell_print_output(output: Output, colored: bool) {
     use Output::*;
+    let type_tag = match colored {
+        true => format!(
+            "\x1b[{}m[{}]\x1b[0m",
+            output_color(&output),
+            output.get_type()
+        ),
+        false => format!("[{}]", output.get_type()),
+    };
     match &output {
         // 带Narsese输出
         IN { content, narsese }
@@ -190,9 +247,9 @@ fn shell_print_output(output: Output) {
             narsese,
         } => match narsese {
             Some(narsese) => {
-                println!("[{}] {}", output.get_type(), FORMAT_ASCII.format(narsese))
+                println!("{type_tag} {}", FORMAT_ASCII.format(narsese))
             }
-            None => println!("[{}] {}", output.get_type(), content),
+            None => println!("{type_tag} {}", content),
         },
         // 仅消息
         ERROR {
@@ -203,12 +260,12 @@ fn shell_print_output(output: Output) {
         | TERMINATED {
             description: content,
         }
-        | OTHER { content } => println!("[{}] {}", output.get_type(), content),
+        | OTHER { content } => println!("{type_tag} {}", content),
         // 操作
         EXE {
             content_raw,
             operation,
-        } => println!("[{}] {} by '{}'", output.get_type(), operation, content_raw),
+        } => println!("{type_tag} {} by '{}'", operation, content_raw),
         // 其它
         output @ UNCLASSIFIED { .. } => {
             println!("{}", output.to_json_string());
@@ -261,6 +318,57 @@ pub fn main() -> Result<()> {
     // * 🚩【2024-07-31 23:20:33】现不再默认最大音量
     // set_max_volume(&mut runtime)?;
     // * 🚩交互
-    shell(runtime, shell_iter_stdin())?;
+    // * 🚩标准输入是终端⇒使用行编辑器，并以记忆区中的概念补全
+    #[cfg(feature = "shell_line_editor")]
+    if std::io::stdin().is_terminal() {
+        let concepts = line_editor::ConceptTerms::default();
+        let inputs = line_editor::shell_iter_line_editor(concepts.clone())?;
+        let update_concepts = move |runtime: &RuntimeAlpha| {
+            *concepts.borrow_mut() = runtime
+                .reasoner()
+                .memory()
+                .iter_concepts()
+                .map(|concept| concept.term().format_ascii())
+                .collect();
+        };
+        return shell(runtime, inputs, update_concepts);
+    }
+    shell(runtime, shell_iter_stdin(), |_| {})?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 不完整的输入不会吞掉后续行
+    #[test]
+    fn unterminated_input() -> Result<()> {
+        let runtime = launcher_dev().launch()?;
+        let inputs = ["<A --> (B", "<C --> D>.", "<E --> F>.\\"].map(String::from);
+        let mut n_handled = 0;
+        shell(runtime, shell_iter_inputs(inputs), |_| n_handled += 1)?;
+        assert_eq!(n_handled, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn input_complete() {
+        assert!(is_input_complete("<A --> B>."));
+        assert!(is_input_complete("<(*, A, B) ==> C>."));
+        assert!(!is_input_complete("<A --> (B"));
+        assert!(!is_input_complete("<A --> B>. \\"));
+        assert_eq!(join_lines("<A --> \\\n B>.\n"), "<A --> B>.");
+    }
+
+    #[test]
+    fn interpret() {
+        assert!(matches!(interpret_cmd("5"), Some(Cmd::CYC(5))));
+        assert!(matches!(interpret_cmd("<A --> B>."), Some(Cmd::NSE(..))));
+        assert!(matches!(
+            interpret_cmd("DEF <A --> B>."),
+            Some(Cmd::Custom { .. })
+        ));
+        assert!(interpret_cmd("<A --> B.").is_none());
+    }
+}
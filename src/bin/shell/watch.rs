@@ -0,0 +1,198 @@
+//! 交互式终端的「监视模式」
+//! * 🎯类似`top`：实时观察推理器的注意力如何转移
+//! * 🚩反复运行若干周期，并重绘终端画面
+//!   * 📍优先级最高的若干概念：优先级、信念数、最佳信念的真值
+//!   * 📍最近的导出结论
+//!   * 📍记忆区、任务缓冲区的规模
+//! * 🚩`Ctrl+C`⇒结束监视，返回终端
+//! * ⚠️导出结论的报告受音量控制：音量过低时，可先输入`VOL 100`
+//!
+//! ## 语法
+//!
+//! `WATCH [每次刷新的周期数=10] [显示的概念数=10]`
+
+use anyhow::Result;
+use narust_158::{
+    entity::Concept,
+    inference::{Budget, Truth},
+    vm::alpha::RuntimeAlpha,
+};
+use navm::{cmd::Cmd, output::Output, vm::VmRuntime};
+use std::{
+    cmp::Reverse,
+    collections::VecDeque,
+    io::{stdout, Write},
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+/// 默认的「每次刷新的周期数」
+const DEFAULT_CYCLES_PER_REFRESH: usize = 10;
+
+/// 默认的「显示的概念数」
+const DEFAULT_TOP_N: usize = 10;
+
+/// 保留的「最近导出结论」数
+const N_RECENT_DERIVATIONS: usize = 10;
+
+/// 两次刷新的最短间隔
+/// * 🎯避免推理过快时画面闪烁、不可读
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 是否正在监视
+/// * 🚩`Ctrl+C`时：正在监视⇒结束监视；否则⇒结束进程
+static WATCHING: AtomicBool = AtomicBool::new(false);
+
+/// 是否已请求中断
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// 监视模式的配置
+#[derive(Debug, Clone, Copy)]
+pub struct WatchConfig {
+    /// 每次刷新的周期数
+    cycles_per_refresh: usize,
+    /// 显示的概念数
+    top_n: usize,
+}
+
+/// 从输入中解析「监视」子命令
+/// * 🚩不区分大小写；参数缺省⇒使用默认值
+/// * 📌非`WATCH`开头⇒返回`None`；参数无效⇒返回错误
+pub fn parse_watch(input: &str) -> Option<Result<WatchConfig>> {
+    let mut words = input.split_whitespace();
+    if !words.next()?.eq_ignore_ascii_case("watch") {
+        return None;
+    }
+    let mut arg = |default| match words.next() {
+        None => Ok(default),
+        Some(word) => match word.parse::<usize>() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(anyhow::anyhow!("Invalid WATCH argument: {word:?}")),
+        },
+    };
+    Some((|| {
+        Ok(WatchConfig {
+            cycles_per_refresh: arg(DEFAULT_CYCLES_PER_REFRESH)?,
+            top_n: arg(DEFAULT_TOP_N)?,
+        })
+    })())
+}
+
+/// 安装`Ctrl+C`处理函数
+/// * 🚩仅安装一次
+#[cfg(feature = "shell_watch")]
+fn install_interrupt_handler() {
+    static INSTALL: std::sync::Once = std::sync::Once::new();
+    INSTALL.call_once(|| {
+        let result = ctrlc::set_handler(|| match WATCHING.load(Ordering::SeqCst) {
+            true => INTERRUPTED.store(true, Ordering::SeqCst),
+            false => std::process::exit(130),
+        });
+        if let Err(e) = result {
+            eprintln!("Failed to install Ctrl+C handler: {e}");
+        }
+    });
+}
+
+/// 未启用特性⇒不处理`Ctrl+C`：直接结束进程
+#[cfg(not(feature = "shell_watch"))]
+fn install_interrupt_handler() {}
+
+/// 运行监视模式，直到被中断
+/// * 🚩期间的输出不打印，只记录导出结论
+pub fn watch(runtime: &mut RuntimeAlpha, config: WatchConfig) -> Result<()> {
+    install_interrupt_handler();
+    INTERRUPTED.store(false, Ordering::SeqCst);
+    WATCHING.store(true, Ordering::SeqCst);
+    let mut recent = VecDeque::with_capacity(N_RECENT_DERIVATIONS);
+    let result = (|| -> Result<()> {
+        while !INTERRUPTED.load(Ordering::SeqCst) {
+            let start = Instant::now();
+            VmRuntime::input_cmd(runtime, Cmd::CYC(config.cycles_per_refresh))?;
+            while let Some(output) = runtime.try_fetch_output()? {
+                record_derivation(&mut recent, output);
+            }
+            draw(runtime, config, &recent)?;
+            if let Some(rest) = MIN_REFRESH_INTERVAL.checked_sub(start.elapsed()) {
+                std::thread::sleep(rest);
+            }
+        }
+        Ok(())
+    })();
+    WATCHING.store(false, Ordering::SeqCst);
+    result
+}
+
+/// 记录导出结论
+/// * 🚩只记录`OUT`与`ANSWER`；超出容量⇒丢弃最早的
+fn record_derivation(recent: &mut VecDeque<String>, output: Output) {
+    let line = match &output {
+        Output::OUT { content_raw, .. } | Output::ANSWER { content_raw, .. } => {
+            format!("[{}] {content_raw}", output.get_type())
+        }
+        _ => return,
+    };
+    if recent.len() >= N_RECENT_DERIVATIONS {
+        recent.pop_front();
+    }
+    recent.push_back(line);
+}
+
+/// 重绘画面
+/// * 🚩清屏后从左上角开始绘制
+fn draw(runtime: &RuntimeAlpha, config: WatchConfig, recent: &VecDeque<String>) -> Result<()> {
+    let reasoner = runtime.reasoner();
+    let memory = reasoner.memory();
+    let task_buffer = reasoner.task_buffer();
+    let mut concepts = memory.iter_concepts().collect::<Vec<_>>();
+    concepts.sort_by_key(|concept| Reverse(concept.priority()));
+
+    let mut out = stdout().lock();
+    write!(out, "\x1b[2J\x1b[H")?;
+    writeln!(
+        out,
+        "NARust-158 watch - time {} | volume {} | {} concepts | {} new tasks, {} novel tasks | Ctrl+C to stop",
+        reasoner.time(),
+        reasoner.volume(),
+        concepts.len(),
+        task_buffer.n_new_tasks(),
+        task_buffer.n_novel_tasks(),
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "{:>8} {:>7} {:>11}  CONCEPT",
+        "PRIORITY", "BELIEFS", "BEST TRUTH"
+    )?;
+    for concept in concepts.iter().take(config.top_n) {
+        writeln!(out, "{}", format_concept(concept))?;
+    }
+    writeln!(out)?;
+    writeln!(out, "RECENT DERIVATIONS")?;
+    for line in recent.iter().rev() {
+        writeln!(out, "  {line}")?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// 格式化概念的一行
+/// * 🚩最佳信念：信念表按排名排序，取第一个
+fn format_concept(concept: &Concept) -> String {
+    let n_beliefs = concept.beliefs().count();
+    let best_truth = match concept.beliefs().next() {
+        Some(belief) => format!(
+            "%{:.2};{:.2}%",
+            belief.frequency().to_float(),
+            belief.confidence().to_float()
+        ),
+        None => "-".into(),
+    };
+    format!(
+        "{:>8.4} {:>7} {:>11}  {}",
+        concept.priority().to_float(),
+        n_beliefs,
+        best_truth,
+        concept.term().format_ascii()
+    )
+}
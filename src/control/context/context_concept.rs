@@ -8,7 +8,7 @@ use super::{ReasonContext, ReasonContextCore, ReasonContextCoreOut, ReasonContex
 use crate::{
     __delegate_from_core,
     control::Reasoner,
-    entity::{Concept, JudgementV1, RCTask, TLink, Task, TaskLink, TermLink},
+    entity::{Concept, JudgementV1, RCTask, Sentence, TLink, Task, TaskLink, TermLink},
     global::{ClockTime, Float},
     parameters::Parameters,
     storage::Memory,
@@ -123,6 +123,23 @@ impl ReasonContextConcept<'_> {
     fn update_current_belief(&mut self) {
         // * 🚩设置当前信念（可空性相对独立）
         self.current_belief = self.updated_current_belief();
+        // * 🚩🆕检测「当前任务」与「当前信念」之间的冲突
+        self.report_current_conflict();
+    }
+
+    /// 🆕检测「当前任务」与新选取的「当前信念」之间的冲突
+    /// * 📌仅针对同内容的判断：亦即后续「匹配推理」中将被修正的一对
+    /// * 🚩未设置「冲突频率阈值」时直接跳过，避免无谓的复制
+    fn report_current_conflict(&mut self) {
+        if self.parameters().conflict_frequency_threshold.is_none() {
+            return;
+        }
+        let belief = unwrap_or_return!(?self.current_belief.clone());
+        let task_judgement = self.current_task().get_().as_judgement().cloned();
+        let judgement = unwrap_or_return!(?task_judgement);
+        if judgement.content() == belief.content() {
+            self.report_conflict(&judgement, &belief);
+        }
     }
 
     /// 通过设置好的（非空的）「当前信念链」返回更新的「当前信念」（所有权）
@@ -136,7 +153,7 @@ impl ReasonContextConcept<'_> {
 
         // * 🚩找到新的「信念」充当「当前信念」并返回（可空性相对独立）
         belief_concept
-            .get_belief(&*self.current_task().get_())
+            .get_belief(&*self.current_task().get_(), self.evidence_overlap_policy())
             // * 🚩语句在此复制，以避开生命周期问题
             .cloned()
     }
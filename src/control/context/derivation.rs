@@ -7,8 +7,8 @@ use crate::{
         BudgetValue, Judgement, JudgementV1, Punctuation, Sentence, SentenceV1, Stamp, Task,
         TruthValue,
     },
-    inference::Budget,
-    language::Term,
+    inference::{Budget, Truth},
+    language::{variable_process::has_unification_q, Term},
     util::ToDisplayAndBrief,
 };
 use nar_dev_utils::RefCount;
@@ -53,17 +53,81 @@ pub trait ContextDerivation: ReasonContext {
             self.report_comment(format!("!!! Ignored: {}", new_task.to_display_long()));
             return;
         }
+        // * 🚩🆕导出的「问题」⇒检查「问题导出」的限制
+        if let Some(limit) = self.question_derivation_limit(&new_task) {
+            self.report_comment(format!(
+                "!!! Ignored ({limit}): {}",
+                new_task.to_display_long()
+            ));
+            return;
+        }
         // * 🚩报告
         self.report_comment(format!("!!! Derived: {}", new_task.to_display_long()));
         let budget_summary = new_task.budget_summary().to_float();
-        if budget_summary > self.silence_percent() {
+        // * 🚩🆕还需满足「导出输出」的订阅条件
+        if budget_summary > self.silence_percent() && self.derived_output_subscribed(&new_task) {
             // only report significant derived Tasks
             self.report_out(&new_task);
         }
         // * 🚩将「导出的新任务」添加到「新任务表」中
+        self.reasoner_mut().statistics.derived_tasks += 1;
         self.add_new_task(new_task);
     }
 
+    /// 🆕检查导出的任务是否满足「导出输出」的订阅条件
+    /// * 🎯减少刷屏的低信度结论：只影响`OUT`报告，不影响推理
+    /// * 🚩判断句：信度、期望均不低于阈值
+    /// * 🚩订阅了词项模式⇒内容须能与之归一
+    fn derived_output_subscribed(&self, new_task: &Task) -> bool {
+        let parameters = self.parameters();
+        if let Some(judgement) = new_task.as_judgement() {
+            if judgement.confidence().to_float() < parameters.derived_output_minimum_confidence
+                || judgement.expectation() < parameters.derived_output_minimum_expectation
+            {
+                return false;
+            }
+        }
+        match self.reasoner().derived_output_pattern() {
+            // * 🚩随机种子固定：过滤结果可重复
+            Some(pattern) => has_unification_q(pattern, new_task.content(), 0),
+            None => true,
+        }
+    }
+
+    /// 🆕检查导出的任务是否超出「问题导出」的限制
+    /// * 🚩仅检查「问题」：判断句总是放行
+    /// * 🚩返回所超出的限制（若有）
+    fn question_derivation_limit(&self, new_task: &Task) -> Option<&'static str> {
+        if !new_task.is_question() {
+            return None;
+        }
+        let parameters = self.parameters();
+        // * 🚩深度：祖先中问题的数目
+        // * 📌不计判断句：反向推理的链条中，被激活的信念亦作为父任务
+        if let Some(max_depth) = parameters.maximum_question_derivation_depth {
+            let depth = new_task
+                .parents()
+                .filter(|(task, _)| task.get_().is_question())
+                .count();
+            if depth > max_depth {
+                return Some("question derivation depth");
+            }
+        }
+        // * 🚩数目：本次推理（同一概念）中已导出的问题
+        // * 📌导出的任务在推理结束后才进入任务缓冲区：在此处计数，方能限制涌入缓冲区的问题
+        if let Some(max_derived) = parameters.maximum_derived_questions_per_concept {
+            let n_derived = self
+                .new_tasks()
+                .iter()
+                .filter(|task| task.is_question())
+                .count();
+            if n_derived >= max_derived {
+                return Some("derived questions per concept");
+            }
+        }
+        None
+    }
+
     /// 🆕仅源自「修正规则」调用，没有「父信念」
     fn double_premise_task_revision(
         &mut self,
@@ -127,7 +191,17 @@ pub trait ContextDerivationConcept: ReasonContextWithLinks {
         self.current_belief().map(|belief|
                 // * 📌此处的「时间戳」一定是「当前信念」的时间戳
                 // * 📄理由：最后返回的信念与「成功时比对的信念」一致（只隔着`clone`）
-                 Stamp::from_merge_unchecked(&*current_task, belief, self.time(), self.max_evidence_base_length()))
+                 Stamp::from_merge_unchecked(&*current_task, belief, self.time(), self.max_evidence_base_length(), self.evidence_interleave_order()))
+    }
+
+    /// 🆕按「证据重合策略」折扣双前提结论的真值
+    /// * 🎯「当前任务」与「当前信念」的证据部分重合时，降低结论信度
+    /// * 🚩没有「当前信念」⇒原样返回
+    fn overlap_discounted_double(&self, truth: TruthValue) -> TruthValue {
+        match self.current_belief() {
+            Some(belief) => self.overlap_discounted(truth, &*self.current_task().get_(), belief),
+            None => truth,
+        }
     }
 
     /* --------------- new task building --------------- */
@@ -143,7 +217,8 @@ pub trait ContextDerivationConcept: ReasonContextWithLinks {
     ) {
         // * 🚩尝试创建「新时间戳」然后使用之
         if let Some(new_stamp) = self.generate_new_stamp_double() {
-            let new_truth_revisable = new_truth.map(|truth| (truth, true));
+            let new_truth_revisable =
+                new_truth.map(|truth| (self.overlap_discounted_double(truth), true));
             self.double_premise_task_full(
                 None, // * 🚩默认「当前任务」
                 new_content,
@@ -187,7 +262,7 @@ pub trait ContextDerivationConcept: ReasonContextWithLinks {
                 None, // * 🚩默认「当前任务」
                 new_content,
                 // * 🚩默认「不可修正」，其它相同
-                new_truth.map(|truth| (truth, false)),
+                new_truth.map(|truth| (self.overlap_discounted_double(truth.into()), false)),
                 new_budget,
                 new_stamp,
             )
@@ -309,3 +384,203 @@ pub trait ContextDerivationConcept: ReasonContextWithLinks {
 /// * 📝需要采用`?Sized`以包括【运行时尺寸未定】的对象
 ///   * ⚠️不然默认仅对[`Sized`]实现
 impl<T: ?Sized + ReasonContextWithLinks> ContextDerivationConcept for T {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        global::Float,
+        inference::tools::{create_reasoner, set_max_volume, ENGINE_DEV},
+        language::Term,
+        ok,
+        parameters::{Parameters, DEFAULT_PARAMETERS},
+        test_term as term,
+        util::AResult,
+    };
+    use narsese::{api::GetTerm, lexical::Narsese};
+    use navm::output::Output;
+
+    /// 「问题导出」的推理过程：由「A→B」与「A→C?」导出新问题
+    const INPUTS: &str = "nse <A --> B>.\nnse <A --> C>?\ncyc 20";
+
+    /// 运行推理，统计「导出的问题」及「因限制而忽略」的报告数目
+    fn count_derived_questions(parameters: Parameters) -> (usize, usize) {
+        let mut reasoner = create_reasoner(parameters, ENGINE_DEV);
+        set_max_volume(&mut reasoner);
+        let outputs = reasoner.input_cmds_and_fetch_out(INPUTS);
+        let n_derived = outputs
+            .iter()
+            .filter(|output| match output {
+                Output::OUT { content_raw, .. } => content_raw.contains('?'),
+                _ => false,
+            })
+            .count();
+        let n_ignored = outputs
+            .iter()
+            .filter(|output| match output {
+                Output::COMMENT { content } => {
+                    content.contains("question derivation depth")
+                        || content.contains("derived questions per concept")
+                }
+                _ => false,
+            })
+            .count();
+        (n_derived, n_ignored)
+    }
+
+    #[test]
+    fn unlimited_by_default() {
+        let (n_derived, n_ignored) = count_derived_questions(DEFAULT_PARAMETERS);
+        assert!(n_derived > 0);
+        assert_eq!(n_ignored, 0);
+    }
+
+    #[test]
+    fn maximum_question_derivation_depth() {
+        // * 🚩深度为0：不导出任何问题
+        let (n_derived, n_ignored) = count_derived_questions(Parameters {
+            maximum_question_derivation_depth: Some(0),
+            ..DEFAULT_PARAMETERS
+        });
+        assert_eq!(n_derived, 0);
+        assert!(n_ignored > 0);
+        // * 🚩深度为1：仍可由输入的问题直接导出
+        let (n_derived, _) = count_derived_questions(Parameters {
+            maximum_question_derivation_depth: Some(1),
+            ..DEFAULT_PARAMETERS
+        });
+        assert!(n_derived > 0);
+    }
+
+    #[test]
+    fn maximum_derived_questions_per_concept() {
+        let (unlimited, _) = count_derived_questions(DEFAULT_PARAMETERS);
+        // * 🚩上限为0：不导出任何问题
+        let (n_derived, n_ignored) = count_derived_questions(Parameters {
+            maximum_derived_questions_per_concept: Some(0),
+            ..DEFAULT_PARAMETERS
+        });
+        assert_eq!(n_derived, 0);
+        assert!(n_ignored > 0);
+        // * 🚩上限为2：导出的问题严格少于无限制时
+        let (n_derived, n_ignored) = count_derived_questions(Parameters {
+            maximum_derived_questions_per_concept: Some(2),
+            ..DEFAULT_PARAMETERS
+        });
+        assert!(n_ignored > 0);
+        assert!(
+            0 < n_derived && n_derived < unlimited,
+            "{n_derived} vs {unlimited}"
+        );
+    }
+
+    /// 「导出结论」的推理过程：由「A→B」与「B→C」导出各种结论
+    const INPUTS_JUDGEMENT: &str = "nse <A --> B>.\nnse <B --> C>.\ncyc 20";
+
+    /// 运行推理，收集所有`OUT`报告中的「词项-频率-信度」
+    fn derived_outputs(parameters: Parameters, pattern: Option<Term>) -> Vec<(Term, Float, Float)> {
+        let mut reasoner = create_reasoner(parameters, ENGINE_DEV);
+        set_max_volume(&mut reasoner);
+        reasoner.set_derived_output_pattern(pattern);
+        let outputs = reasoner.input_cmds_and_fetch_out(INPUTS_JUDGEMENT);
+        outputs
+            .iter()
+            .filter_map(|output| match output {
+                Output::OUT {
+                    narsese: Some(narsese @ Narsese::Task(task)),
+                    ..
+                } => Some((
+                    Term::from_lexical(narsese.get_term().clone()).ok()?,
+                    task.sentence.truth.first()?.parse().ok()?,
+                    task.sentence.truth.get(1)?.parse().ok()?,
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn derived_output_minimum_confidence() {
+        let unfiltered = derived_outputs(DEFAULT_PARAMETERS, None);
+        assert!(unfiltered.iter().any(|(_, _, c)| *c < 0.5));
+        // * 🚩只报告信度不低于阈值的结论
+        let filtered = derived_outputs(
+            Parameters {
+                derived_output_minimum_confidence: 0.5,
+                ..DEFAULT_PARAMETERS
+            },
+            None,
+        );
+        assert!(!filtered.is_empty());
+        assert!(filtered.len() < unfiltered.len());
+        assert!(filtered.iter().all(|(_, _, c)| *c >= 0.5), "{filtered:?}");
+    }
+
+    #[test]
+    fn derived_output_minimum_expectation() {
+        // * 📝期望 = 信度 × (频率 - 0.5) + 0.5
+        let expectation = |f: &Float, c: &Float| c * (f - 0.5) + 0.5;
+        let unfiltered = derived_outputs(DEFAULT_PARAMETERS, None);
+        assert!(unfiltered.iter().any(|(_, f, c)| expectation(f, c) < 0.75));
+        // * 🚩只报告期望不低于阈值的结论
+        // * ⚠️输出的真值有舍入：留出误差
+        let filtered = derived_outputs(
+            Parameters {
+                derived_output_minimum_expectation: 0.75,
+                ..DEFAULT_PARAMETERS
+            },
+            None,
+        );
+        assert!(!filtered.is_empty());
+        assert!(filtered.len() < unfiltered.len());
+        assert!(
+            filtered
+                .iter()
+                .all(|(_, f, c)| expectation(f, c) >= 0.75 - 1e-3),
+            "{filtered:?}"
+        );
+    }
+
+    #[test]
+    fn derived_output_pattern() -> AResult {
+        let pattern = term!("<?x --> C>");
+        let filtered = derived_outputs(DEFAULT_PARAMETERS, Some(pattern));
+        // * 🚩只报告与模式匹配的结论
+        assert!(!filtered.is_empty());
+        let c = term!("C");
+        let matches = |term: &Term| term.as_statement().is_some_and(|s| *s.predicate == c);
+        assert!(
+            filtered.iter().all(|(term, _, _)| matches(term)),
+            "{filtered:?}"
+        );
+        ok!()
+    }
+
+    /// 「经由导出问题回答」的推理过程
+    /// * 📝`<A --> C>?`与`<B --> C>.`导出问题`<A --> B>?`，其解答经演绎回答原问题
+    const INPUTS_ANSWER: &str =
+        "nse <B --> C>.\ncyc 5\nnse <A --> C>?\ncyc 5\nnse <A --> B>.\ncyc 300";
+
+    /// 统计输出中「经由导出问题回答」的报告数目
+    fn count_answered_via_derived_questions(outputs: &[Output]) -> usize {
+        outputs
+            .iter()
+            .filter(|output| match output {
+                Output::COMMENT { content } => content.contains("Answered via derived questions"),
+                _ => false,
+            })
+            .count()
+    }
+
+    #[test]
+    fn answered_via_derived_questions() {
+        let mut reasoner = create_reasoner(DEFAULT_PARAMETERS, ENGINE_DEV);
+        set_max_volume(&mut reasoner);
+        // * 🚩新的判断句解答输入的问题：沿其父任务追溯
+        let outputs = reasoner.input_cmds_and_fetch_out(INPUTS_ANSWER);
+        assert!(count_answered_via_derived_questions(&outputs) > 0);
+        // * 🚩输入的问题被已有信念解答：沿信念所源自的任务追溯
+        let outputs = reasoner.input_cmds_and_fetch_out("nse <A --> C>?\ncyc 1");
+        assert!(outputs.iter().any(|output| output.is_type("ANSWER")));
+        assert!(count_answered_via_derived_questions(&outputs) > 0);
+    }
+}
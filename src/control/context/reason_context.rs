@@ -11,14 +11,18 @@
 use crate::{
     control::{util_outputs, Reasoner},
     entity::{
-        Concept, JudgementV1, Punctuation, RCTask, Sentence, ShortFloat, Task, TaskLink, TermLink,
+        Concept, Judgement, JudgementV1, Punctuation, RCTask, RevisionRecord, Sentence, ShortFloat,
+        Task, TaskLink, TermLink, TruthValue,
     },
     global::{ClockTime, Float},
+    inference::{
+        BudgetPolicyRef, Evidential, InterleaveOrder, OverlapPolicy, Truth, TruthCalculusRef,
+    },
     language::Term,
     parameters::Parameters,
     storage::Memory,
 };
-use nar_dev_utils::RefCount;
+use nar_dev_utils::{unwrap_or_return, RefCount};
 use navm::output::Output;
 use rand::RngCore;
 use std::ops::{Deref, DerefMut};
@@ -46,10 +50,53 @@ pub trait ReasonContext {
     /// * 📝可变性：只读
     fn parameters(&self) -> &Parameters;
 
+    /// 🆕访问推理器所选的「真值演算」
+    /// * 🎯所有推理规则均通过此演算计算真值
+    /// * 📌静态引用：不借用上下文，可与其它操作交织
+    fn truth_calculus(&self) -> TruthCalculusRef {
+        self.reasoner().truth_calculus()
+    }
+
+    /// 🆕访问推理器所选的「预算策略」
+    /// * 🎯所有推理规则均通过此策略计算预算值
+    /// * 📌静态引用：不借用上下文，可与其它操作交织
+    fn budget_policy(&self) -> BudgetPolicyRef {
+        self.reasoner().budget_policy()
+    }
+
     fn max_evidence_base_length(&self) -> usize {
         self.parameters().maximum_stamp_length
     }
 
+    /// 🆕访问「当前超参数」中的「证据重合策略」
+    fn evidence_overlap_policy(&self) -> OverlapPolicy {
+        self.parameters().evidence_overlap_policy
+    }
+
+    /// 🆕访问「当前超参数」中的「证据基交织顺序」
+    fn evidence_interleave_order(&self) -> InterleaveOrder {
+        self.parameters().evidence_interleave_order
+    }
+
+    /// 🆕按「证据重合策略」折扣双前提结论的信度
+    /// * 🎯部分重合的证据不应被完整地重复计入
+    /// * 📌严格策略下不会有重合：原样返回
+    fn overlap_discounted(
+        &self,
+        mut truth: TruthValue,
+        first: &impl Evidential,
+        second: &impl Evidential,
+    ) -> TruthValue {
+        let discount = self
+            .evidence_overlap_policy()
+            .confidence_discount(first, second);
+        if discount < 1.0 {
+            let confidence = truth.confidence().to_float() * discount;
+            *truth.confidence_mut() = ShortFloat::from_float(confidence);
+        }
+        truth
+    }
+
     /// 🆕访问「当前超参数」中的「单前提推理依赖度」
     /// * 🎯结构规则中的「单前提推理」情形
     /// * 🚩返回短浮点类型
@@ -58,6 +105,16 @@ pub trait ReasonContext {
         ShortFloat::from_float(self.parameters().reliance)
     }
 
+    /// 🆕作为推理前提的真值
+    /// * 🎯「定义」作为前提时，以「依赖度」代替其信度
+    ///   * 📌所得真值不带「分析性」标记：由此导出的仍是经验性知识
+    fn premise_truth(&self, judgement: &impl Judgement) -> TruthValue {
+        match judgement.is_definition() {
+            true => TruthValue::new_fc(judgement.frequency(), self.reasoning_reliance()),
+            false => TruthValue::from(judgement),
+        }
+    }
+
     /// 获取「音量百分比」
     /// * 🎯在「推理上下文」中无需获取「推理器」`getReasoner`
     /// * 📌音量越大，允许的输出越多
@@ -99,6 +156,9 @@ pub trait ReasonContext {
     /// 获取「新任务」的数量
     fn num_new_tasks(&self) -> usize;
 
+    /// 🆕获取本次推理中已导出的「新任务」
+    fn new_tasks(&self) -> &[Task];
+
     /// 添加「新任务」
     /// * 🎯添加推理导出的任务
     /// * 🚩【2024-06-26 20:51:20】目前固定为「实际值」
@@ -135,6 +195,53 @@ pub trait ReasonContext {
         self.report(util_outputs::output_error(description))
     }
 
+    /// 🆕检测两个同内容判断之间的「冲突」，若有则报告
+    /// * 🎯定位知识库中的矛盾：频率相差悬殊、信度均不低的两个判断
+    /// * 🚩仅在设置了「冲突频率阈值」时检测
+    /// * 📌以「信息」而非「注释」输出：不受音量限制，便于筛选
+    fn report_conflict(&mut self, judgement1: &impl Judgement, judgement2: &impl Judgement) {
+        let parameters = self.parameters();
+        let threshold = unwrap_or_return!(?parameters.conflict_frequency_threshold);
+        let min_confidence = parameters.conflict_minimum_confidence;
+        // * 🚩任一判断信度过低⇒不算冲突
+        if [judgement1.confidence(), judgement2.confidence()]
+            .iter()
+            .any(|c| c.to_float() < min_confidence)
+        {
+            return;
+        }
+        // * 🚩频率差距未超阈值⇒不算冲突
+        let gap = (judgement1.frequency().to_float() - judgement2.frequency().to_float()).abs();
+        if gap <= threshold {
+            return;
+        }
+        self.report(util_outputs::output_info(format!(
+            "!!! Conflict (frequency gap {gap:.4}): {} <=> {}",
+            judgement1.to_display_long(),
+            judgement2.to_display_long()
+        )))
+    }
+
+    /// 🆕将一次「信念修正」记入所修正词项的概念中
+    /// * 🎯保留被修正信念的旧真值，供事后分析
+    /// * 🚩未设置「修正历史长度」时直接跳过
+    /// * ⚠️概念不存在（不在记忆区中）⇒不记录
+    fn record_revision(&mut self, content: &Term, record: RevisionRecord) {
+        if self.parameters().revision_history_length == 0 {
+            return;
+        }
+        let concept = match content == self.current_term() {
+            true => Some(self.current_concept_mut()),
+            false => self
+                .reasoner_mut()
+                .memory_mut()
+                .term_to_concept_mut(content),
+        };
+        if let Some(concept) = concept {
+            concept.record_revision(record);
+        }
+    }
+
     /// 获取「当前概念」（不可变）
     fn current_concept(&self) -> &Concept;
 
@@ -384,6 +491,10 @@ impl ReasonContextCoreOut {
         self.new_tasks.len()
     }
 
+    pub fn new_tasks(&self) -> &[Task] {
+        &self.new_tasks
+    }
+
     pub fn add_new_task(&mut self, task: Task) {
         self.new_tasks.push(task);
     }
@@ -424,6 +535,10 @@ macro_rules! __delegate_from_core {
             self.outs.num_new_tasks()
         }
 
+        fn new_tasks(&self) -> &[Task] {
+            self.outs.new_tasks()
+        }
+
         fn add_new_task(&mut self, task: Task) {
             self.outs.add_new_task(task)
         }
@@ -7,7 +7,7 @@ use crate::{
     entity::{
         BudgetValue, Concept, Item, RCTask, TLink, TLinkType, TaskLink, TermLink, TermLinkTemplate,
     },
-    inference::{Budget, BudgetFunctions},
+    inference::{Budget, BudgetFunctions, BudgetPolicyRef},
     language::{CompoundTermRef, Term},
     storage::Memory,
     util::ToDisplayAndBrief,
@@ -165,6 +165,7 @@ impl ReasonContextDirect<'_> {
     /// 搭建任务链
     fn build_task_links(&mut self) {
         // * 🚩载入自身字段 | 无法预加载，避免借用问题
+        let budget_policy = self.core.reasoner.budget_policy();
         let concept = &mut self.core.current_concept;
         let memory = &mut self.core.reasoner.memory;
         let task = &self.current_task;
@@ -186,7 +187,7 @@ impl ReasonContextDirect<'_> {
         // 对自身 //
         // * 🚩对当前任务构造任务链，链接到传入的任务 | 构造「自身」
         let self_link = TaskLink::new_self(task.clone()); // link type: SELF
-        let result = concept.insert_task_link_outer(memory, self_link);
+        let result = concept.insert_task_link_outer(memory, budget_policy, self_link);
         deal_overflowed_task_link(result);
 
         // 对子项 //
@@ -206,7 +207,8 @@ impl ReasonContextDirect<'_> {
         }
         // * 🚩仅在「预算达到阈值」时：遍历预先构建好的所有「子项词项链模板」，递归链接到任务
         for template in concept.link_templates_to_self() {
-            let result = memory.link_task_link_from_template(template, task, &sub_budget);
+            let result =
+                memory.link_task_link_from_template(budget_policy, template, task, &sub_budget);
             // * 🚩对「溢出的任务链」作报告
             deal_overflowed_task_link(result);
         }
@@ -304,10 +306,11 @@ impl Concept {
     fn insert_task_link_outer(
         &mut self,
         memory: &mut Memory,
+        budget_policy: BudgetPolicyRef,
         task_link: TaskLink,
     ) -> Option<TaskLink> {
         // * 📝注意：任务链の预算 ≠ 任务の预算；「任务链」与「所链接的任务」是不同的Item对象
-        let new_budget = memory.activate_concept_calculate(self, &task_link);
+        let new_budget = memory.activate_concept_calculate(budget_policy, self, &task_link);
         let overflowed_task_link = self.put_task_link_back(task_link);
         // * 🚩插入「任务链」的同时，以「任务链」激活概念 | 直接传入【可预算】的任务链
         Memory::activate_concept_apply(self, new_budget);
@@ -323,6 +326,7 @@ impl Memory {
     #[must_use]
     fn link_task_link_from_template(
         &mut self,
+        budget_policy: BudgetPolicyRef,
         template: &TermLinkTemplate,
         task: &RCTask,
         sub_budget: &impl Budget,
@@ -335,20 +339,25 @@ impl Memory {
         let key = component_concept.key().clone();
 
         // * 🚩插入任务链，并返回「溢出的任务链」
-        self.insert_task_link_inner(&key, link)
+        self.insert_task_link_inner(budget_policy, &key, link)
     }
 
     /// 向「概念」插入任务链
     /// * 📌该方法针对【在记忆区中】的概念
     ///   * 📝此时需要考虑借用问题
     #[must_use]
-    fn insert_task_link_inner(&mut self, key: &str, link: TaskLink) -> Option<TaskLink> {
+    fn insert_task_link_inner(
+        &mut self,
+        budget_policy: BudgetPolicyRef,
+        key: &str,
+        link: TaskLink,
+    ) -> Option<TaskLink> {
         // * 🚩先拿出对应的概念
         // * 📝【2024-06-29 02:45:55】此处通过「先拿出概念，再激活，最后才放回」暂且解决了「长期稳定性中袋mass下溢」问题
         let mut component_concept = self.pick_out_concept(key)?;
 
         // * 🚩计算预算值
-        let new_budget = self.activate_concept_calculate(&component_concept, &link);
+        let new_budget = self.activate_concept_calculate(budget_policy, &component_concept, &link);
 
         // * 🚩放入任务链 & 更新预算值
         let overflowed_task_link = component_concept.put_in_task_link(link);
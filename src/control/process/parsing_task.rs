@@ -25,6 +25,32 @@ impl Reasoner {
         narsese: LexicalTask,
         stamp_current_serial: ClockTime,
         task_current_serial: Serial,
+    ) -> Result<Task> {
+        self.parse_task_as(narsese, stamp_current_serial, task_current_serial, false)
+    }
+
+    /// 🆕将词法Narsese任务解析为「定义」
+    /// * 🎯输入「正方形是长方形」这类定义性、本体性知识
+    /// * 📌定义：不可修正的判断
+    ///   * 🚩不参与修正，不被遗忘，推理时以「依赖度」代替其信度
+    /// * ⚠️只有判断句能作为定义
+    pub fn parse_definition(
+        &self,
+        narsese: LexicalTask,
+        stamp_current_serial: ClockTime,
+        task_current_serial: Serial,
+    ) -> Result<Task> {
+        self.parse_task_as(narsese, stamp_current_serial, task_current_serial, true)
+    }
+
+    /// 解析任务的完整实现
+    /// * 🚩`is_definition`：是否作为「定义」解析
+    fn parse_task_as(
+        &self,
+        narsese: LexicalTask,
+        stamp_current_serial: ClockTime,
+        task_current_serial: Serial,
+        is_definition: bool,
     ) -> Result<Task> {
         use Punctuation::*;
 
@@ -76,11 +102,19 @@ impl Reasoner {
                 Some((truth, revisable))
             }
             // * 🚩疑问句 ⇒ 空
+            Question if is_definition => return Err(anyhow!("Only judgements can be definitions")),
             Question => None,
         };
 
         // 构造语句
         let sentence = SentenceV1::with_punctuation(content, punctuation, stamp, truth_revisable)?;
+        // * 🚩🆕定义⇒标记为定义（不可修正）
+        let sentence = match sentence {
+            SentenceV1::JudgementV1(judgement) if is_definition => {
+                judgement.into_definition().into()
+            }
+            sentence => sentence,
+        };
 
         // * 🚩解析任务
 
@@ -117,4 +151,11 @@ impl Reasoner {
         let task_current_serial = self.updated_task_current_serial();
         self.parse_task(narsese, stamp_current_serial, task_current_serial)
     }
+
+    /// 将任务视作一个新的「定义」解析
+    pub fn parse_new_definition(&mut self, narsese: LexicalTask) -> Result<Task> {
+        let stamp_current_serial = self.updated_stamp_current_serial();
+        let task_current_serial = self.updated_task_current_serial();
+        self.parse_definition(narsese, stamp_current_serial, task_current_serial)
+    }
 }
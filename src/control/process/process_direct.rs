@@ -79,9 +79,11 @@ impl Reasoner {
 
         // * 🚩调整概念的预算值 @「激活」
         // * 📌断言：此处一定是「概念在记忆区之外」
-        let new_concept_budget = context
-            .memory()
-            .activate_concept_calculate(context.current_concept(), &*context.current_task().get_());
+        let new_concept_budget = context.memory().activate_concept_calculate(
+            context.budget_policy(),
+            context.current_concept(),
+            &*context.current_task().get_(),
+        );
         context
             .current_concept_mut()
             .copy_budget_from(&new_concept_budget);
@@ -15,6 +15,7 @@
 //! * ♻️【2024-06-26 11:59:58】开始根据改版OpenNARS重写
 
 use crate::control::Reasoner;
+use std::time::Instant;
 
 impl Reasoner {
     /// 处理「工作周期」
@@ -28,16 +29,22 @@ impl Reasoner {
 
     /// 内部所用的「工作周期」
     /// * ⚠️不负责「时钟更新」
+    /// * 🚩🆕各阶段的耗时记入[运行统计](Reasoner::statistics)
     fn work_cycle(&mut self) {
         self.report_comment(format!("--- {} ---", self.time()));
+        self.statistics.cycles += 1;
 
         // * 🚩本地任务直接处理 阶段 * //
+        let start = Instant::now();
         let has_result = self.process_direct();
+        self.statistics.direct_time += start.elapsed();
 
         // * 🚩内部概念高级推理 阶段 * //
         // * 📝OpenNARS的逻辑：一次工作周期，只能在「直接推理」与「概念推理」中选择一个
         if !has_result {
+            let start = Instant::now();
             self.process_reason();
+            self.statistics.reason_time += start.elapsed();
         }
 
         // * 🚩最后收尾 阶段 * //
@@ -4,10 +4,11 @@
 //!
 //! * ♻️【2024-06-26 12:02:36】开始根据改版OpenNARS重写
 
-use super::ReasonRecorder;
+use super::{ReasonRecorder, ReasonerStatistics};
 use crate::{
     global::ClockTime,
-    inference::InferenceEngine,
+    inference::{BudgetPolicyRef, InferenceEngine, StandardBudget, StandardNal, TruthCalculusRef},
+    language::Term,
     parameters::Parameters,
     storage::{Memory, TaskBuffer},
     util::Serial,
@@ -42,12 +43,32 @@ pub struct Reasoner {
     /// 使用的推理引擎
     pub(in super::super) inference_engine: InferenceEngine,
 
+    /// 🆕使用的真值演算
+    /// * 🎯在同一套推理规则上比较不同的不确定性演算
+    /// * 🚩默认为[标准NAL演算](StandardNal)
+    truth_calculus: TruthCalculusRef,
+
+    /// 🆕使用的预算策略
+    /// * 🎯在同一套推理规则上比较不同的注意力分配方案
+    /// * 🚩默认为[标准预算策略](StandardBudget)
+    budget_policy: BudgetPolicyRef,
+
+    /// 🆕订阅的「导出结论」词项模式
+    /// * 🎯只报告关心的导出结论
+    /// * 📌模式中的查询变量可匹配任意词项
+    /// * 🚩默认为空：报告所有导出结论
+    derived_output_pattern: Option<Term>,
+
     /// 推理过程的「中间数据」
     pub(in super::super) task_buffer: TaskBuffer,
 
     /// 系统时钟
     clock: ClockTime,
 
+    /// 🆕运行统计
+    /// * 📌在工作周期、「导出任务」处直接记录
+    pub(in super::super) statistics: ReasonerStatistics,
+
     // ! ❌不再状态「运行中」，因为NARust-158是始终运行的
 
     // ! ❌不再需要「待步进的步数」，因为NARust-158是单线程的
@@ -78,16 +99,24 @@ impl Reasoner {
         parameters: impl Into<Parameters>,
         inference_engine: impl Into<InferenceEngine>,
     ) -> Self {
+        let parameters = parameters.into();
         Self {
             name: name.into(),
             // * 🚩默认为空
-            parameters: parameters.into(),
-            memory: Memory::default(),
+            parameters,
+            // * 🚩记忆区与推理器共用超参数：概念的容量、排行策略等均取自其中
+            //   * ⚠️不可用`Memory::default`：否则推理器上设置的「排行策略」等无法传到概念
+            //   * 📌同时使「概念袋容量」等记忆区超参数生效
+            memory: Memory::new(parameters),
             recorder: ReasonRecorder::default(),
             inference_engine: inference_engine.into(),
+            truth_calculus: StandardNal::REF,
+            budget_policy: StandardBudget::REF,
+            derived_output_pattern: None,
             task_buffer: TaskBuffer::default(),
             // * 🚩默认为0/false
             clock: 0,
+            statistics: ReasonerStatistics::default(),
             volume: 0,
             stamp_current_serial: 0,
             task_current_serial: 0,
@@ -115,6 +144,7 @@ impl Reasoner {
 
         // * 🚩重置状态变量
         self.clock = 0;
+        self.statistics = ReasonerStatistics::default();
         self.stamp_current_serial = 0;
         self.task_current_serial = 0;
 
@@ -139,11 +169,50 @@ impl Reasoner {
         &mut self.memory
     }
 
+    /// 获取任务缓冲区（不可变引用）
+    pub fn task_buffer(&self) -> &TaskBuffer {
+        &self.task_buffer
+    }
+
     /// 获取超参数（不可变引用）
     pub fn parameters(&self) -> &Parameters {
         &self.parameters
     }
 
+    /// 获取真值演算
+    pub fn truth_calculus(&self) -> TruthCalculusRef {
+        self.truth_calculus
+    }
+
+    /// 🆕设置真值演算
+    /// * 📌只影响之后的推理：已有的真值不会重新计算
+    pub fn set_truth_calculus(&mut self, truth_calculus: TruthCalculusRef) {
+        self.truth_calculus = truth_calculus;
+    }
+
+    /// 获取预算策略
+    pub fn budget_policy(&self) -> BudgetPolicyRef {
+        self.budget_policy
+    }
+
+    /// 🆕设置预算策略
+    /// * 📌只影响之后的推理：已有的预算值不会重新计算
+    pub fn set_budget_policy(&mut self, budget_policy: BudgetPolicyRef) {
+        self.budget_policy = budget_policy;
+    }
+
+    /// 获取订阅的「导出结论」词项模式
+    pub fn derived_output_pattern(&self) -> Option<&Term> {
+        self.derived_output_pattern.as_ref()
+    }
+
+    /// 🆕设置订阅的「导出结论」词项模式
+    /// * 📌只影响`OUT`输出，不影响推理
+    /// * 🚩空值⇒取消订阅，报告所有导出结论
+    pub fn set_derived_output_pattern(&mut self, pattern: Option<Term>) {
+        self.derived_output_pattern = pattern;
+    }
+
     /// 获取音量等级
     pub fn volume(&self) -> usize {
         self.volume
@@ -0,0 +1,235 @@
+//! 将记忆区中的「概念图」导出为Graphviz DOT格式
+//! * 🎯为论文配图、调试提供可视化的知识结构
+//! * 📌节点：概念，大小随优先级增长
+//! * 📌边：词项链（实线）、任务链（虚线）
+//!   * 📍标签为[链接类型](TLinkType)
+//!   * 📍颜色随链接的优先级由蓝（低）变红（高）
+//! * 📌可选：只导出「焦点概念」周围指定半径内的概念
+//!   * 📍半径以链接跳数计，不区分链接方向
+
+use super::Reasoner;
+use crate::{
+    entity::{Concept, Sentence, TLink, TLinkType},
+    global::Float,
+    inference::Budget,
+    language::Term,
+    storage::Memory,
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Write,
+};
+
+/// 「概念图」的导出选项
+#[derive(Debug, Clone, Default)]
+pub struct GraphExportOptions {
+    /// 焦点概念的词项
+    /// * 📌`None`⇒导出所有概念
+    pub focus: Option<Term>,
+    /// 焦点周围的半径（链接跳数）
+    /// * 📌仅在指定焦点时有效
+    pub radius: usize,
+}
+
+/// 图中的一条边
+struct Edge {
+    /// 起点概念的键
+    from: String,
+    /// 终点概念的键
+    to: String,
+    /// 链接类型
+    link_type: TLinkType,
+    /// 链接的优先级
+    priority: Float,
+    /// 是否为任务链
+    is_task_link: bool,
+}
+
+impl Reasoner {
+    /// 将记忆区中的概念图导出为DOT格式
+    /// * 🚩只导出「两端均为已有概念」的链接
+    pub fn export_dot(&self, options: &GraphExportOptions) -> String {
+        let concepts = self
+            .memory
+            .iter_concepts()
+            .map(|concept| (Memory::term_to_key(concept.term()), concept))
+            .collect::<HashMap<_, _>>();
+        let edges = self
+            .memory
+            .iter_concepts()
+            .flat_map(concept_edges)
+            .filter(|edge| concepts.contains_key(&edge.to))
+            .collect::<Vec<_>>();
+        // 按焦点筛选
+        let included = match &options.focus {
+            Some(focus) => nodes_within(&edges, Memory::term_to_key(focus), options.radius),
+            None => concepts.keys().cloned().collect(),
+        };
+        // 按记忆区中的顺序输出，保证结果稳定
+        let mut dot = String::from("digraph memory {\n");
+        dot.push_str("    node [shape=ellipse, style=filled, fillcolor=\"#f5f5f5\"];\n");
+        for concept in self.memory.iter_concepts() {
+            let key = Memory::term_to_key(concept.term());
+            if included.contains(&key) {
+                let _ = writeln!(dot, "    {}", format_node(&key, concept));
+            }
+        }
+        for edge in &edges {
+            if included.contains(&edge.from) && included.contains(&edge.to) {
+                let _ = writeln!(dot, "    {}", format_edge(edge));
+            }
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+/// 收集一个概念的所有出边
+/// * 🚩词项链⇒指向目标词项的概念
+/// * 🚩任务链⇒指向目标任务内容的概念
+/// * ⚠️跳过「指向自身」的任务链
+fn concept_edges(concept: &Concept) -> Vec<Edge> {
+    let from = Memory::term_to_key(concept.term());
+    let term_links = concept.iter_term_links().map(|link| Edge {
+        from: from.clone(),
+        to: Memory::term_to_key(&link.target()),
+        link_type: link.link_type(),
+        priority: link.priority().to_float(),
+        is_task_link: false,
+    });
+    let task_links = concept
+        .iter_task_links()
+        .filter(|link| link.link_type() != TLinkType::SELF)
+        .map(|link| Edge {
+            from: from.clone(),
+            to: Memory::term_to_key(link.target().content()),
+            link_type: link.link_type(),
+            priority: link.priority().to_float(),
+            is_task_link: true,
+        });
+    term_links.chain(task_links).collect()
+}
+
+/// 广度优先搜索：焦点周围指定半径内的节点
+/// * 🚩链接视作无向边
+/// * 📌焦点没有任何链接⇒只有焦点自身
+fn nodes_within(edges: &[Edge], focus: String, radius: usize) -> HashSet<String> {
+    let mut neighbors = HashMap::<&str, Vec<&str>>::new();
+    for edge in edges {
+        neighbors.entry(&edge.from).or_default().push(&edge.to);
+        neighbors.entry(&edge.to).or_default().push(&edge.from);
+    }
+    let mut included = HashSet::new();
+    if !neighbors.contains_key(focus.as_str()) {
+        // 孤立的焦点概念：仍然输出自身
+        included.insert(focus);
+        return included;
+    }
+    let mut queue = VecDeque::from([(focus.clone(), 0)]);
+    included.insert(focus);
+    while let Some((key, distance)) = queue.pop_front() {
+        if distance >= radius {
+            continue;
+        }
+        for &next in neighbors.get(key.as_str()).into_iter().flatten() {
+            if included.insert(next.to_string()) {
+                queue.push_back((next.to_string(), distance + 1));
+            }
+        }
+    }
+    included
+}
+
+/// 转义DOT中带引号的字符串
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// 由优先级计算颜色
+/// * 🚩HSV色彩：色相从蓝（0.66）到红（0）
+fn priority_color(priority: Float) -> String {
+    format!("{:.3} 0.850 0.850", 0.66 * (1.0 - priority.clamp(0.0, 1.0)))
+}
+
+/// 格式化节点
+/// * 🚩大小、字号随优先级增长
+fn format_node(key: &str, concept: &Concept) -> String {
+    let priority = concept.priority().to_float();
+    format!(
+        "\"{}\" [label=\"{}\", width={:.2}, fontsize={:.1}, tooltip=\"priority={:.4}\"];",
+        escape(key),
+        escape(&concept.term().format_ascii()),
+        0.5 + 1.5 * priority,
+        10.0 + 14.0 * priority,
+        priority,
+    )
+}
+
+/// 格式化边
+/// * 🚩词项链为实线，任务链为虚线
+fn format_edge(edge: &Edge) -> String {
+    format!(
+        "\"{}\" -> \"{}\" [label=\"{:?}\", color=\"{}\", fontcolor=\"{}\", style={}];",
+        escape(&edge.from),
+        escape(&edge.to),
+        edge.link_type,
+        priority_color(edge.priority),
+        priority_color(edge.priority),
+        match edge.is_task_link {
+            true => "dashed",
+            false => "solid",
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        inference::InferenceEngine, ok, parameters::DEFAULT_PARAMETERS, test_term as term,
+        util::AResult, vm::alpha::RuntimeAlpha,
+    };
+    use navm::{cmd::Cmd, vm::VmRuntime};
+
+    /// 构造一个有链接的推理器
+    fn runtime() -> AResult<RuntimeAlpha> {
+        let mut runtime = RuntimeAlpha::new("test", DEFAULT_PARAMETERS, InferenceEngine::VOID);
+        for cmd in ["NSE <A --> B>.", "NSE <C --> D>.", "CYC 5"] {
+            VmRuntime::input_cmd(&mut runtime, Cmd::parse(cmd)?)?;
+        }
+        Ok(runtime)
+    }
+
+    #[test]
+    fn export_all() -> AResult {
+        let runtime = runtime()?;
+        let dot = runtime.reasoner().export_dot(&GraphExportOptions::default());
+        assert!(dot.starts_with("digraph memory {"));
+        assert!(dot.ends_with('}'));
+        for node in ["\"A\"", "\"B\"", "\"C\"", "\"D\""] {
+            assert!(dot.contains(&format!("    {node} [")), "{node} should be in {dot}");
+        }
+        assert!(dot.contains("-> \"A\" [label=\"ComponentStatement\""));
+        ok!()
+    }
+
+    #[test]
+    fn export_focus() -> AResult {
+        let runtime = runtime()?;
+        let options = GraphExportOptions {
+            focus: Some(term!("A")),
+            radius: 2,
+        };
+        let dot = runtime.reasoner().export_dot(&options);
+        // 经由`<A --> B>`可达B
+        assert!(dot.contains("    \"B\" ["));
+        // 另一个连通分量不在图中
+        assert!(!dot.contains("\"C\""));
+        // 半径为0⇒只有焦点自身
+        let options = GraphExportOptions { radius: 0, ..options };
+        let dot = runtime.reasoner().export_dot(&options);
+        assert!(dot.contains("    \"A\" ["));
+        assert!(!dot.contains("->"));
+        ok!()
+    }
+}
@@ -0,0 +1,70 @@
+//! 实现「推理器」层面的「记忆区合并」
+//! * 🎯将另一个记忆区的知识并入自身，而非覆盖
+//! * 📄知识库导入：从多个来源合并知识
+//! * ℹ️有关「记忆区覆盖加载」参见[`super::serde`]
+
+use super::Reasoner;
+use crate::{
+    entity::{JudgementV1, Sentence, Stamp},
+    global::ClockTime,
+    storage::Memory,
+    util::Serial,
+};
+use std::cell::Cell;
+
+impl Reasoner {
+    /// 合并一个外来的记忆区
+    /// * 🚩先将外来记忆区的「证据基」「任务序列号」偏移到自身当前序列号之后
+    ///   * 🎯避免外来证据被误判为「与自身证据重叠」
+    ///   * 🎯避免外来任务与自身任务序列号冲突
+    /// * 🚩再逐个并入外来概念
+    ///   * 同名概念：信念尝试修正，问题去重，链接合并
+    ///   * 新概念：直接放入
+    /// * 📌所有「溢出」信息均以「注释」形式报告
+    pub fn merge_memory(&mut self, mut incoming: Memory) {
+        // 偏移序列号，并记录偏移后的最大值
+        let evidence_offset = self.stamp_current_serial();
+        let serial_offset = self.task_current_serial();
+        let max_evidence = Cell::new(evidence_offset);
+        let max_serial = Cell::new(serial_offset);
+        incoming.remap_serials(
+            |evidence: ClockTime| {
+                let remapped = evidence + evidence_offset;
+                max_evidence.set(max_evidence.get().max(remapped));
+                remapped
+            },
+            |serial: Serial| {
+                let remapped = serial + serial_offset;
+                max_serial.set(max_serial.get().max(remapped));
+                remapped
+            },
+        );
+        self.set_stamp_current_serial(max_evidence.get());
+        self.set_task_current_serial(max_serial.get());
+
+        // 逐个并入概念
+        let time = self.time();
+        let max_evidence_base_length = self.parameters.maximum_stamp_length;
+        let interleave_order = self.parameters.evidence_interleave_order;
+        let calculus = self.truth_calculus();
+        let revise = |new_belief: &JudgementV1, old_belief: &JudgementV1| {
+            let content = new_belief.content().clone();
+            let revised_truth = calculus.revision(new_belief, old_belief);
+            let new_stamp = Stamp::from_merge_unchecked(
+                new_belief,
+                old_belief,
+                time,
+                max_evidence_base_length,
+                interleave_order,
+            );
+            JudgementV1::new(content, revised_truth, new_stamp, true)
+        };
+        let mut messages = vec![];
+        for concept in incoming.take_all_concepts() {
+            messages.extend(self.memory.merge_concept(concept, revise));
+        }
+        for message in messages {
+            self.report_comment(message);
+        }
+    }
+}
@@ -14,6 +14,15 @@ nar_dev_utils::mods! {
     // 功能：序列反序列化
     pub use serde;
 
+    // 功能：记忆区合并
+    pub use merge;
+
     // 功能：NAVM接口
     pub use vm_api;
+
+    // 功能：导出概念图
+    pub use graph;
+
+    // 功能：运行统计
+    pub use statistics;
 }
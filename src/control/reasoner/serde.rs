@@ -10,7 +10,7 @@ use super::Reasoner;
 use crate::{
     entity::RCTask,
     global::ClockTime,
-    storage::{Memory, TaskBuffer},
+    storage::{Memory, MemoryDelta, MemoryDeltaRef, TaskBuffer},
     util::Serial,
 };
 use serde::{Deserialize, Serialize};
@@ -64,6 +64,49 @@ pub(super) struct ReasonerStatusStorageRef<'s> {
     pub task_current_serial: Serial,
 }
 
+/// 推理器状态的增量快照
+/// * 🎯只存储自上次「检查点」以来变化了的数据
+///   * 📄记忆区：变化了的概念
+///   * 📄推导数据：未变化时不存储
+/// * 🎯先反序列化到此类型，再让推理器应用
+#[derive(Debug, Deserialize)]
+pub(super) struct ReasonerStatusDelta {
+    /// 记忆区的增量快照
+    pub memory: MemoryDelta,
+
+    /// 推导数据（若有变化）
+    pub derivation_datas: Option<TaskBuffer>,
+
+    /// 系统时钟
+    pub clock: ClockTime,
+
+    /// 时间戳序列号（递增序列号）
+    pub stamp_current_serial: ClockTime,
+
+    /// 任务序列号（递增序列号）
+    pub task_current_serial: Serial,
+}
+
+/// 推理器状态增量快照的引用
+/// * 🎯从「推理器」构造引用，并由此序列化
+#[derive(Debug, Serialize)]
+pub(super) struct ReasonerStatusDeltaRef<'s> {
+    /// 记忆区的增量快照
+    pub memory: MemoryDeltaRef<'s>,
+
+    /// 推导数据（若有变化）
+    pub derivation_datas: Option<&'s TaskBuffer>,
+
+    /// 系统时钟
+    pub clock: ClockTime,
+
+    /// 时间戳序列号（递增序列号）
+    pub stamp_current_serial: ClockTime,
+
+    /// 任务序列号（递增序列号）
+    pub task_current_serial: Serial,
+}
+
 impl ReasonerStatusStorage {
     /// 对整个「推理器状态」的共享引用归一化
     fn unify_all_task_rcs(&mut self) {
@@ -83,6 +126,8 @@ impl Reasoner {
     pub fn load_memory(&mut self, mut memory: Memory) -> Memory {
         // 先交换记忆区对象
         std::mem::swap(&mut memory, &mut self.memory);
+        // 上一个检查点已不再适用于新记忆区
+        self.memory.mark_all_dirty();
         // 返回旧记忆区
         memory
     }
@@ -117,6 +162,8 @@ impl Reasoner {
         self.set_stamp_current_serial(stamp_current_serial);
         let task_current_serial_old = self.task_current_serial();
         self.set_task_current_serial(task_current_serial);
+        // 加载的状态即为新的检查点
+        self.checkpoint();
         // 将旧的数据返回
         ReasonerStatusStorage {
             memory,
@@ -157,16 +204,21 @@ impl Reasoner {
     }
     /// 从推理器序列化出「推理器状态」
     pub fn serialize_status<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        // 先构造引用
-        let storage_ref = ReasonerStatusStorageRef {
+        self.status_serializable().serialize(serializer)
+    }
+
+    /// 获取可直接序列化的「推理器状态」
+    /// * 🎯供「只接受可序列化值，不暴露序列化器」的格式使用
+    ///   * 📄CBOR
+    /// * 🚩不暴露「推理器状态」数据类型
+    pub fn status_serializable(&self) -> impl Serialize + '_ {
+        ReasonerStatusStorageRef {
             memory: &self.memory,
             derivation_datas: &self.task_buffer,
             clock: self.time(),
             stamp_current_serial: self.stamp_current_serial(),
             task_current_serial: self.task_current_serial(),
-        };
-        // 再序列化
-        storage_ref.serialize(serializer)
+        }
     }
 
     /// 反序列化并加载「推理器状态」
@@ -177,11 +229,96 @@ impl Reasoner {
         D: serde::Deserializer<'de>,
     {
         // 先反序列化到结构体
-        let mut status = ReasonerStatusStorage::deserialize(deserializer)?;
+        let status = ReasonerStatusData::deserialize(deserializer)?;
+        // 再加载
+        self.load_status_data(status);
+        Ok(())
+    }
+
+    /// 加载已反序列化的「推理器状态」
+    /// * 🚩先做引用归一化，再加载
+    pub fn load_status_data(&mut self, status: ReasonerStatusData) {
+        let ReasonerStatusData(mut status) = status;
         // 引用归一化
         status.unify_all_task_rcs();
         // 再加载
         let _ = self.load_status(status);
+    }
+}
+
+/// 已反序列化、尚未加载的「推理器状态」
+/// * 🎯供「只能反序列化到具体类型，不暴露反序列化器」的格式使用
+///   * 📄CBOR
+/// * 📌内部数据类型不对外暴露，只能交由[`Reasoner::load_status_data`]加载
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct ReasonerStatusData(ReasonerStatusStorage);
+
+/// 推理器「增量快照」接口
+/// * 🎯大记忆区下的频繁存档：只保存自上次检查点以来的变化
+/// * 📌「检查点」：完整保存/加载状态、保存增量快照 之后
+impl Reasoner {
+    /// 设置检查点
+    /// * 🚩清除记忆区与推导数据的所有「脏标记」
+    pub fn checkpoint(&mut self) {
+        self.memory.clear_dirty();
+        self.task_buffer.clear_dirty();
+    }
+
+    /// 序列化出自上次检查点以来的「增量快照」
+    /// * ⚠️不会自动设置检查点：需调用方在保存成功后调用[`Self::checkpoint`]
+    pub fn serialize_status_delta<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // 先构造引用
+        let delta_ref = ReasonerStatusDeltaRef {
+            memory: self.memory.delta_ref(),
+            derivation_datas: match self.task_buffer.is_dirty() {
+                true => Some(&self.task_buffer),
+                false => None,
+            },
+            clock: self.time(),
+            stamp_current_serial: self.stamp_current_serial(),
+            task_current_serial: self.task_current_serial(),
+        };
+        // 再序列化
+        delta_ref.serialize(serializer)
+    }
+
+    /// 反序列化并应用「增量快照」
+    /// * ⚠️须在对应的「基础快照」（及先前的增量快照）之上应用
+    /// * 🚩应用后即为新的检查点
+    pub fn load_from_deserialized_status_delta<'de, D>(
+        &mut self,
+        deserializer: D,
+    ) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let ReasonerStatusDelta {
+            memory,
+            derivation_datas,
+            clock,
+            stamp_current_serial,
+            task_current_serial,
+        } = ReasonerStatusDelta::deserialize(deserializer)?;
+        // 应用记忆区的增量快照
+        self.memory.apply_delta(memory);
+        // 覆盖推导数据（若有变化）
+        if let Some(derivation_datas) = derivation_datas {
+            let _ = self.load_derivation_datas(derivation_datas);
+        }
+        // 引用归一化：推导数据中的「父任务」
+        let memory_refs = self.memory.all_task_rcs();
+        let derivation_datas_refs = self.task_buffer.iter_mut_task_rcs();
+        RCTask::unify_rcs(memory_refs.chain(derivation_datas_refs));
+        // 其它基础类型
+        self.set_time(clock);
+        self.set_stamp_current_serial(stamp_current_serial);
+        self.set_task_current_serial(task_current_serial);
+        // 设置检查点
+        self.checkpoint();
         Ok(())
     }
 }
@@ -0,0 +1,65 @@
+//! 🆕推理器的运行统计
+//! * 🎯为「以性能为目的的改动」提供可比较的数据
+//! * 📌由工作周期与「导出任务」处直接记录：不依赖输出、音量等
+
+use super::Reasoner;
+use std::time::Duration;
+
+/// 推理器的运行统计
+/// * 🚩自推理器创建（或[重置统计](Reasoner::reset_statistics)）起累计
+/// * ⚠️不参与序列反序列化：只反映当前进程中的运行
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ReasonerStatistics {
+    /// 运行的工作周期数
+    pub cycles: usize,
+    /// 导出的任务数
+    /// * 📌只统计通过预算阈值、问题导出限制，被加入「新任务」的任务
+    pub derived_tasks: usize,
+    /// 「直接推理」阶段的累计耗时
+    pub direct_time: Duration,
+    /// 「概念推理」阶段的累计耗时
+    pub reason_time: Duration,
+}
+
+impl Reasoner {
+    /// 获取运行统计
+    pub fn statistics(&self) -> &ReasonerStatistics {
+        &self.statistics
+    }
+
+    /// 重置运行统计
+    /// * 🎯分段测量：只统计之后的运行
+    pub fn reset_statistics(&mut self) {
+        self.statistics = ReasonerStatistics::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        inference::tools::{create_reasoner, ENGINE_DEV},
+        ok,
+        parameters::DEFAULT_PARAMETERS,
+        util::AResult,
+    };
+    use navm::output::Output;
+
+    /// 导出任务数不受音量影响；重置后从零开始
+    #[test]
+    fn count_cycles_and_derived_tasks() -> AResult {
+        let mut reasoner = create_reasoner(DEFAULT_PARAMETERS, ENGINE_DEV);
+        reasoner.input_cmds("vol 0\nnse <A --> B>.\nnse <B --> C>.\ncyc 20");
+        let statistics = *reasoner.statistics();
+        assert_eq!(statistics.cycles, 20);
+        assert!(statistics.derived_tasks > 0, "{statistics:?}");
+        let outputs = reasoner.fetch_outputs();
+        assert!(
+            !outputs.iter().any(|o| matches!(o, Output::OUT { .. })),
+            "{outputs:?}"
+        );
+        reasoner.reset_statistics();
+        assert_eq!(*reasoner.statistics(), ReasonerStatistics::default());
+        ok!()
+    }
+}
@@ -7,14 +7,17 @@
 
 use super::Reasoner;
 use crate::{
-    entity::{Concept, Judgement, Sentence, TLink, Task},
+    entity::{Concept, Judgement, RevisionRecord, Sentence, TLink, Task},
     global::Float,
     inference::{Budget, Evidential, Truth},
     language::Term,
     util::{AverageFloat, AverageUsize, ToDisplayAndBrief},
 };
 use nar_dev_utils::{join, macro_once, JoinTo, RefCount};
-use narsese::lexical::Task as LexicalTask;
+use narsese::{
+    conversion::string::impl_lexical::format_instances::FORMAT_ASCII,
+    lexical::Task as LexicalTask,
+};
 
 /// 输入输出
 /// * 📄（解析并）输入任务
@@ -59,6 +62,16 @@ impl Reasoner {
             Err(e) => self.report_error(format!("Narsese任务解析错误：{e}",)),
         }
     }
+
+    /// 🆕输入「定义」
+    /// * 🎯输入不随证据修正、不被遗忘的定义性知识
+    /// * 🚩除「作为定义解析」外，同[`Reasoner::input_task`]
+    pub fn input_definition(&mut self, task: LexicalTask) {
+        match self.parse_new_definition(task) {
+            Ok(task) => self.intake_task(task),
+            Err(e) => self.report_error(format!("Narsese definition parse error: {e}")),
+        }
+    }
 }
 
 /// 推理周期
@@ -208,6 +221,43 @@ mod information_report {
             )
         }
 
+        /// 将记忆区中的知识导出为Narsese脚本
+        /// * 🎯人类可读、可diff、可被其它NARS实现读取的知识文件
+        /// * 📌格式：每行一个ASCII格式的Narsese任务，含预算值与真值
+        ///   * 📍信念：预算值取自其所在的「概念」
+        ///   * 📍问题：预算值取自任务自身（可选导出）
+        /// * 🚩先导出所有信念，再导出问题：重新输入后问题可以直接得到回答
+        /// * 🆕「定义」导出为`DEF`指令：重新输入后仍为定义
+        ///   * ⚠️此类行不是纯Narsese，其它NARS实现需去掉`DEF `前缀
+        /// * ⚠️时间戳、派生关系等不予保留：重新输入后均为「新输入的任务」
+        pub fn export_narsese(&self, include_questions: bool) -> String {
+            let beliefs = self.memory.iter_concepts().flat_map(|concept| {
+                let budget = concept.budget_to_lexical();
+                concept.iter_beliefs().map(move |belief| {
+                    let task = LexicalTask {
+                        budget: budget.clone(),
+                        sentence: belief.sentence_to_lexical(),
+                    };
+                    (belief.is_definition(), task)
+                })
+            });
+            let questions = match include_questions {
+                true => self.collect_tasks_map(fmt_question(Task::to_lexical)),
+                false => vec![],
+            };
+            let questions = questions.into_iter().flatten().map(|task| (false, task));
+            beliefs
+                .chain(questions)
+                .map(|(is_definition, task)| {
+                    let narsese = FORMAT_ASCII.format_task(&task);
+                    match is_definition {
+                        true => format!("DEF {narsese}"),
+                        false => narsese,
+                    }
+                })
+                .join_to_new("\n")
+        }
+
         /// 按指定函数格式化推理器内的所有「概念」
         fn fmt_concepts(&self, fmt: impl Fn(&Concept) -> String) -> String {
             // 开始组织格式化
@@ -233,6 +283,29 @@ mod information_report {
             )
         }
 
+        /// 🆕获取某词项所对应概念的「信念修正历史」
+        /// * 🎯类型化接口：由早到晚迭代所有修正记录
+        /// * 📌概念不存在⇒空迭代器
+        pub fn revision_history(&self, term: &Term) -> impl Iterator<Item = &RevisionRecord> {
+            self.memory
+                .term_to_concept(term)
+                .into_iter()
+                .flat_map(|concept| concept.revision_history().iter())
+        }
+
+        /// 🆕报告推理器内所有「信念修正历史」
+        /// * 🚩只展示有记录的概念
+        pub fn report_revisions(&self) -> String {
+            format!(
+                "Revisions in memory:\n{}",
+                self.memory
+                    .iter_concepts()
+                    .filter(|c| !c.revision_history().is_empty())
+                    .map(format_concept_revisions)
+                    .join_to_new("\n")
+            )
+        }
+
         /// 报告内部所有链接（仅词项）
         pub fn report_links(&self) -> String {
             format!(
@@ -439,6 +512,18 @@ mod information_report {
         })
     }
 
+    /// 展示一个「概念」的「信念修正历史」
+    fn format_concept_revisions(c: &Concept) -> String {
+        format!(
+            "- {}\n{}",
+            c.term(),
+            c.revision_history()
+                .iter()
+                .map(|r| format!("  {}", r.to_display()))
+                .join_to_new("\n")
+        )
+    }
+
     /// 展示一个「概念」的链接
     fn format_concept_links(c: &Concept) -> String {
         format!(
@@ -4,14 +4,14 @@
 
 use crate::{
     entity::{
-        BudgetValue, Item, Judgement, JudgementV1, RCTask, Sentence, TaskLink, TermLink,
-        TermLinkTemplate, Token,
+        BudgetValue, Item, Judgement, JudgementV1, RCTask, RevisionHistory, RevisionRecord,
+        Sentence, TaskLink, TermLink, TermLinkTemplate, Token,
     },
     global::{ClockTime, Float},
-    inference::{Budget, BudgetFunctions},
+    inference::{Budget, BudgetFunctions, BudgetInference, Evidential, OverlapPolicy, Truth},
     language::Term,
-    parameters::{Parameters, DEFAULT_PARAMETERS},
-    storage::{ArrayBuffer, ArrayRankTable, Bag, Buffer, IsCompatibleToAddF, RankF, RankTable},
+    parameters::{Parameters, RankStrategy, DEFAULT_PARAMETERS},
+    storage::{ArrayRankTable, Bag, IsCompatibleToAddF, RankF, RankTable},
     util::{to_display_when_has_content, Iterable, ToDisplayAndBrief},
 };
 use nar_dev_utils::{join, RefCount};
@@ -50,7 +50,9 @@ pub struct Concept {
     /// Question directly asked about the term
     /// * 📝需要是共享引用：一个「问题」既然是一个「任务」，那除了被存储在这缓冲区内，还会被「任务链」引用
     /// * 🚩【2024-07-02 15:58:38】转换为共享引用
-    questions: ArrayBuffer<RCTask>,
+    /// * 🚩按「排行策略」排序：与「信念表」一致
+    #[serde(deserialize_with = "questions::deserialize")]
+    questions: ArrayRankTable<RCTask>,
 
     /// 信念表
     ///
@@ -69,25 +71,73 @@ pub struct Concept {
     ///  Sentences directly made about the term, with non-future tense
     #[serde(deserialize_with = "beliefs::deserialize")]
     beliefs: ArrayRankTable<JudgementV1>,
+
+    /// 🆕「信念表」的排行策略
+    /// * 🎯反序列化后据此覆写排行函数
+    #[serde(default)]
+    belief_rank_strategy: RankStrategy,
+
+    /// 🆕「问题表」的排行策略
+    /// * 🎯反序列化后据此覆写排行函数
+    #[serde(default)]
+    question_rank_strategy: RankStrategy,
+
+    /// 🆕信念修正历史
+    /// * 🎯保留被修正的信念的旧真值
+    /// * 📌向下兼容：旧存档中没有此字段⇒空历史，不记录
+    #[serde(default)]
+    revision_history: RevisionHistory,
 }
 
 /// 有关「信念排行表」的模块
 mod beliefs {
     use super::*;
-    pub const RANK_F: RankF<JudgementV1> = BudgetValue::rank_belief;
+    pub const RANK_F: RankF<JudgementV1> = |belief| protected(belief, BudgetValue::rank_belief);
     pub const IS_COMPATIBLE_TO_ADD_F: IsCompatibleToAddF<JudgementV1> = belief_compatible_to_add;
 
     type Table = ArrayRankTable<JudgementV1>;
 
     /// 构造一个「信念排行表」
-    pub fn new(capacity: usize) -> Table {
+    pub fn new(capacity: usize, strategy: RankStrategy) -> Table {
         Table::new(
             capacity,
-            RANK_F, // * 📌作为「预算函数」的「预算值」
+            rank_f(strategy), // * 📌作为「预算函数」的「预算值」
             IS_COMPATIBLE_TO_ADD_F,
         )
     }
 
+    /// 根据「排行策略」选取排行函数
+    pub fn rank_f(strategy: RankStrategy) -> RankF<JudgementV1> {
+        match strategy {
+            RankStrategy::Original => RANK_F,
+            RankStrategy::Confidence => {
+                |belief| protected(belief, |belief| belief.confidence().to_float())
+            }
+            RankStrategy::Expectation => |belief| protected(belief, |belief| belief.expectation()),
+            RankStrategy::Recency => {
+                |belief| protected(belief, |belief| belief.creation_time() as Float)
+            }
+            RankStrategy::Mixed => |belief| {
+                protected(belief, |belief| {
+                    rank_mixed(
+                        belief.confidence().to_float(),
+                        belief.expectation(),
+                        belief.creation_time(),
+                    )
+                })
+            },
+        }
+    }
+
+    /// 🆕「定义」排在所有经验信念之前
+    /// * 🎯定义不因信念表满员而被淘汰
+    fn protected(belief: &JudgementV1, rank_f: RankF<JudgementV1>) -> Float {
+        match belief.is_definition() {
+            true => Float::INFINITY,
+            false => rank_f(belief),
+        }
+    }
+
     /// 信念适合添加的条件：不能等价
     fn belief_compatible_to_add(incoming: &impl Judgement, existed: &impl Judgement) -> bool {
         // * 📌【2024-07-09 17:13:29】debug：应该是「不等价⇒可兼容」
@@ -96,6 +146,7 @@ mod beliefs {
 
     /// 定制版序列化函数
     /// * 🚩反序列化→覆写指针→原样返回
+    /// * ⚠️此处只能覆写为默认策略：非默认的策略需由[`Concept::restore_rank_fns`]再次覆写
     pub fn deserialize<'de, D>(deserializer: D) -> Result<ArrayRankTable<JudgementV1>, D::Error>
     where
         D: serde::Deserializer<'de>,
@@ -109,6 +160,91 @@ mod beliefs {
     }
 }
 
+/// 有关「问题排行表」的模块
+/// * 📌与「信念排行表」共用「排行策略」
+/// * ℹ️问题没有真值：与真值有关的策略取其最优解，尚未被解答者排行为0
+mod questions {
+    use super::*;
+    pub const RANK_F: RankF<RCTask> = rank_recency;
+    pub const IS_COMPATIBLE_TO_ADD_F: IsCompatibleToAddF<RCTask> = |_, _| true;
+
+    type Table = ArrayRankTable<RCTask>;
+
+    /// 构造一个「问题排行表」
+    pub fn new(capacity: usize, strategy: RankStrategy) -> Table {
+        Table::new(capacity, rank_f(strategy), IS_COMPATIBLE_TO_ADD_F)
+    }
+
+    /// 根据「排行策略」选取排行函数
+    /// * 📌原版策略：新近优先，满员时淘汰最旧的问题（同原先的先进先出缓冲区）
+    pub fn rank_f(strategy: RankStrategy) -> RankF<RCTask> {
+        match strategy {
+            RankStrategy::Original | RankStrategy::Recency => RANK_F,
+            RankStrategy::Confidence => |question| {
+                let question = question.get_();
+                let confidence = question.best_solution().map(|s| s.confidence().to_float());
+                confidence.unwrap_or(0.0)
+            },
+            RankStrategy::Expectation => |question| {
+                let question = question.get_();
+                let expectation = question.best_solution().map(Truth::expectation);
+                expectation.unwrap_or(0.0)
+            },
+            RankStrategy::Mixed => |question| {
+                let question = question.get_();
+                let (confidence, expectation) = question
+                    .best_solution()
+                    .map_or((0.0, 0.0), |s| (s.confidence().to_float(), s.expectation()));
+                rank_mixed(confidence, expectation, question.creation_time())
+            },
+        }
+    }
+
+    /// 新近优先：按创建时间
+    fn rank_recency(question: &RCTask) -> Float {
+        question.get_().creation_time() as Float
+    }
+
+    /// 定制版序列化函数
+    /// * 🚩反序列化→覆写指针→原样返回
+    /// * 📌与原先的「问题缓冲区」格式一致：均只存储「内部数组」与「容量」
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<ArrayRankTable<RCTask>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut table = ArrayRankTable::deserialize(deserializer)?;
+        table.override_fn(questions::RANK_F, questions::IS_COMPATIBLE_TO_ADD_F);
+        Ok(table)
+    }
+}
+
+// * 📌「加权混合」策略的各项系数固定为常量，而非超参数
+//   * 📝排行函数为函数指针（以便序列化后恢复），无法捕获超参数
+//   * 💭需要其它权重时，宜另增「排行策略」
+
+/// 「加权混合」策略中，信度的权重
+const MIXED_WEIGHT_CONFIDENCE: Float = 0.4;
+/// 「加权混合」策略中，期望的权重
+const MIXED_WEIGHT_EXPECTATION: Float = 0.4;
+/// 「加权混合」策略中，新近程度的权重
+/// * 📌权重较小：新近程度只在信度、期望相近时起决定作用
+const MIXED_WEIGHT_RECENCY: Float = 0.2;
+/// 「加权混合」策略中，新近程度的时间尺度
+/// * 📌创建时间为此值时，新近程度为0.5
+/// * ⚠️新近程度随时间饱和：创建时间为尺度的9倍时已达0.9
+///   * 📝长时间运行后，新近程度之间的差异可忽略，排行几乎只取决于信度与期望
+const MIXED_RECENCY_SCALE: Float = 100.0;
+
+/// 「加权混合」的排行值
+/// * 🚩创建时间经`t / (t + 尺度)`映射到`[0, 1)`，再与信度、期望加权求和
+fn rank_mixed(confidence: Float, expectation: Float, creation_time: ClockTime) -> Float {
+    let time = creation_time as Float;
+    let recency = time / (time + MIXED_RECENCY_SCALE);
+    MIXED_WEIGHT_CONFIDENCE * confidence
+        + MIXED_WEIGHT_EXPECTATION * expectation
+        + MIXED_WEIGHT_RECENCY * recency
+}
+
 /// 用于构造「概念」的结构体
 /// * 🎯构造函数中规范传参
 /// * ⚠️保留后续被修改的可能
@@ -120,6 +256,9 @@ pub struct ConceptParameters {
     maximum_belief_length: usize,
     task_link_bag_size: usize,
     term_link_bag_size: usize,
+    belief_rank_strategy: RankStrategy,
+    question_rank_strategy: RankStrategy,
+    revision_history_length: usize,
 }
 
 impl From<&Parameters> for ConceptParameters {
@@ -131,6 +270,9 @@ impl From<&Parameters> for ConceptParameters {
             maximum_belief_length: parameters.maximum_belief_length,
             task_link_bag_size: parameters.task_link_bag_size,
             term_link_bag_size: parameters.term_link_bag_size,
+            belief_rank_strategy: parameters.belief_rank_strategy,
+            question_rank_strategy: parameters.question_rank_strategy,
+            revision_history_length: parameters.revision_history_length,
         }
     }
 }
@@ -155,13 +297,17 @@ impl Concept {
             term_link_bag_size,
             task_link_forgetting_cycle,
             term_link_forgetting_cycle,
+            belief_rank_strategy,
+            question_rank_strategy,
+            revision_history_length,
         } = parameters;
         // 创建内部字段
         let token = Token::new(term.name(), initial_budget);
-        let questions = ArrayBuffer::new(maximum_questions_length);
-        let beliefs = beliefs::new(maximum_belief_length);
+        let questions = questions::new(maximum_questions_length, question_rank_strategy);
+        let beliefs = beliefs::new(maximum_belief_length, belief_rank_strategy);
         let task_links = Bag::new(task_link_forgetting_cycle, task_link_bag_size);
         let term_links = Bag::new(term_link_forgetting_cycle, term_link_bag_size);
+        let revision_history = RevisionHistory::new(revision_history_length);
         // 创建结构体
         Self {
             token,
@@ -171,9 +317,26 @@ impl Concept {
             link_templates_to_self,
             questions,
             beliefs,
+            belief_rank_strategy,
+            question_rank_strategy,
+            revision_history,
         }
     }
 
+    /// 🆕按自身的「排行策略」覆写信念表、问题表的排行函数
+    /// * 🎯反序列化后恢复非默认的排行策略
+    ///   * ℹ️字段的反序列化函数无法获知其它字段，只能先覆写为默认策略
+    pub(crate) fn restore_rank_fns(&mut self) {
+        self.beliefs.override_fn(
+            beliefs::rank_f(self.belief_rank_strategy),
+            beliefs::IS_COMPATIBLE_TO_ADD_F,
+        );
+        self.questions.override_fn(
+            questions::rank_f(self.question_rank_strategy),
+            questions::IS_COMPATIBLE_TO_ADD_F,
+        );
+    }
+
     /// 🆕对外接口：获取「当前信念表」
     /// * 🎯从「直接推理」而来
     /// * 🚩【2024-07-02 16:23:51】目前因「无需获取内部表」，直接返回迭代器
@@ -192,6 +355,24 @@ impl Concept {
         self.beliefs.add(belief)
     }
 
+    /// 🆕是否含有「定义」
+    /// * 🎯含有定义的概念不被遗忘
+    pub fn has_definition(&self) -> bool {
+        self.beliefs.iter().any(Judgement::is_definition)
+    }
+
+    /// 🆕对外接口：获取「信念修正历史」
+    /// * 🎯查看信念的真值如何随证据到来而变化
+    pub fn revision_history(&self) -> &RevisionHistory {
+        &self.revision_history
+    }
+
+    /// 🆕记录一次「信念修正」
+    /// * 🚩超出容量⇒丢弃最早的记录
+    pub fn record_revision(&mut self, record: RevisionRecord) {
+        self.revision_history.record(record)
+    }
+
     /// 🆕对外接口：获取「当前所有问题」
     /// * 🎯从「直接推理」而来
     /// * 📝有可能是「拿着问题找答案」：此时引用无需可变
@@ -215,6 +396,13 @@ impl Concept {
         self.questions.add(question)
     }
 
+    /// 🆕重新排行「问题集」
+    /// * 🎯问题的最优解变化后调用
+    ///   * 📝部分「排行策略」取问题的最优解：插入后仍会变化
+    pub fn re_rank_questions(&mut self) {
+        self.questions.re_rank()
+    }
+
     /// API方法 @ 链接建立
     ///
     /// # 📄OpenNARS
@@ -278,13 +466,18 @@ impl Concept {
     /// * 📝⚠️实际上并不`only called in RuleTables.reason`
     /// * 📄在「组合规则」的「回答带变量合取」时用到
     /// * 🚩改：去除其中「设置当前时间戳」的副作用，将其迁移到调用者处
-    pub fn get_belief(&self, task_sentence: &impl Sentence) -> Option<&JudgementV1> {
+    /// * 🆕「证据基不重复」的判断交由「证据重合策略」决定
+    pub fn get_belief(
+        &self,
+        task_sentence: &impl Sentence,
+        overlap_policy: OverlapPolicy,
+    ) -> Option<&JudgementV1> {
         // * 🚩此处按「信念排名」从大到小遍历；第一个满足「证据基不重复」的信念将被抽取
         for belief in self.beliefs.iter() {
             // * 📝在OpenNARS 3.0.4中会被覆盖：
             // * 📄`nal.setTheNewStamp(taskStamp, belief.stamp, currentTime);`
             // * ✅【2024-06-08 10:13:46】现在彻底删除newStamp字段，不再需要覆盖了
-            if !task_sentence.evidential_overlap(belief) {
+            if overlap_policy.admits(task_sentence, belief) {
                 let selected = belief;
                 return Some(selected);
             }
@@ -334,6 +527,21 @@ impl Concept {
         self.beliefs.iter()
     }
 
+    /// 🆕可变迭代内部所有的信念
+    /// * 🎯合并记忆区时重映射证据基
+    /// * ⚠️不应改变信念的排行
+    pub(crate) fn iter_beliefs_mut(&mut self) -> impl Iterator<Item = &mut JudgementV1> {
+        self.beliefs.iter_mut()
+    }
+
+    /// 🆕可变迭代「信念修正历史」中的所有记录
+    /// * 🎯合并记忆区时重映射证据基
+    pub(crate) fn iter_revision_history_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut RevisionRecord> {
+        self.revision_history.iter_mut()
+    }
+
     /// 🆕迭代内部所有的问题（任务）
     pub(crate) fn iter_questions(&self) -> impl Iterator<Item = &RCTask> {
         self.questions.iter()
@@ -357,6 +565,79 @@ impl Concept {
         let iter_questions = self.questions.iter_mut();
         iter_task_links.chain(iter_questions)
     }
+
+    /// 🆕吸收另一个「同名概念」的所有内容
+    /// * 🎯合并记忆区：将外来概念并入自身
+    /// * 🚩预算：合并
+    /// * 🚩信念：可修正且证据基不重叠⇒修正后加入；否则直接加入
+    /// * 🚩问题：内容未重复者加入
+    /// * 🚩词项链、任务链：逐个放入（同键者合并）
+    /// * 📌返回所有「溢出」的信息，交由调用方报告
+    pub(crate) fn absorb(
+        &mut self,
+        mut other: Concept,
+        revise: impl Fn(&JudgementV1, &JudgementV1) -> JudgementV1,
+    ) -> Vec<String> {
+        let mut overflowed = vec![];
+        // 预算
+        self.merge_from(&other);
+        // 信念
+        let incoming_beliefs = other.beliefs.iter().cloned().collect::<Vec<_>>();
+        for belief in incoming_beliefs {
+            let revised = self
+                .beliefs
+                .iter()
+                .find(|existed| {
+                    belief.revisable_to(*existed) && !belief.evidential_overlap(*existed)
+                })
+                .map(|existed| revise(&belief, existed));
+            let new_belief = revised.unwrap_or(belief);
+            if let Some(overflowed_belief) = self.add_belief(new_belief) {
+                overflowed.push(format!(
+                    "!!! Overflowed Belief in '{}': {}",
+                    self.term,
+                    overflowed_belief.to_display_long()
+                ));
+            }
+        }
+        // 修正历史：按时间与外来记录归并
+        self.revision_history.merge(&other.revision_history);
+        // 问题
+        let incoming_questions = other.questions.iter().cloned().collect::<Vec<_>>();
+        for question in incoming_questions {
+            let content_existed = self
+                .questions
+                .iter()
+                .any(|existed| existed.get_().content() == question.get_().content());
+            if content_existed {
+                continue;
+            }
+            if let Some(overflowed_question) = self.add_question(question) {
+                overflowed.push(format!(
+                    "!!! Overflowed Question Task: {}",
+                    overflowed_question.get_().to_display_long()
+                ));
+            }
+        }
+        // 词项链、任务链
+        for term_link in other.term_links.pick_out_all() {
+            if let Some(overflowed_link) = self.put_in_term_link(term_link) {
+                overflowed.push(format!(
+                    "!!! Overflowed TermLink: {}",
+                    overflowed_link.to_display_long()
+                ));
+            }
+        }
+        for task_link in other.task_links.pick_out_all() {
+            if let Some(overflowed_link) = self.put_in_task_link(task_link) {
+                overflowed.push(format!(
+                    "!!! Overflowed TaskLink: {}",
+                    overflowed_link.to_display_long()
+                ));
+            }
+        }
+        overflowed
+    }
 }
 
 impl Budget for Concept {
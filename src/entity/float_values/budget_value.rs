@@ -441,6 +441,7 @@ mod tests {
 
     /// 测试/summary
     #[test]
+    #[cfg_attr(feature = "high_precision", ignore = "依赖四位精度的舍入")]
     fn summary() -> AResult {
         fn test(budget: BudgetValue, expected: SF) {
             assert_eq!(budget.budget_summary(), expected);
@@ -8,29 +8,209 @@
 //!
 //! * ✅【2024-05-02 21:41:48】（初代实现）基本复刻完毕
 //! * ♻️【2024-06-19 23:36:56】删繁就简：删去「抽象特征」以免去后续诸多泛型引入的代码复杂性
+//!
+//! ## 🆕数值精度
+//!
+//! * 📌默认：与OpenNARS一致的四位精度，以`0~10000`的整数存储
+//! * 📌特性`high_precision`：以完整的[`Float`]存储，不再舍入
+//!   * 🎯避免长推理链中的真值漂移、`%1.00;0.00%`等舍入问题
+//!   * 📍真值、预算值、序列反序列化、真值函数、预算函数均随之改变
+//!   * ⚠️序列化格式随之改变：两种精度下的存档互不兼容
 
 use crate::{global::Float, impl_display_from_to_display, util::ToDisplayAndBrief};
 use narsese::api::EvidentNumber;
+use precision::*;
 use std::ops::{Add, BitAnd, BitOr, Div, Mul, Not, Sub};
 use thiserror::Error;
 
-/// 用作「短浮点」的整数类型
-/// * 🚩使用0~4294967296的「三十二位无符号整数」覆盖`0~10000`与（相乘时的）`0~100000000`
-/// * 🎯在「短浮点乘法」处避免重复的`as`转换（以提升性能⚡）
-///   * 📄【2024-05-02 11:38:12】总测试时间从原先`(3.5+x)s`变为`3.23s`（用空间换时间后）
-type UShort = u32;
+/// 四位精度：与OpenNARS一致
+#[cfg(not(feature = "high_precision"))]
+mod precision {
+    use crate::global::Float;
+
+    /// 用作「短浮点」的整数类型
+    /// * 🚩使用0~4294967296的「三十二位无符号整数」覆盖`0~10000`与（相乘时的）`0~100000000`
+    /// * 🎯在「短浮点乘法」处避免重复的`as`转换（以提升性能⚡）
+    ///   * 📄【2024-05-02 11:38:12】总测试时间从原先`(3.5+x)s`变为`3.23s`（用空间换时间后）
+    pub type UShort = u32;
+
+    /// 用作「短浮点」的范围上界
+    /// * 🚩表示区间`0~10000`
+    pub const SHORT_MAX: UShort = 10000;
 
-/// 用作「短浮点」的范围上界
-/// * 🚩表示区间`0~10000`
-const SHORT_MAX: UShort = 10000;
+    /// 「短浮点」中的「0」
+    pub const SHORT_ZERO: UShort = 0;
 
-/// 用作「整数→浮点」的转换倍率
-/// * 🚩【2024-05-02 09:27:03】目前相当于「直接除以一万」
-const MULTIPLIER_TO_FLOAT: Float = 0.0001;
+    /// 「短浮点」中的「1/2」
+    pub const SHORT_HALF: UShort = SHORT_MAX / 2;
 
-/// 用作「浮点→整数」的转换倍率
-/// * 🚩【2024-05-02 09:27:03】目前相当于「直接乘以一万」
-const MULTIPLIER_TO_UINT: Float = 10000.0;
+    /// 「短浮点」的最小间隔
+    pub const SHORT_STEP: UShort = 1;
+
+    /// 用作「整数→浮点」的转换倍率
+    /// * 🚩【2024-05-02 09:27:03】目前相当于「直接除以一万」
+    pub const MULTIPLIER_TO_FLOAT: Float = 0.0001;
+
+    /// 用作「浮点→整数」的转换倍率
+    /// * 🚩【2024-05-02 09:27:03】目前相当于「直接乘以一万」
+    const MULTIPLIER_TO_UINT: Float = 10000.0;
+
+    /// 浮点转换为「短整数」（无检查）
+    /// * 🚩四舍五入
+    pub fn float_to_short(value: Float) -> UShort {
+        (value * MULTIPLIER_TO_UINT).round() as UShort
+    }
+
+    /// 判断短整数是否合法
+    /// * 🚩直接判断「是否小于等于最大值」
+    pub fn is_valid_short(short: UShort) -> bool {
+        short <= SHORT_MAX
+    }
+
+    /// 运算结果的归一化
+    /// * 📌整数运算无误差，无需处理
+    #[inline(always)]
+    pub fn normalize(short: UShort) -> UShort {
+        short
+    }
+
+    /// 逻辑或：`a + b - ab`
+    pub fn short_or(a: UShort, b: UShort) -> UShort {
+        a + b - ((a * b) / SHORT_MAX)
+    }
+
+    /// 格式化：保留四位小数
+    pub fn format_short(short: UShort) -> String {
+        match short {
+            // 对`1`的特别处理 | 🆕不同于OpenNARS：会将「异常值」按原样展示
+            SHORT_MAX => "1.0000".to_string(),
+            // 否则：右对齐，左边补零到四位，前缀添加`0.`格式化
+            value => format!("0.{value:0>4}"),
+        }
+    }
+
+    /// 简略格式化：保留两位小数
+    pub fn format_short_brief(short: UShort) -> String {
+        // * 🚩先尝试舍入，再决定截断
+        match short + 50 {
+            // 对`1`的特别处理（此时可能要大于了：舍入）
+            v if v >= SHORT_MAX => "1.00".to_string(),
+            // 否则：四舍五入到百分位；右对齐，只取两位，前缀添加`0.`格式化
+            value => {
+                let s = (value / 100).to_string();
+                format!("0.{s:0>2}")
+            }
+        }
+    }
+}
+
+/// 🆕高精度：直接存储浮点数
+/// * 🚩数值即`0~1`的浮点数本身，不做舍入
+/// * ⚠️浮点数不满足[`Eq`]、[`Ord`]、[`Hash`]：在此手动实现
+///   * 📌合法的值不会是`NaN`，故可安全地全序比较
+#[cfg(feature = "high_precision")]
+mod precision {
+    use super::ShortFloat;
+    use crate::global::Float;
+    use std::{
+        cmp::Ordering,
+        hash::{Hash, Hasher},
+    };
+
+    /// 用作「短浮点」的数值类型
+    pub type UShort = Float;
+
+    /// 用作「短浮点」的范围上界
+    pub const SHORT_MAX: UShort = 1.0;
+
+    /// 「短浮点」中的「0」
+    pub const SHORT_ZERO: UShort = 0.0;
+
+    /// 「短浮点」中的「1/2」
+    pub const SHORT_HALF: UShort = 0.5;
+
+    /// 「短浮点」的最小间隔
+    /// * 📌`1 - ε`可精确表示
+    pub const SHORT_STEP: UShort = Float::EPSILON;
+
+    /// 用作「数值→浮点」的转换倍率
+    pub const MULTIPLIER_TO_FLOAT: Float = 1.0;
+
+    /// 运算误差的容许范围
+    /// * 🎯吸收`0.7 + 0.3 > 1`之类的浮点误差
+    const EPSILON: Float = 1e-12;
+
+    /// 浮点转换为「短浮点数值」（无检查）
+    /// * 🚩与整数转换的饱和行为一致：负数、`NaN`⇒`0`
+    pub fn float_to_short(value: Float) -> UShort {
+        match value.is_nan() {
+            true => 0.0,
+            false => value.max(0.0),
+        }
+    }
+
+    /// 判断数值是否合法
+    pub fn is_valid_short(short: UShort) -> bool {
+        (0.0..=SHORT_MAX).contains(&short)
+    }
+
+    /// 运算结果的归一化
+    /// * 🚩略微越界的浮点误差⇒收回到`0~1`内
+    pub fn normalize(short: UShort) -> UShort {
+        match short {
+            s if s < 0.0 && s > -EPSILON => 0.0,
+            s if s > SHORT_MAX && s < SHORT_MAX + EPSILON => SHORT_MAX,
+            s => s,
+        }
+    }
+
+    /// 逻辑或：`hi + lo(1 - hi)`
+    /// * 🎯保证边界情况的精确性：`a ∨ 0 = a`、`a ∨ 1 = 1`
+    ///   * 📄`a + b - ab`中，`1 + 0.4 - 0.4`会因浮点误差略小于`1`
+    pub fn short_or(a: UShort, b: UShort) -> UShort {
+        let (hi, lo) = match a >= b {
+            true => (a, b),
+            false => (b, a),
+        };
+        normalize(hi + lo * (SHORT_MAX - hi))
+    }
+
+    /// 格式化：保留四位小数
+    pub fn format_short(short: UShort) -> String {
+        format!("{short:.4}")
+    }
+
+    /// 简略格式化：保留两位小数
+    pub fn format_short_brief(short: UShort) -> String {
+        format!("{short:.2}")
+    }
+
+    impl PartialEq for ShortFloat {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other).is_eq()
+        }
+    }
+
+    impl Eq for ShortFloat {}
+
+    impl PartialOrd for ShortFloat {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for ShortFloat {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.value_short().total_cmp(&other.value_short())
+        }
+    }
+
+    impl Hash for ShortFloat {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.value_short().to_bits().hash(state)
+        }
+    }
+}
 
 /// 模拟`nars.entity.ShortFloat`
 /// * 🚩使用`u32`0~4294967296的范围覆盖`0~10000²`
@@ -49,6 +229,7 @@ const MULTIPLIER_TO_UINT: Float = 10000.0;
 /// * ❗即便在构造时采用了[`Float::round`]，但实际效果仍然与OpenNARS不同
 ///   * ⚡为性能考量，许多运算最后的舍入操作仍然是四舍五入（整数除法，避免转换为浮点）
 /// * 📄这导致`0.1 * 0.0005`在OpenNARS中等于`0.0001`而在NARust中为`0`
+/// * 💡启用特性`high_precision`后不再舍入，此时结果为`0.00005`
 ///
 /// OpenNARS中可行的推理：
 ///
@@ -63,7 +244,11 @@ const MULTIPLIER_TO_UINT: Float = 10000.0;
 /// # 📄OpenNARS
 ///
 /// A float value in [0, 1], with 4 digits accuracy.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+    not(feature = "high_precision"),
+    derive(PartialEq, Eq, PartialOrd, Ord, Hash)
+)]
 pub struct ShortFloat {
     /// 0~4294967296的「实际值」
     ///
@@ -116,13 +301,17 @@ pub type ShortFloatResult = Result<ShortFloat, ShortFloatError>;
 
 impl ShortFloat {
     /// 常量「0」
-    pub const ZERO: Self = Self::new_unchecked(0);
+    pub const ZERO: Self = Self::new_unchecked(SHORT_ZERO);
 
     /// 常量「1」
     pub const ONE: Self = Self::new_unchecked(SHORT_MAX);
 
     /// 常量「1/2」
-    pub const HALF: Self = Self::new_unchecked(SHORT_MAX / 2);
+    pub const HALF: Self = Self::new_unchecked(SHORT_HALF);
+
+    /// 🆕常量「小于1的最大值」
+    /// * 🎯表示「有限证据下的最高信度」：信度为「1」意味着无穷证据
+    pub const ALMOST_ONE: Self = Self::new_unchecked(SHORT_MAX - SHORT_STEP);
 
     /// 以0~10000的整数创建（有检查）
     #[inline(always)]
@@ -204,7 +393,7 @@ impl ShortFloat {
     /// * 🎯提取共用逻辑，以同时用于「构造」和「赋值」
     /// * ⚠️必须确保值在范围内
     pub fn float_to_short_value_unchecked(value: Float) -> UShort {
-        (value * MULTIPLIER_TO_UINT).round() as UShort
+        float_to_short(value)
     }
 
     // ! ✅对`equals`、`hashCode`、`clone`均已通过宏自动生成
@@ -213,7 +402,7 @@ impl ShortFloat {
     /// * 🚩直接判断「是否小于等于最大值」
     #[inline(always)]
     pub fn is_valid_short(short: UShort) -> bool {
-        short <= SHORT_MAX
+        precision::is_valid_short(short)
     }
 
     /// 🆕判断自身值是否合法
@@ -291,25 +480,11 @@ impl ShortFloat {
 /// 模拟`ShortFloat.toString`、`ShortFloat.toStringBrief`
 impl ToDisplayAndBrief for ShortFloat {
     fn to_display(&self) -> String {
-        match self.value {
-            // 对`1`的特别处理 | 🆕不同于OpenNARS：会将「异常值」按原样展示
-            SHORT_MAX => "1.0000".to_string(),
-            // 否则：右对齐，左边补零到四位，前缀添加`0.`格式化
-            value => format!("0.{value:0>4}"),
-        }
+        format_short(self.value)
     }
 
     fn to_display_brief(&self) -> String {
-        // * 🚩先尝试舍入，再决定截断
-        match self.value + 50 {
-            // 对`1`的特别处理（此时可能要大于了：舍入）
-            v if v >= SHORT_MAX => "1.00".to_string(),
-            // 否则：四舍五入到百分位；右对齐，只取两位，前缀添加`0.`格式化
-            value => {
-                let s = (value / 100).to_string();
-                format!("0.{s:0>2}")
-            }
-        }
+        format_short_brief(self.value)
     }
 }
 
@@ -338,7 +513,7 @@ impl Add for ShortFloat {
     /// ! ⚠️可能会有「数值溢出」的panic
     fn add(self, rhs: Self) -> Self::Output {
         // 相加、构造、返回
-        Self::new(self.value + rhs.value).unwrap()
+        Self::new(normalize(self.value + rhs.value)).unwrap()
     }
 }
 
@@ -352,7 +527,7 @@ impl Sub for ShortFloat {
     ///
     /// ! ⚠️可能会有「数值溢出」的panic
     fn sub(self, rhs: Self) -> Self::Output {
-        Self::new_unchecked(self.value - rhs.value)
+        Self::new_unchecked(normalize(self.value - rhs.value))
     }
 }
 
@@ -392,7 +567,7 @@ impl Div for ShortFloat {
         // * 📌因此 new.value = (self.value / rhs.value) * 10000 = (self.value * 10000) / rhs.value
         // * 📝↑采用「先乘后除」的方法，最大保留精度
         // 相除、构造、返回
-        Self::new((self.value * SHORT_MAX) / rhs.value).unwrap()
+        Self::new(normalize((self.value * SHORT_MAX) / rhs.value)).unwrap()
     }
 }
 
@@ -486,7 +661,7 @@ impl BitOr for ShortFloat {
         // = 1 - 1 + a + b - ab
         // = a + b - ab
         // ↑仅在`ab`引入小数，故最终舍入不会受其影响
-        Self::new_unchecked(self.value + rhs.value - ((self.value * rhs.value) / SHORT_MAX))
+        Self::new_unchecked(short_or(self.value, rhs.value))
     }
 }
 
@@ -509,7 +684,8 @@ macro_rules! short_float {
 }
 
 /// 单元测试
-#[cfg(test)]
+/// * ⚠️基于四位精度的整数表示
+#[cfg(all(test, not(feature = "high_precision")))]
 mod tests {
     use super::*;
     use crate::{ok, util::AResult};
@@ -890,3 +1066,45 @@ mod tests {
         ok!()
     }
 }
+
+/// 单元测试（高精度）
+#[cfg(all(test, feature = "high_precision"))]
+mod tests_high_precision {
+    use super::*;
+    use crate::{ok, util::AResult};
+
+    /// 用于测试的类型简写
+    type SF = ShortFloat;
+
+    /// 不再舍入到四位小数
+    #[test]
+    fn no_rounding() -> AResult {
+        let tiny = SF::try_from(0.00001)?;
+        assert!(!tiny.is_zero());
+        assert_eq!(tiny.to_float(), 0.00001);
+        // 四位精度下为`0`
+        assert!(!(SF::try_from(0.1)? * SF::try_from(0.0005)?).is_zero());
+        ok!()
+    }
+
+    /// 浮点误差不会导致越界
+    #[test]
+    fn normalized() -> AResult {
+        let sum = SF::try_from(0.7)? + SF::try_from(0.3)?;
+        assert!(sum.is_valid());
+        let or = SF::try_from(0.1)? | SF::try_from(0.2)?;
+        assert!(or.is_valid());
+        assert!((SF::ONE - SF::try_from(0.999999)?).is_valid());
+        ok!()
+    }
+
+    /// 比较与格式化
+    #[test]
+    fn cmp_and_fmt() -> AResult {
+        assert!(SF::try_from(0.12345)? > SF::try_from(0.1234)?);
+        assert_eq!(SF::HALF, SF::try_from(0.5)?);
+        assert_eq!(SF::try_from(0.12345)?.to_string(), "0.1235");
+        assert_eq!(SF::ONE.to_string(), "1.0000");
+        ok!()
+    }
+}
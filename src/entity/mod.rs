@@ -42,6 +42,10 @@ pub use task::*;
 mod linkages;
 pub use linkages::*;
 
+// 🆕信念修正历史 `RevisionHistory`
+mod revision_history;
+pub use revision_history::*;
+
 // 概念 `Concept`
 mod concept;
 pub use concept::*;
@@ -0,0 +1,198 @@
+//! 🆕信念修正历史
+//! * 🎯保留概念中每次「信念修正」前后的真值
+//!   * 📝信念表只保留修正后的信念：旧真值随之丢失
+//!   * 📄分析「某个论断的信度如何随证据到来而变化」
+//! * 📌有界：超出容量时丢弃最早的记录
+
+use crate::{
+    entity::{Judgement, Stamp, TruthValue},
+    global::ClockTime,
+    inference::{Evidential, Truth},
+    util::ToDisplayAndBrief,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// 一次「信念修正」的记录
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevisionRecord {
+    /// 修正发生的时间
+    pub time: ClockTime,
+    /// 修正前（旧信念）的真值
+    pub old_truth: TruthValue,
+    /// 修正后的真值
+    pub new_truth: TruthValue,
+    /// 被合并进来的时间戳
+    /// * 📌即「新信念」的时间戳：本次修正所新增的证据
+    pub stamp: Stamp,
+}
+
+impl RevisionRecord {
+    /// 从一次「修正」的前提与结论构造
+    /// * 🚩旧真值取自「旧信念」，时间戳取自「新信念」
+    pub fn from_revision(
+        time: ClockTime,
+        new_belief: &impl Judgement,
+        old_belief: &impl Judgement,
+        new_truth: TruthValue,
+    ) -> Self {
+        Self {
+            time,
+            old_truth: TruthValue::from(old_belief),
+            new_truth,
+            stamp: Stamp::new(new_belief.creation_time(), new_belief.evidential_base()),
+        }
+    }
+}
+
+impl ToDisplayAndBrief for RevisionRecord {
+    fn to_display(&self) -> String {
+        format!(
+            "[{}] {} => {} with {}",
+            self.time,
+            self.old_truth.truth_to_display(),
+            self.new_truth.truth_to_display(),
+            self.stamp.stamp_to_display()
+        )
+    }
+}
+
+/// 有界的「信念修正历史」
+/// * 🚩按时间先后排列：新记录追加在末尾
+/// * 📌容量为`0`⇒不记录
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RevisionHistory {
+    /// 最多保留的记录数
+    capacity: usize,
+    /// 所有记录
+    records: VecDeque<RevisionRecord>,
+}
+
+impl RevisionHistory {
+    /// 构造函数
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// 最多保留的记录数
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 已有的记录数
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// 是否没有记录
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// 追加一条记录
+    /// * 🚩超出容量⇒丢弃最早的记录
+    pub fn record(&mut self, record: RevisionRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.records.push_back(record);
+        while self.records.len() > self.capacity {
+            self.records.pop_front();
+        }
+    }
+
+    /// 并入另一段历史
+    /// * 🎯合并记忆区时，保持「由早到晚」的顺序
+    /// * 🚩按修正时间归并：时间相同者，自身的记录在前
+    /// * 🚩超出容量⇒丢弃最早的记录
+    pub fn merge(&mut self, other: &RevisionHistory) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut merged = VecDeque::with_capacity(self.records.len() + other.records.len());
+        let mut own = self.records.drain(..).peekable();
+        let mut incoming = other.records.iter().peekable();
+        loop {
+            let take_own = match (own.peek(), incoming.peek()) {
+                (Some(a), Some(b)) => a.time <= b.time,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            match take_own {
+                true => merged.extend(own.next()),
+                false => merged.extend(incoming.next().cloned()),
+            }
+        }
+        drop(own);
+        while merged.len() > self.capacity {
+            merged.pop_front();
+        }
+        self.records = merged;
+    }
+
+    /// 由早到晚迭代所有记录
+    pub fn iter(&self) -> impl Iterator<Item = &RevisionRecord> {
+        self.records.iter()
+    }
+
+    /// 由早到晚可变迭代所有记录
+    /// * 🎯合并记忆区时重映射证据基
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut RevisionRecord> {
+        self.records.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stamp;
+
+    /// 第`i`次修正的记录
+    fn record(i: ClockTime) -> RevisionRecord {
+        RevisionRecord {
+            time: i,
+            old_truth: TruthValue::from_fc(1.0, 0.9),
+            new_truth: TruthValue::from_fc(0.5, 0.95),
+            stamp: stamp!({i: i}),
+        }
+    }
+
+    #[test]
+    fn bounded() {
+        let mut history = RevisionHistory::new(3);
+        for i in 0..5 {
+            history.record(record(i));
+        }
+        // * 🚩只保留最近的三条，由早到晚
+        assert_eq!(history.len(), 3);
+        let times = history.iter().map(|r| r.time).collect::<Vec<_>>();
+        assert_eq!(times, [2, 3, 4]);
+    }
+
+    #[test]
+    fn merge_by_time() {
+        let mut history = RevisionHistory::new(4);
+        for i in [1, 4, 6] {
+            history.record(record(i));
+        }
+        let mut other = RevisionHistory::new(4);
+        for i in [2, 3, 5] {
+            other.record(record(i));
+        }
+        history.merge(&other);
+        // * 🚩按时间归并，只保留最近的四条
+        let times = history.iter().map(|r| r.time).collect::<Vec<_>>();
+        assert_eq!(times, [3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn disabled() {
+        let mut history = RevisionHistory::default();
+        history.record(record(0));
+        assert!(history.is_empty());
+    }
+}
@@ -20,6 +20,11 @@ pub struct JudgementV1 {
     revisable: bool,
     /// The truth value of Judgment
     truth: TruthValue,
+    /// 🆕是否为「定义」
+    /// * 📌仅由用户输入时标记：导出的结论都不是定义
+    /// * 📌向下兼容：旧存档中没有此字段⇒不是定义
+    #[serde(default)]
+    definition: bool,
 }
 
 impl JudgementV1 {
@@ -33,8 +38,25 @@ impl JudgementV1 {
             inner: SentenceInner::new(content, stamp.into()),
             revisable,
             truth: truth.into(),
+            definition: false,
         }
     }
+
+    /// 🆕标记为「定义」
+    /// * 🚩定义总是不可修正的
+    /// * 📌不设置真值的「分析性」标记：作为前提时以「依赖度」代替其信度，而非令结论失效
+    ///   * 🔗参见[`ReasonContext::premise_truth`](crate::control::ReasonContext::premise_truth)
+    pub fn into_definition(mut self) -> Self {
+        self.revisable = false;
+        self.definition = true;
+        self
+    }
+
+    /// 🆕获取内部「时间戳」的可变引用
+    /// * 🎯合并记忆区时重映射证据基
+    pub(crate) fn stamp_mut(&mut self) -> &mut Stamp {
+        self.inner.stamp_mut()
+    }
 }
 
 impl Evidential for JudgementV1 {
@@ -121,6 +143,10 @@ impl Judgement for JudgementV1 {
     fn revisable(&self) -> bool {
         self.revisable
     }
+
+    fn is_definition(&self) -> bool {
+        self.definition
+    }
 }
 
 __impl_to_display_and_display! {
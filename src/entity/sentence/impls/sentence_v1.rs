@@ -66,6 +66,12 @@ impl SentenceV1 {
             | SentenceV1::QuestionV1(QuestionV1 { inner, .. }) => inner,
         }
     }
+
+    /// 🆕获取内部「时间戳」的可变引用
+    /// * 🎯合并记忆区时重映射证据基
+    pub(crate) fn stamp_mut(&mut self) -> &mut Stamp {
+        self.inner_mut().stamp_mut()
+    }
 }
 
 impl Evidential for SentenceV1 {
@@ -8,12 +8,20 @@ use narsese::lexical::Sentence as LexicalSentence;
 /// * 📌在[「语句」](Sentence)的基础上具有「可修正」等功能
 pub trait Judgement: Sentence + Truth {
     /// 📄改版OpenNARS `static revisable`
+    /// * 🆕「定义」不修正其它信念：避免定义与经验证据混合
     fn revisable_to(&self, other: &Self) -> bool {
         let content_eq = self.content() == other.content();
         let other_revisable = other.revisable();
-        content_eq && other_revisable
+        content_eq && other_revisable && !self.is_definition()
     }
 
+    /// 🆕是否为「定义」
+    /// * 📄由[`Reasoner::input_definition`](crate::control::Reasoner::input_definition)输入
+    /// * 📌定义总是不可修正的，但反之不然
+    ///   * 📄「包含因变量的合取」经结构规则导出的结论
+    /// * 🎯不参与修正、不被遗忘
+    fn is_definition(&self) -> bool;
+
     /// 模拟`Sentence.revisable`、`Sentence.getRevisable`
     /// * 📝OpenNARS只在「解析任务」时会设置值
     ///   * 🎯使用目的：「包含因变量的合取」不可被修正
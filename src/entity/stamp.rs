@@ -4,7 +4,10 @@
 //! * ♻️【2024-06-21 00:05:34】基本依OpenNARS改版 重复刻（去特征化）完成
 
 use crate::__impl_to_display_and_display;
-use crate::{global::ClockTime, inference::Evidential};
+use crate::{
+    global::ClockTime,
+    inference::{Evidential, InterleaveOrder, OverlapPolicy},
+};
 use anyhow::Result;
 use narsese::lexical::Stamp as LexicalStamp;
 use serde::{Deserialize, Serialize};
@@ -153,11 +156,13 @@ impl Stamp {
         second: &impl Evidential,
         time: ClockTime,
         max_evidence_base_length: usize,
+        order: InterleaveOrder,
     ) -> Self {
         let merged_base = Self::merged_evidential_base(
             first.evidential_base(),
             second.evidential_base(),
             max_evidence_base_length,
+            order,
         );
         Self::new(time, merged_base)
     }
@@ -174,20 +179,24 @@ impl Stamp {
     /// @param second The second Stamp
     /// @param time   The new creation time
     /// @return The merged Stamp, or null
+    /// * 🆕是否允许证据重合，由[「证据重合策略」](OverlapPolicy)决定
     pub fn from_merge(
         first: &impl Evidential,
         second: &impl Evidential,
         time: ClockTime,
         max_evidence_base_length: usize,
+        overlap_policy: OverlapPolicy,
+        order: InterleaveOrder,
     ) -> Option<Self> {
-        // * 🚩有重合证据⇒返回空；无重合证据⇒合并证据
-        match first.evidential_overlap(second) {
-            true => None,
-            false => Some(Self::from_merge_unchecked(
+        // * 🚩策略不允许⇒返回空；否则⇒合并证据
+        match overlap_policy.admits(first, second) {
+            false => None,
+            true => Some(Self::from_merge_unchecked(
                 first,
                 second,
                 time,
                 max_evidence_base_length,
+                order,
             )),
         }
     }
@@ -210,6 +219,15 @@ impl Stamp {
     ) -> Result<Self> {
         Ok(Self::with_time(current_serial, time))
     }
+
+    /// 🆕按指定函数重映射「证据基」中的每个序列号
+    /// * 🎯合并外来记忆区时，避免其证据序列号与自身的冲突
+    /// * ⚠️不改变「创建时间」
+    pub fn remap_evidential_base(&mut self, f: impl Fn(ClockTime) -> ClockTime) {
+        self.evidential_base
+            .iter_mut()
+            .for_each(|serial| *serial = f(*serial));
+    }
 }
 
 /// 初代「时间戳」的快捷构造宏
@@ -298,11 +316,11 @@ mod tests {
             macro test {
                 // 没结果
                 (@SINGLE ( $s1:tt, $s2:tt, $time:expr, $max_base_l:expr ) => None ) => {
-                    assert_s_eq!(Option S::from_merge(&stamp!($s1), &stamp!($s2), $time, $max_base_l), None::<S>);
+                    assert_s_eq!(Option S::from_merge(&stamp!($s1), &stamp!($s2), $time, $max_base_l, OverlapPolicy::Strict, InterleaveOrder::Original), None::<S>);
                 }
                 // 有结果
                 (@SINGLE ( $s1:tt, $s2:tt, $time:expr, $max_base_l:expr ) => $stamp:tt ) => {
-                    assert_s_eq!(Option S::from_merge(&stamp!($s1), &stamp!($s2), $time, $max_base_l), Some(stamp!($stamp)));
+                    assert_s_eq!(Option S::from_merge(&stamp!($s1), &stamp!($s2), $time, $max_base_l, OverlapPolicy::Strict, InterleaveOrder::Original), Some(stamp!($stamp)));
                 }
                 // 总模式
                 ( $( $parameters:tt => $expected:tt )* ) => {
@@ -325,6 +343,26 @@ mod tests {
         }
     }
 
+    /// 测试/from_merge
+    /// * 🎯「证据重合策略」与「交织顺序」
+    #[test]
+    fn from_merge_policies() {
+        use InterleaveOrder::*;
+        use OverlapPolicy::*;
+        let merge = |s1: S, s2: S, max_base_l, policy, order| {
+            S::from_merge(&s1, &s2, 10, max_base_l, policy, order)
+        };
+        // * 🚩折扣：部分重合⇒允许合并，且重复的证据只保留一次
+        assert_s_eq!(Option merge(stamp!({0: 1; 2}), stamp!({0: 2; 3}), 8, Strict, Original), None::<S>);
+        assert_s_eq!(Option merge(stamp!({0: 1; 2}), stamp!({0: 2; 3}), 8, Discount, Original), Some(stamp!({10: 2; 1; 3})));
+        // * 🚩折扣：完全重合⇒仍然拒绝
+        assert_s_eq!(Option merge(stamp!({0: 1; 2}), stamp!({0: 2; 1}), 8, Discount, Original), None::<S>);
+        // * 🚩交织顺序：决定截断时保留的证据
+        assert_s_eq!(Option merge(stamp!({0: 4}), stamp!({0: 1; 3; 2}), 2, Strict, Original), Some(stamp!({10: 1; 4})));
+        assert_s_eq!(Option merge(stamp!({0: 4}), stamp!({0: 1; 3; 2}), 2, Strict, Sequential), Some(stamp!({10: 4; 1})));
+        assert_s_eq!(Option merge(stamp!({0: 4}), stamp!({0: 1; 3; 2}), 2, Strict, Newest), Some(stamp!({10: 4; 3})));
+    }
+
     /// 测试/evidential_base
     #[test]
     fn evidential_base() {
@@ -184,6 +184,29 @@ impl Task {
     }
 }
 
+/// 有关「记忆区合并」的方法
+impl Task {
+    /// 🆕重映射任务内的「任务序列号」与所有「证据基」
+    /// * 🎯合并外来记忆区时，避免其序列号与自身的冲突
+    /// * 📄涉及：自身语句、父信念、最优解
+    /// * ⚠️不处理「父任务」：共享引用需由调用方统一遍历
+    /// * ⚠️改变序列号后，指向自身的[共享引用](RCTask)需重新同步序列号
+    pub(crate) fn remap_serials(
+        &mut self,
+        remap_evidence: impl Fn(ClockTime) -> ClockTime,
+        remap_serial: impl FnOnce(Serial) -> Serial,
+    ) {
+        self.serial = remap_serial(self.serial);
+        self.sentence
+            .stamp_mut()
+            .remap_evidential_base(&remap_evidence);
+        let judgements = self.parent_belief.iter_mut().chain(&mut self.best_solution);
+        for judgement in judgements {
+            judgement.stamp_mut().remap_evidential_base(&remap_evidence);
+        }
+    }
+}
+
 /// 转换到词法Narsese
 impl Task {
     pub fn to_lexical(&self) -> LexicalTask {
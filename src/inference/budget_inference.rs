@@ -6,7 +6,7 @@ use super::{BudgetInferenceFunction, BudgetInferenceResult, Truth};
 use crate::{
     control::ReasonContextWithLinks,
     entity::{BudgetValue, ShortFloat, TLink, TruthValue},
-    inference::{Budget, BudgetFunctions, ReviseResult},
+    inference::{Budget, BudgetFunctions, BudgetPolicyRef, ReviseResult},
     language::Term,
 };
 use nar_dev_utils::{OrSomeRef, RefCount};
@@ -27,11 +27,13 @@ pub trait BudgetInference: Budget {
 
     /// 修正@直接推理
     /// * 🚩【2024-05-21 10:30:50】现在仅用于直接推理，但逻辑可以共用：「反馈到链接」与「具体任务计算」并不矛盾
+    /// * 🚩使用传入的「预算策略」计算
     ///
     /// # 📄OpenNARS
     ///
     /// Evaluate the quality of a revision, then de-prioritize the premises
     fn revise_direct(
+        policy: BudgetPolicyRef,
         new_belief_truth: &impl Truth,
         old_belief_truth: &impl Truth,
         revised_truth: &impl Truth,
@@ -42,12 +44,12 @@ pub trait BudgetInference: Budget {
             new_budget,
             new_task_budget,
             ..
-        } = BudgetValue::revise(
+        } = policy.revise(
             new_belief_truth,
             old_belief_truth,
             revised_truth,
-            current_task_budget,
-            None::<(&BudgetValue, &BudgetValue)>,
+            &BudgetValue::from(&*current_task_budget),
+            None,
         );
         // * 🚩应用修改
         current_task_budget.copy_budget_from(&new_task_budget);
@@ -75,13 +77,14 @@ pub trait BudgetInferenceContext: ReasonContextWithLinks {
         let current_task = self.current_task();
         let current_task_link = self.current_task_link();
         let current_belief_link = self.belief_link_for_budget_inference();
-        let current_links_budget = current_belief_link.map(|b_link| (current_task_link, b_link));
-        let result = BudgetValue::revise(
+        let current_links_budget = current_belief_link
+            .map(|b_link| (BudgetValue::from(current_task_link), BudgetValue::from(b_link)));
+        let result = self.budget_policy().revise(
             new_belief_truth,
             old_belief_truth,
             revised_truth,
-            &*current_task.get_(),
-            current_links_budget,
+            &BudgetValue::from(&*current_task.get_()),
+            current_links_budget.as_ref().map(|(t, b)| (t, b)),
         );
         // * 🚩应用修改
         // 任务更新
@@ -168,12 +171,12 @@ pub trait BudgetInferenceContext: ReasonContextWithLinks {
             self.concept_activation(&b_link.target())
         });
         // * 🚩计算新结果
-        let result = BudgetValue::budget_inference(
+        let result = self.budget_policy().budget_inference(
             function,
-            truth,
+            truth.map(|t| t as &dyn Truth),
             content,
-            t_link,
-            b_link,
+            &BudgetValue::from(t_link),
+            b_link.map(BudgetValue::from).as_ref(),
             target_activation,
         );
         // * 🚩应用新结果
@@ -1,7 +1,10 @@
 //! 🎯复刻OpenNARS `nars.inference.BudgetFunctions`
+//! * 🚩推理与激活相关的预算函数均委托到[标准预算策略](StandardBudget)
+//!   * 📌具体公式见[`BudgetPolicy`]
+//!   * ⚠️推理规则应使用推理器所选的策略，而非此处的扩展方法
 
+use super::{BudgetPolicy, StandardBudget};
 use crate::{
-    debug_assert_matches,
     entity::*,
     global::*,
     inference::{Budget, Truth},
@@ -31,11 +34,11 @@ pub trait BudgetFunctions: Budget {
     ///
     /// @param t The truth value of a judgement
     /// @return The quality of the judgement, according to truth value only
-    fn truth_to_quality(truth: &impl Truth) -> ShortFloat {
+    fn truth_to_quality(truth: &(impl Truth + ?Sized)) -> ShortFloat {
         // * 🚩现在从更原始（无需反复转换）的`_float`函数中来
         ShortFloat::from_float(Self::truth_to_quality_float(truth))
     }
-    fn truth_to_quality_float(truth: &impl Truth) -> Float {
+    fn truth_to_quality_float(truth: &(impl Truth + ?Sized)) -> Float {
         // * 🚩真值⇒质量：期望与「0.75(1-期望)」的最大值
         // * 📝函数：max(c * (f - 0.5) + 0.5, 0.375 - 0.75 * c * (f - 0.5))
         // * 📍最小值：当exp=3/7时，全局最小值为3/7（max的两端相等）
@@ -121,19 +124,12 @@ pub trait BudgetFunctions: Budget {
         solution: &impl Judgement,
         question_task_budget: &impl Budget,
     ) -> BudgetValue {
-        /* 📄OpenNARS改版：
-        final float newP = or(questionTaskBudget.getPriority(), solutionQuality(problem, solution));
-        final float newD = questionTaskBudget.getDurability();
-        final float newQ = truthToQuality(solution);
-        return new BudgetValue(newP, newD, newQ); */
-        // * ️📝新优先级 = 任务优先级 | 解决方案质量
-        let p = question_task_budget.priority() | Self::solution_quality(problem, solution);
-        // * 📝新耐久度 = 任务耐久度
-        let d = question_task_budget.durability();
-        // * ️📝新质量 = 解决方案の真值→质量
-        let q = Self::truth_to_quality(solution);
-        // 返回
-        BudgetValue::new(p, d, q)
+        // * 🚩委托到标准预算策略
+        StandardBudget.solution_eval(
+            Self::solution_quality(problem, solution),
+            solution,
+            &BudgetValue::from(question_task_budget),
+        )
     }
 
     /// 统一的「修正规则」预算函数
@@ -147,65 +143,16 @@ pub trait BudgetFunctions: Budget {
         current_task_budget: &impl Budget,
         current_links_budget: Option<(&impl Budget, &impl Budget)>,
     ) -> ReviseResult {
-        // * 🚩计算落差 | t = task, b = belief
-        let dif_to_new_task =
-            ShortFloat::from_float(revised_truth.expectation_abs_dif(new_belief_truth));
-        let dif_to_old_belief =
-            ShortFloat::from_float(revised_truth.expectation_abs_dif(old_belief_truth));
-        // * 🚩若有：反馈到 [任务链, 信念链]
-        let new_links_budget = current_links_budget.map(|(t_budget, b_budget)| {
-            [
-                // * 📝当前任务链 降低预算：
-                // * * p = link & !difT
-                // * * d = link & !difT
-                // * * q = link
-                BudgetValue::new(
-                    t_budget.priority() & !dif_to_new_task,
-                    t_budget.durability() & !dif_to_new_task,
-                    t_budget.quality(),
-                ),
-                // * 📝当前信念链 降低预算：
-                // * * p = link & !difB
-                // * * d = link & !difB
-                // * * q = link
-                BudgetValue::new(
-                    b_budget.priority() & !dif_to_old_belief,
-                    b_budget.durability() & !dif_to_old_belief,
-                    b_budget.quality(),
-                ),
-            ]
-        });
-        // * 🚩用落差降低优先级、耐久度
-        // * 📝当前任务 降低预算：
-        // * * p = task & !difT
-        // * * d = task & !difT
-        // * * q = task
-        let new_task_budget = BudgetValue::new(
-            current_task_budget.priority() & !dif_to_new_task,
-            current_task_budget.durability() | !dif_to_new_task,
-            current_task_budget.quality(),
-        );
-        // * 🚩用更新后的值计算新差 | ❓此时是否可能向下溢出？
-        // * 📝新差 = 修正后信念.信度 - max(新信念.信度, 旧信念.信度)
-        let dif = revised_truth.confidence()
-            - old_belief_truth
-                .confidence()
-                .max(old_belief_truth.confidence());
-        // * 🚩计算新预算值
-        // * 📝优先级 = 差 | 当前任务
-        // * 📝耐久度 = (差 + 当前任务) / 2
-        // * 📝质量 = 新真值→质量
-        let new_budget = BudgetValue::new(
-            dif | current_task_budget.priority(),
-            ShortFloat::arithmetical_average([dif, current_task_budget.durability()]),
-            Self::truth_to_quality(revised_truth),
-        );
-        // 返回
-        ReviseResult {
-            new_budget,
-            new_task_budget,
-            new_links_budget,
-        }
+        // * 🚩委托到标准预算策略
+        let current_links_budget = current_links_budget
+            .map(|(t_budget, b_budget)| (BudgetValue::from(t_budget), BudgetValue::from(b_budget)));
+        StandardBudget.revise(
+            new_belief_truth,
+            old_belief_truth,
+            revised_truth,
+            &BudgetValue::from(current_task_budget),
+            current_links_budget.as_ref().map(|(t, b)| (t, b)),
+        )
     }
 
     /// 模拟`BudgetFunctions.update`
@@ -282,17 +229,8 @@ pub trait BudgetFunctions: Budget {
     /// @param budget  The budget for the new item
     #[doc(alias = "activate")]
     fn activate_to_concept(&self, concept: &Concept) -> BudgetValue {
-        // * 🚩直接计算
-        let [cp, cd] = [concept.priority(), concept.durability()];
-        let [bp, bd] = [self.priority(), self.durability()];
-        // * 📝优先级 = 概念 | 参考
-        // * 📝耐久度 = (概念 + 参考) / 2
-        // * 📝质量 = 综合所有词项链后的新「质量」
-        BudgetValue::new(
-            cp | bp,
-            ShortFloat::arithmetical_average([cd, bd]),
-            Self::concept_total_quality(concept),
-        )
+        // * 🚩委托到标准预算策略
+        StandardBudget.activate_to_concept(concept, &BudgetValue::from(self.pdq()))
     }
 
     /* ---------------- Bag functions, on all Items ------------------- */
@@ -364,26 +302,12 @@ pub trait BudgetFunctions: Budget {
 
     /// Forward inference result and adjustment
     fn forward(truth: Option<&impl Truth>, content: Option<&Term>) -> BudgetInferenceParameters {
-        // * 📝真值转质量，用不到词项
-        debug_assert_matches!((truth, content), (Some(..), None));
-        let inference_quality = truth.map_or(ShortFloat::ONE, Self::truth_to_quality);
-        let complexity = 1;
-        BudgetInferenceParameters {
-            inference_quality, // 默认值：1
-            complexity,
-        }
+        StandardBudget.forward(truth.map(|t| t as &dyn Truth), content)
     }
 
     /// Backward inference result and adjustment, stronger case
     fn backward(truth: Option<&impl Truth>, content: Option<&Term>) -> BudgetInferenceParameters {
-        // * 📝真值转质量，用不到词项
-        debug_assert_matches!((truth, content), (Some(..), None));
-        let inference_quality = truth.map_or(ShortFloat::ONE, Self::truth_to_quality);
-        let complexity = 1;
-        BudgetInferenceParameters {
-            inference_quality, // 默认值：1
-            complexity,
-        }
+        StandardBudget.backward(truth.map(|t| t as &dyn Truth), content)
     }
 
     /// Backward inference result and adjustment, weaker case
@@ -391,15 +315,7 @@ pub trait BudgetFunctions: Budget {
         truth: Option<&impl Truth>,
         content: Option<&Term>,
     ) -> BudgetInferenceParameters {
-        // * 📝真值转质量，用不到词项
-        debug_assert_matches!((truth, content), (Some(..), None));
-        let inference_quality =
-            ShortFloat::W2C1() * truth.map_or(ShortFloat::ONE, Self::truth_to_quality);
-        let complexity = 1;
-        BudgetInferenceParameters {
-            inference_quality, // 默认值：1
-            complexity,
-        }
+        StandardBudget.backward_weak(truth.map(|t| t as &dyn Truth), content)
     }
 
     /// Forward inference with CompoundTerm conclusion
@@ -407,14 +323,7 @@ pub trait BudgetFunctions: Budget {
         truth: Option<&impl Truth>,
         content: Option<&Term>,
     ) -> BudgetInferenceParameters {
-        // * 📝真值转质量，用到词项的复杂度
-        debug_assert_matches!((truth, content), (Some(..), Some(..)));
-        let inference_quality = truth.map_or(ShortFloat::ONE, Self::truth_to_quality);
-        let complexity = content.map_or(1, Term::complexity);
-        BudgetInferenceParameters {
-            inference_quality, // 默认值：1
-            complexity,        // 默认值：1
-        }
+        StandardBudget.compound_forward(truth.map(|t| t as &dyn Truth), content)
     }
 
     /// Backward inference with CompoundTerm conclusion, stronger case
@@ -422,14 +331,7 @@ pub trait BudgetFunctions: Budget {
         truth: Option<&impl Truth>,
         content: Option<&Term>,
     ) -> BudgetInferenceParameters {
-        // * 📝用到词项的复杂度，用不到真值
-        debug_assert_matches!((truth, content), (None, Some(..)));
-        let inference_quality = ShortFloat::ONE;
-        let complexity = content.map_or(1, Term::complexity);
-        BudgetInferenceParameters {
-            inference_quality,
-            complexity, // 默认值：1
-        }
+        StandardBudget.compound_backward(truth.map(|t| t as &dyn Truth), content)
     }
 
     /// Backward inference with CompoundTerm conclusion, weaker case
@@ -437,30 +339,9 @@ pub trait BudgetFunctions: Budget {
         truth: Option<&impl Truth>,
         content: Option<&Term>,
     ) -> BudgetInferenceParameters {
-        // * 📝用到词项的复杂度，用不到真值
-        debug_assert_matches!((truth, content), (None, Some(..)));
-        let inference_quality = ShortFloat::W2C1();
-        let complexity = content.map_or(1, Term::complexity);
-        BudgetInferenceParameters {
-            inference_quality,
-            complexity, // 默认值：1
-        }
+        StandardBudget.compound_backward_weak(truth.map(|t| t as &dyn Truth), content)
     }
 
-    /// 从「预算推理函数 枚举」到「预算推理函数指针」
-    fn budget_inference_function_from<T: Truth>(
-        function_enum: BudgetInferenceFunction,
-    ) -> BudgetInferenceF<T> {
-        use BudgetInferenceFunction::*;
-        match function_enum {
-            Forward => Self::forward,
-            Backward => Self::backward,
-            BackwardWeak => Self::backward_weak,
-            CompoundForward => Self::compound_forward,
-            CompoundBackward => Self::compound_backward,
-            CompoundBackwardWeak => Self::compound_backward_weak,
-        }
-    }
     /// Common processing for all inference step
     ///
     /// @param inferenceQuality [] Quality of the inference
@@ -474,47 +355,15 @@ pub trait BudgetFunctions: Budget {
         belief_link_budget: Option<&impl Budget>,
         target_activation: ShortFloat,
     ) -> BudgetInferenceResult {
-        // * 🚩应用函数，提取其中的「推理优先级」和「复杂度」
-        let budget_inference_function = Self::budget_inference_function_from::<T>(function);
-        let BudgetInferenceParameters {
-            inference_quality,
-            complexity,
-        } = budget_inference_function(truth, content);
-        // * 🚩获取「任务链」和「信念链」的优先级（默认0）与耐久度（默认1）
-        // * 📝p = self ?? 0
-        // * 📝d = self ?? 1
-        let [t_link_p, t_link_d] = [task_link_budget.priority(), task_link_budget.durability()];
-        let [b_link_p, b_link_d] = match belief_link_budget {
-            // * 🚩有信念链⇒取其值
-            Some(budget) => [budget.priority(), budget.durability()],
-            // * 🚩无信念链⇒默认为[0, 1]
-            None => [ShortFloat::ZERO, ShortFloat::ONE],
-        };
-        // * 🚩更新预算
-        // * 📝p = task | belief
-        // * 📝d = (task / complexity) & belief
-        // * 📝q = inferenceQuality / complexity
-        let [p, d, q] = [
-            t_link_p | b_link_p,
-            (t_link_d / complexity) & b_link_d,
-            inference_quality / complexity,
-        ];
-        // * 🚩有信念链⇒更新信念链预算值
-        // * 🚩【2024-06-20 17:11:30】现在返回一个新的预算值
-        let new_belief_link_budget = belief_link_budget.map(|b_link_budget| {
-            // * 📌此处仅在「概念推理」中出现：能使用可空值处理
-            // * 📝p = belief | quality | targetActivation
-            // * 📝d = belief | quality
-            // * 📝q = belief
-            // * 🚩提升优先级
-            let [b_link_p, b_link_d, b_link_q] = b_link_budget.pdq();
-            BudgetValue::new(b_link_p | q | target_activation, b_link_d | q, b_link_q)
-        });
-        // * 🚩返回预算值
-        BudgetInferenceResult {
-            new_budget: BudgetValue::new(p, d, q),
-            new_belief_link_budget,
-        }
+        // * 🚩委托到标准预算策略
+        StandardBudget.budget_inference(
+            function,
+            truth.map(|t| t as &dyn Truth),
+            content,
+            &BudgetValue::from(task_link_budget),
+            belief_link_budget.map(BudgetValue::from).as_ref(),
+            target_activation,
+        )
     }
 }
 
@@ -540,9 +389,6 @@ mod budget_inference_functions {
         pub complexity: usize,
     }
 
-    /// 统一的「预算值参数计算函数」指针类型（带泛型）
-    pub type BudgetInferenceF<T> = fn(Option<&T>, Option<&Term>) -> BudgetInferenceParameters;
-
     /// 所有可用的预算值函数
     /// * 🎯统一呈现「在推理过程中计算预算值」的「预算超参数」
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -0,0 +1,525 @@
+//! 🆕可替换的「预算策略」
+//! * 🎯在同一套推理规则上，比较不同的注意力分配方案
+//!   * 📄标准预算策略：与OpenNARS一致
+//!   * 📄回答奖励策略：提升「回答问题」的信念所得的预算
+//! * 🚩以特征对象的形式存储于[推理器](crate::control::Reasoner)中
+//!   * 📌推理规则与控制机制均通过「推理上下文」中的策略计算预算值
+//! * 📝所有函数均【返回新预算值】且【不修改所传入参数】
+//!
+//! ## 扩展方式
+//!
+//! * 🚩特征中的所有预算函数均有默认实现（OpenNARS 1.5.8）
+//!   * 📍各类「预算推理」的参数，统一经由[`BudgetPolicy::inference_parameters`]分派
+//! * 💡新的策略只需覆盖需要改变的函数
+
+use super::{
+    BudgetFunctions, BudgetInferenceFunction, BudgetInferenceParameters, BudgetInferenceResult,
+    ReviseResult,
+};
+use crate::{
+    debug_assert_matches,
+    entity::{BudgetValue, Concept, ShortFloat},
+    global::Float,
+    inference::{Budget, Truth},
+    language::Term,
+};
+use std::fmt::Debug;
+
+/// 预算策略
+/// * 🚩第一个参数为策略自身，其后为参与计算的真值、预算值
+///   * 📌真值参数统一为特征对象，预算参数统一为[`BudgetValue`]
+/// * ⚠️需要线程安全：推理器可能在后台线程中运行
+pub trait BudgetPolicy: Debug + Send + Sync {
+    /// 策略的名称
+    /// * 🎯用于报告、比较实验
+    fn name(&self) -> &str;
+
+    /// 真值→质量
+    /// * 🚩默认：同[`BudgetFunctions::truth_to_quality`]
+    fn truth_to_quality(&self, truth: &dyn Truth) -> ShortFloat {
+        BudgetValue::truth_to_quality(truth)
+    }
+
+    /* ----- 问题求解 ----- */
+
+    /// 模拟`BudgetFunctions.solutionEval`
+    /// * 🚩🆕「解的质量」由调用者预先算出：同时用于「比较新旧解」
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// Evaluate the quality of a belief as a solution to a problem, then reward
+    /// the belief and de-prioritize the problem
+    fn solution_eval(
+        &self,
+        solution_quality: ShortFloat,
+        solution_truth: &dyn Truth,
+        question_task_budget: &BudgetValue,
+    ) -> BudgetValue {
+        // * ️📝新优先级 = 任务优先级 | 解决方案质量
+        let p = question_task_budget.priority() | solution_quality;
+        // * 📝新耐久度 = 任务耐久度
+        let d = question_task_budget.durability();
+        // * ️📝新质量 = 解决方案の真值→质量
+        let q = self.truth_to_quality(solution_truth);
+        BudgetValue::new(p, d, q)
+    }
+
+    /* ----- 修正 ----- */
+
+    /// 统一的「修正规则」预算函数
+    /// * 🚩依照改版OpenNARS，从旧稿中重整
+    /// * ✅其中对「任务链可空性=信念链可空性」做断言：`feedBackToLinks == current_links_budget.is_some()`
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// Evaluate the quality of a revision, then de-prioritize the premises
+    fn revise(
+        &self,
+        new_belief_truth: &dyn Truth, // from task
+        old_belief_truth: &dyn Truth, // from belief
+        revised_truth: &dyn Truth,
+        current_task_budget: &BudgetValue,
+        current_links_budget: Option<(&BudgetValue, &BudgetValue)>,
+    ) -> ReviseResult {
+        // * 🚩计算落差 | t = task, b = belief
+        let dif_to_new_task =
+            ShortFloat::from_float(revised_truth.expectation_abs_dif(new_belief_truth));
+        let dif_to_old_belief =
+            ShortFloat::from_float(revised_truth.expectation_abs_dif(old_belief_truth));
+        // * 🚩若有：反馈到 [任务链, 信念链]
+        let new_links_budget = current_links_budget.map(|(t_budget, b_budget)| {
+            [
+                // * 📝当前任务链 降低预算：
+                // * * p = link & !difT
+                // * * d = link & !difT
+                // * * q = link
+                BudgetValue::new(
+                    t_budget.priority() & !dif_to_new_task,
+                    t_budget.durability() & !dif_to_new_task,
+                    t_budget.quality(),
+                ),
+                // * 📝当前信念链 降低预算：
+                // * * p = link & !difB
+                // * * d = link & !difB
+                // * * q = link
+                BudgetValue::new(
+                    b_budget.priority() & !dif_to_old_belief,
+                    b_budget.durability() & !dif_to_old_belief,
+                    b_budget.quality(),
+                ),
+            ]
+        });
+        // * 🚩用落差降低优先级、耐久度
+        // * 📝当前任务 降低预算：
+        // * * p = task & !difT
+        // * * d = task & !difT
+        // * * q = task
+        let new_task_budget = BudgetValue::new(
+            current_task_budget.priority() & !dif_to_new_task,
+            current_task_budget.durability() | !dif_to_new_task,
+            current_task_budget.quality(),
+        );
+        // * 🚩用更新后的值计算新差
+        // * 📝新差 = 修正后信念.信度 - max(新信念.信度, 旧信念.信度)
+        // * ⚠️「证据重合折扣」下，修正后的信度可能低于前提：此时取`0`以免向下溢出
+        let max_confidence = old_belief_truth
+            .confidence()
+            .max(old_belief_truth.confidence());
+        let dif = revised_truth.confidence().max(max_confidence) - max_confidence;
+        // * 🚩计算新预算值
+        // * 📝优先级 = 差 | 当前任务
+        // * 📝耐久度 = (差 + 当前任务) / 2
+        // * 📝质量 = 新真值→质量
+        let new_budget = BudgetValue::new(
+            dif | current_task_budget.priority(),
+            ShortFloat::arithmetical_average([dif, current_task_budget.durability()]),
+            self.truth_to_quality(revised_truth),
+        );
+        // 返回
+        ReviseResult {
+            new_budget,
+            new_task_budget,
+            new_links_budget,
+        }
+    }
+
+    /* ----- 概念激活 ----- */
+
+    /// 模拟`BudgetFunctions.activate`
+    /// * 📝优先级 = 概念 | 参考
+    /// * 📝耐久度 = (概念 + 参考) / 2
+    /// * 📝质量 = 综合所有词项链后的新「质量」
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// Activate a concept by an incoming TaskLink
+    #[doc(alias = "activate")]
+    fn activate_to_concept(&self, concept: &Concept, incoming_budget: &BudgetValue) -> BudgetValue {
+        let [cp, cd] = [concept.priority(), concept.durability()];
+        let [bp, bd] = [incoming_budget.priority(), incoming_budget.durability()];
+        BudgetValue::new(
+            cp | bp,
+            ShortFloat::arithmetical_average([cd, bd]),
+            BudgetValue::concept_total_quality(concept),
+        )
+    }
+
+    /* ----- 预算推理 ----- */
+
+    /// 从「预算推理函数 枚举」分派到具体的参数计算函数
+    fn inference_parameters(
+        &self,
+        function: BudgetInferenceFunction,
+        truth: Option<&dyn Truth>,
+        content: Option<&Term>,
+    ) -> BudgetInferenceParameters {
+        use BudgetInferenceFunction::*;
+        match function {
+            Forward => self.forward(truth, content),
+            Backward => self.backward(truth, content),
+            BackwardWeak => self.backward_weak(truth, content),
+            CompoundForward => self.compound_forward(truth, content),
+            CompoundBackward => self.compound_backward(truth, content),
+            CompoundBackwardWeak => self.compound_backward_weak(truth, content),
+        }
+    }
+
+    /// Forward inference result and adjustment
+    fn forward(&self, truth: Option<&dyn Truth>, content: Option<&Term>) -> BudgetInferenceParameters {
+        // * 📝真值转质量，用不到词项
+        debug_assert_matches!((truth, content), (Some(..), None));
+        let inference_quality = truth.map_or(ShortFloat::ONE, |t| self.truth_to_quality(t));
+        BudgetInferenceParameters {
+            inference_quality, // 默认值：1
+            complexity: 1,
+        }
+    }
+
+    /// Backward inference result and adjustment, stronger case
+    fn backward(
+        &self,
+        truth: Option<&dyn Truth>,
+        content: Option<&Term>,
+    ) -> BudgetInferenceParameters {
+        // * 📝真值转质量，用不到词项
+        debug_assert_matches!((truth, content), (Some(..), None));
+        let inference_quality = truth.map_or(ShortFloat::ONE, |t| self.truth_to_quality(t));
+        BudgetInferenceParameters {
+            inference_quality, // 默认值：1
+            complexity: 1,
+        }
+    }
+
+    /// Backward inference result and adjustment, weaker case
+    fn backward_weak(
+        &self,
+        truth: Option<&dyn Truth>,
+        content: Option<&Term>,
+    ) -> BudgetInferenceParameters {
+        // * 📝真值转质量，用不到词项
+        debug_assert_matches!((truth, content), (Some(..), None));
+        let inference_quality = ShortFloat::W2C1()
+            * truth.map_or(ShortFloat::ONE, |t| self.truth_to_quality(t));
+        BudgetInferenceParameters {
+            inference_quality, // 默认值：1
+            complexity: 1,
+        }
+    }
+
+    /// Forward inference with CompoundTerm conclusion
+    fn compound_forward(
+        &self,
+        truth: Option<&dyn Truth>,
+        content: Option<&Term>,
+    ) -> BudgetInferenceParameters {
+        // * 📝真值转质量，用到词项的复杂度
+        debug_assert_matches!((truth, content), (Some(..), Some(..)));
+        let inference_quality = truth.map_or(ShortFloat::ONE, |t| self.truth_to_quality(t));
+        let complexity = content.map_or(1, Term::complexity);
+        BudgetInferenceParameters {
+            inference_quality, // 默认值：1
+            complexity,        // 默认值：1
+        }
+    }
+
+    /// Backward inference with CompoundTerm conclusion, stronger case
+    fn compound_backward(
+        &self,
+        truth: Option<&dyn Truth>,
+        content: Option<&Term>,
+    ) -> BudgetInferenceParameters {
+        // * 📝用到词项的复杂度，用不到真值
+        debug_assert_matches!((truth, content), (None, Some(..)));
+        let complexity = content.map_or(1, Term::complexity);
+        BudgetInferenceParameters {
+            inference_quality: ShortFloat::ONE,
+            complexity, // 默认值：1
+        }
+    }
+
+    /// Backward inference with CompoundTerm conclusion, weaker case
+    fn compound_backward_weak(
+        &self,
+        truth: Option<&dyn Truth>,
+        content: Option<&Term>,
+    ) -> BudgetInferenceParameters {
+        // * 📝用到词项的复杂度，用不到真值
+        debug_assert_matches!((truth, content), (None, Some(..)));
+        let complexity = content.map_or(1, Term::complexity);
+        BudgetInferenceParameters {
+            inference_quality: ShortFloat::W2C1(),
+            complexity, // 默认值：1
+        }
+    }
+
+    /// Common processing for all inference step
+    ///
+    /// @param inferenceQuality [] Quality of the inference
+    /// @param complexity       [] Syntactic complexity of the conclusion
+    /// @return [] Budget of the conclusion task
+    fn budget_inference(
+        &self,
+        function: BudgetInferenceFunction,
+        truth: Option<&dyn Truth>,
+        content: Option<&Term>,
+        task_link_budget: &BudgetValue,
+        belief_link_budget: Option<&BudgetValue>,
+        target_activation: ShortFloat,
+    ) -> BudgetInferenceResult {
+        // * 🚩应用函数，提取其中的「推理优先级」和「复杂度」
+        let BudgetInferenceParameters {
+            inference_quality,
+            complexity,
+        } = self.inference_parameters(function, truth, content);
+        // * 🚩获取「任务链」和「信念链」的优先级（默认0）与耐久度（默认1）
+        // * 📝p = self ?? 0
+        // * 📝d = self ?? 1
+        let [t_link_p, t_link_d] = [task_link_budget.priority(), task_link_budget.durability()];
+        let [b_link_p, b_link_d] = match belief_link_budget {
+            // * 🚩有信念链⇒取其值
+            Some(budget) => [budget.priority(), budget.durability()],
+            // * 🚩无信念链⇒默认为[0, 1]
+            None => [ShortFloat::ZERO, ShortFloat::ONE],
+        };
+        // * 🚩更新预算
+        // * 📝p = task | belief
+        // * 📝d = (task / complexity) & belief
+        // * 📝q = inferenceQuality / complexity
+        let [p, d, q] = [
+            t_link_p | b_link_p,
+            (t_link_d / complexity) & b_link_d,
+            inference_quality / complexity,
+        ];
+        // * 🚩有信念链⇒更新信念链预算值
+        let new_belief_link_budget = belief_link_budget.map(|b_link_budget| {
+            // * 📌此处仅在「概念推理」中出现：能使用可空值处理
+            // * 📝p = belief | quality | targetActivation
+            // * 📝d = belief | quality
+            // * 📝q = belief
+            // * 🚩提升优先级
+            let [b_link_p, b_link_d, b_link_q] = b_link_budget.pdq();
+            BudgetValue::new(b_link_p | q | target_activation, b_link_d | q, b_link_q)
+        });
+        // * 🚩返回预算值
+        BudgetInferenceResult {
+            new_budget: BudgetValue::new(p, d, q),
+            new_belief_link_budget,
+        }
+    }
+}
+
+/// 对「预算策略」的静态引用
+/// * 🚩与[真值演算](super::TruthCalculusRef)一样静态存在，可随意复制
+pub type BudgetPolicyRef = &'static dyn BudgetPolicy;
+
+/// 标准预算策略
+/// * 📌与OpenNARS 1.5.8一致
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StandardBudget;
+
+impl StandardBudget {
+    /// 静态实例
+    pub const REF: BudgetPolicyRef = &Self;
+}
+
+impl BudgetPolicy for StandardBudget {
+    fn name(&self) -> &str {
+        "standard"
+    }
+}
+
+/// 🆕回答奖励策略
+/// * 🚩在「问题求解」时，以额外的奖励提升所激活信念的优先级、耐久度
+///   * 📝`p = p₀ | reward`，`d = d₀ | reward`
+/// * 🎯让「回答了问题」的知识在记忆区中停留更久、更常被选中
+/// * 📌其它预算函数与标准策略相同
+#[derive(Debug, Clone, Copy)]
+pub struct AnswerRewarding {
+    /// 奖励量（0~1）
+    pub reward: Float,
+}
+
+impl AnswerRewarding {
+    /// 使用默认奖励量的静态实例
+    pub const REF: BudgetPolicyRef = &Self { reward: 0.5 };
+}
+
+impl BudgetPolicy for AnswerRewarding {
+    fn name(&self) -> &str {
+        "answer_rewarding"
+    }
+
+    fn solution_eval(
+        &self,
+        solution_quality: ShortFloat,
+        solution_truth: &dyn Truth,
+        question_task_budget: &BudgetValue,
+    ) -> BudgetValue {
+        let [p, d, q] = StandardBudget
+            .solution_eval(solution_quality, solution_truth, question_task_budget)
+            .pdq();
+        let reward = ShortFloat::from_float(self.reward);
+        BudgetValue::new(p | reward, d | reward, q)
+    }
+}
+
+/// 所有内置的预算策略
+pub const BUDGET_POLICIES: &[BudgetPolicyRef] = &[StandardBudget::REF, AnswerRewarding::REF];
+
+/// 按名称查找内置的预算策略
+pub fn budget_policy_by_name(name: &str) -> Option<BudgetPolicyRef> {
+    BUDGET_POLICIES
+        .iter()
+        .find(|policy| policy.name() == name)
+        .copied()
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{entity::TruthValue, ok, test_term as term, util::AResult};
+
+    /// 预算推理：与OpenNARS公式一致
+    #[test]
+    fn budget_inference() -> AResult {
+        let policy = StandardBudget::REF;
+        let truth = TruthValue::from_fc(1.0, 0.9);
+        let t_link = BudgetValue::from_floats(0.5, 0.8, 0.5);
+        let b_link = BudgetValue::from_floats(0.3, 0.6, 0.4);
+        let result = policy.budget_inference(
+            BudgetInferenceFunction::Forward,
+            Some(&truth),
+            None,
+            &t_link,
+            Some(&b_link),
+            ShortFloat::ZERO,
+        );
+        let q = policy.truth_to_quality(&truth);
+        assert_eq!(result.new_budget.priority(), t_link.priority() | b_link.priority());
+        assert_eq!(result.new_budget.durability(), t_link.durability() & b_link.durability());
+        assert_eq!(result.new_budget.quality(), q);
+        // 信念链被提升
+        let new_b_link = result.new_belief_link_budget.expect("有信念链");
+        assert_eq!(new_b_link.priority(), b_link.priority() | q);
+        // 复合词项：按复杂度折减
+        let content = term!("<A --> B>");
+        let result = policy.budget_inference(
+            BudgetInferenceFunction::CompoundForward,
+            Some(&truth),
+            Some(&content),
+            &t_link,
+            None,
+            ShortFloat::ZERO,
+        );
+        assert_eq!(result.new_budget.quality(), q / content.complexity());
+        assert!(result.new_belief_link_budget.is_none());
+        ok!()
+    }
+
+    /// 断言预算值与给定的`[p, d, q]`一致
+    /// * 📌容许四位精度内的误差：兼容`high_precision`特性
+    fn assert_pdq(budget: &BudgetValue, expected: [Float; 3]) {
+        let pdq = budget.pdq_float();
+        let close = pdq.iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-4);
+        assert!(close, "{pdq:?} != {expected:?}");
+    }
+
+    /// 与重构前的「预算函数」一致
+    /// * 📌期望值取自重构前（`BudgetFunctions`）的计算结果
+    #[test]
+    fn same_as_budget_functions() -> AResult {
+        let policy = StandardBudget::REF;
+        let [t1, t2, t3] = [
+            TruthValue::from_fc(1.0, 0.9),
+            TruthValue::from_fc(0.0, 0.8),
+            TruthValue::from_fc(0.6, 0.95),
+        ];
+        let budget = BudgetValue::from_floats(0.7, 0.6, 0.5);
+        let t_link = BudgetValue::from_floats(0.5, 0.8, 0.5);
+        let b_link = BudgetValue::from_floats(0.3, 0.6, 0.4);
+        // 修正
+        let revised = policy.revise(&t1, &t2, &t3, &budget, Some((&t_link, &b_link)));
+        assert_pdq(&revised.new_budget, [0.745, 0.375, 0.595]);
+        assert_pdq(&revised.new_task_budget, [0.4515, 0.858, 0.5]);
+        let [new_t_link, new_b_link] = revised.new_links_budget.expect("有链接");
+        assert_pdq(&new_t_link, [0.3225, 0.516, 0.5]);
+        assert_pdq(&new_b_link, [0.1515, 0.303, 0.4]);
+        // 问题求解
+        let solved = policy.solution_eval(ShortFloat::from_float(0.2), &t1, &budget);
+        assert_pdq(&solved, [0.76, 0.6, 0.95]);
+        // 预算推理：有信念链
+        use BudgetInferenceFunction::*;
+        let activation = ShortFloat::from_float(0.2);
+        for (function, expected, expected_b_link) in [
+            (Forward, [0.65, 0.48, 0.595], [0.7732, 0.838, 0.4]),
+            (Backward, [0.65, 0.48, 0.595], [0.7732, 0.838, 0.4]),
+            (BackwardWeak, [0.65, 0.48, 0.2975], [0.6067, 0.719, 0.4]),
+        ] {
+            let result = policy.budget_inference(function, Some(&t3), None, &t_link, Some(&b_link), activation);
+            assert_pdq(&result.new_budget, expected);
+            assert_pdq(&result.new_belief_link_budget.expect("有信念链"), expected_b_link);
+        }
+        // 预算推理：复合词项
+        let content = term!("<A --> B>");
+        for (function, truth, expected) in [
+            (CompoundForward, Some(&t3), [0.5, 0.2667, 0.1983]),
+            (CompoundBackward, None, [0.5, 0.2667, 0.3333]),
+            (CompoundBackwardWeak, None, [0.5, 0.2667, 0.1667]),
+        ] {
+            let truth = truth.map(|t| t as &dyn Truth);
+            let result = policy.budget_inference(function, truth, Some(&content), &t_link, None, ShortFloat::ZERO);
+            assert_pdq(&result.new_budget, expected);
+        }
+        ok!()
+    }
+
+    /// 回答奖励：只改变「问题求解」的预算
+    #[test]
+    fn answer_rewarding() -> AResult {
+        let [standard, rewarding] = [StandardBudget::REF, AnswerRewarding::REF];
+        let truth = TruthValue::from_fc(1.0, 0.9);
+        let budget = BudgetValue::from_floats(0.1, 0.1, 0.1);
+        let quality = ShortFloat::from_float(0.2);
+        let [s, r] = [standard, rewarding].map(|p| p.solution_eval(quality, &truth, &budget));
+        assert!(r.priority() > s.priority());
+        assert!(r.durability() > s.durability());
+        assert_eq!(r.quality(), s.quality());
+        // 其它函数不变
+        assert_eq!(
+            standard.revise(&truth, &truth, &truth, &budget, None).new_budget,
+            rewarding.revise(&truth, &truth, &truth, &budget, None).new_budget,
+        );
+        ok!()
+    }
+
+    /// 按名称查找
+    #[test]
+    fn by_name() {
+        for policy in BUDGET_POLICIES {
+            let found = budget_policy_by_name(policy.name()).expect("内置策略应可查找");
+            assert_eq!(found.name(), policy.name());
+        }
+        assert!(budget_policy_by_name("nonexistent").is_none());
+    }
+}
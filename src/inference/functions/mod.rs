@@ -5,7 +5,9 @@
 //!   * 控制依据，如：袋中Item的优先级调整（激活、遗忘……）
 //! * 📄实用函数
 //! * 📄真值函数
+//! * 📄真值演算
 //! * 📄预算函数
+//! * 📄预算策略
 //!
 //! * 🚩【2024-05-16 14:04:59】重构并独立成单独的子模块
 
@@ -16,6 +18,12 @@ nar_dev_utils::mods! {
     // 真值函数 `TruthFunctions`
     pub use truth_functions;
 
+    // 真值演算 `TruthCalculus`
+    pub use truth_calculus;
+
     // 预算值函数 `BudgetFunctions`
     pub use budget_functions;
+
+    // 预算策略 `BudgetPolicy`
+    pub use budget_policy;
 }
@@ -0,0 +1,572 @@
+//! 🆕可替换的「真值演算」
+//! * 🎯在同一套推理规则上，比较不同的不确定性演算
+//!   * 📄标准NAL演算：与OpenNARS一致
+//!   * 📄指数证据视界：以不同的曲线在「证据量」与「信度」间转换
+//! * 🚩以特征对象的形式存储于[推理器](crate::control::Reasoner)中
+//!   * 📌所有推理规则均通过「推理上下文」中的演算计算真值
+//! * 📝所有函数均【返回新真值对象】且【不修改所传入参数】
+//!
+//! ## 扩展方式
+//!
+//! * 🚩特征中的所有真值函数均有默认实现（标准NAL）
+//!   * 📍「弱推理」中的证据量转换，统一经由[`TruthCalculus::w2c`]与[`TruthCalculus::c2w`]
+//! * 💡新的演算只需覆盖需要改变的函数
+
+use crate::{
+    entity::{ShortFloat, TruthValue},
+    global::Float,
+    inference::Truth,
+    parameters::DEFAULT_PARAMETERS,
+};
+use std::fmt::Debug;
+
+/// 真值演算
+/// * 🚩第一个参数为演算自身，其后为参与计算的真值
+///   * 📌真值参数统一为特征对象，以便存储为函数指针
+/// * ⚠️需要线程安全：推理器可能在后台线程中运行
+pub trait TruthCalculus: Debug + Send + Sync {
+    /// 演算的名称
+    /// * 🎯用于报告、比较实验
+    fn name(&self) -> &str;
+
+    /* ----- 证据量转换 ----- */
+
+    /// 从「证据量」到「信度」
+    /// * 🚩默认：`w / (w + k)`
+    fn w2c(&self, w: Float) -> ShortFloat {
+        ShortFloat::w2c(w)
+    }
+
+    /// 从「信度」到「证据量」
+    /// * 🚩默认：`k * c / (1 - c)`
+    /// * ⚠️信度为`1`时，证据量为无穷
+    fn c2w(&self, c: ShortFloat) -> Float {
+        c.c2w()
+    }
+
+    /* ----- Single argument functions, called in MatchingRules ----- */
+
+    /// 🆕恒等真值函数，用于转换推理
+    /// * 🎯维护「真值计算」的一致性：所有真值计算均通过真值函数
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<(*, A, B) --> R>} |- <A --> (/, R, _, B)>
+    fn identity(&self, v1: &dyn Truth) -> TruthValue {
+        let [f1, c1] = v1.fc();
+        // * 📝频率=旧频率
+        // * 📝信度=旧信度
+        TruthValue::new_fc(f1, c1)
+    }
+
+    /// 模拟`TruthFunctions.conversion`
+    /// * 🚩转换
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<A ==> B>} |- <B ==> A>
+    fn conversion(&self, v1: &dyn Truth) -> TruthValue {
+        let [f1, c1] = v1.fc();
+        // * 📝总频数=频率、信度之合取
+        // * 📝频率=1（完全正面之猜测）
+        // * 📝信度=总频数转换（保证弱推理）
+        let w = f1 & c1;
+        let c = self.w2c(w.to_float());
+        TruthValue::new_fc(ShortFloat::ONE, c)
+    }
+
+    /* ----- Single argument functions, called in StructuralRules ----- */
+
+    /// 模拟`TruthFunctions.negation`
+    /// * 🚩否定
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {A} |- (--A)
+    fn negation(&self, v1: &dyn Truth) -> TruthValue {
+        // * 📝频率相反，信度相等
+        let f = !v1.frequency();
+        let c = v1.confidence();
+        TruthValue::new_fc(f, c)
+    }
+
+    /// 模拟`TruthFunctions.contraposition`
+    /// * 🚩逆否
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<A ==> B>} |- <(--, B) ==> (--, A)>
+    fn contraposition(&self, v1: &dyn Truth) -> TruthValue {
+        // * 📝频率为零，信度是弱
+        let [f1, c1] = v1.fc();
+        let w = !f1 & c1;
+        let c = self.w2c(w.to_float());
+        TruthValue::new_fc(ShortFloat::ZERO, c)
+    }
+
+    /* ----- double argument functions, called in SyllogisticRules ----- */
+
+    /// 模拟`TruthFunctions.revision`
+    /// * 🚩修正
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<S ==> P>, <S ==> P>} |- <S ==> P>
+    fn revision(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        // * 📝转换为「频数视角」，频数相加，并转换回（频率，信度）二元组
+        // * ✅特别兼容「信度为1」的「无穷证据量」情况：覆盖 or 取平均
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        let is_inf_1 = c1.is_one();
+        let is_inf_2 = c2.is_one();
+        let ave_ari = ShortFloat::arithmetical_average;
+        let [f, c] = match [is_inf_1, is_inf_2] {
+            // * 1 & 2
+            [true, true] => [ave_ari([f1, f2]), ave_ari([c1, c2])],
+            // * 1
+            [true, false] => [f1, c1],
+            // * 2
+            [false, true] => [f2, c2],
+            // * _
+            [false, false] => {
+                let w1 = self.c2w(c1);
+                let w2 = self.c2w(c2);
+                let w = w1 + w2;
+                let f1 = f1.to_float();
+                let f2 = f2.to_float();
+                [
+                    ShortFloat::from_float((w1 * f1 + w2 * f2) / w),
+                    self.w2c(w),
+                ]
+            }
+        };
+        TruthValue::new_fc(f, c)
+    }
+
+    /// 模拟`TruthFunctions.deduction`
+    /// * 🚩演绎
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<S ==> M>, <M ==> P>} |- <S ==> P>
+    fn deduction(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝频率二者合取，信度四者合取
+        let f = f1 & f2;
+        let c = c1 & c2 & f;
+        TruthValue::new_fc(f, c)
+    }
+
+    /// 模拟`TruthFunctions.deduction`
+    /// * 🚩演绎导出
+    /// * ⚠️此处会设置「真值」的`is_analytic`为`true`
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {M, <M ==> P>} |- P
+    fn analytic_deduction(&self, v1: &dyn Truth, reliance: ShortFloat) -> TruthValue {
+        let [f1, c1] = v1.fc();
+        // * 📌对于第二个「分析性前提」使用「依赖度」衡量
+        // * 📝频率采用前者，信度合取以前者频率、依赖度，并标明这是「分析性」真值
+        let c = f1 & c1 & reliance;
+        TruthValue::new(f1, c, true)
+    }
+
+    /// 模拟`TruthFunctions.analogy`
+    /// * 🚩类比
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<S ==> M>, <M <=> P>} |- <S ==> P>
+    fn analogy(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝类比：频率为二者合取，信度为双方信度、第二方频率三者合取
+        let f = f1 & f2;
+        let c = c1 & c2 & f2;
+        TruthValue::new_fc(f, c)
+    }
+
+    /// 模拟`TruthFunctions.resemblance`
+    /// * 🚩相似
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<S <=> M>, <M <=> P>} |- <S <=> P>
+    fn resemblance(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝类比：频率为二者合取，信度为「双方频率之析取」与「双方信度之合取」之合取
+        let f = f1 & f2;
+        let c = c1 & c2 & (f1 | f2);
+        TruthValue::new_fc(f, c)
+    }
+
+    /// 模拟`TruthFunctions.abduction`
+    /// * 🚩溯因
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<S ==> M>, <P ==> M>} |- <S ==> P>
+    fn abduction(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        // * 🚩分析性⇒无意义（信度清零）
+        if v1.is_analytic() || v2.is_analytic() {
+            return TruthValue::new_analytic_default();
+        }
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝总频数=第二方频率与双方信度之合取
+        // * 📝频率=第一方频率
+        // * 📝信度=总频数转换（总是弱推理）
+        let w = f2 & c1 & c2;
+        let c = self.w2c(w.to_float());
+        TruthValue::new_fc(f1, c)
+    }
+
+    /// 模拟`TruthFunctions.abduction`
+    /// * 🚩溯因导出
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {M, <P ==> M>} |- P
+    fn analytic_abduction(&self, v1: &dyn Truth, reliance: ShortFloat) -> TruthValue {
+        // * 🚩分析性⇒无意义（信度清零） | 只能「分析」一次
+        if v1.is_analytic() {
+            return TruthValue::new_analytic_default();
+        }
+        let [f1, c1] = v1.fc();
+        // * 📝总频数=频率与「依赖度」之合取
+        // * 📝频率=第一方频率
+        // * 📝信度=总频数转换（总是弱推理）
+        let w = c1 & reliance;
+        let c = self.w2c(w.to_float());
+        TruthValue::new(f1, c, true)
+    }
+
+    /// 模拟`TruthFunctions.induction`
+    /// * 🚩归纳
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<M ==> S>, <M ==> P>} |- <S ==> P>
+    fn induction(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        // * 📝归纳是倒过来的归因
+        self.abduction(v2, v1)
+    }
+
+    /// 模拟`TruthFunctions.exemplification`
+    /// * 🚩例证
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<M ==> S>, <P ==> M>} |- <S ==> P>
+    fn exemplification(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        // * 🚩分析性⇒无意义（信度清零） | 只能「分析」一次
+        if v1.is_analytic() || v2.is_analytic() {
+            return TruthValue::new_analytic_default();
+        }
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝总频数=四方值综合
+        // * 📝频率=1（无中生有）
+        // * 📝信度=总频数转换（总是弱推理）
+        let w = f1 & f2 & c1 & c2;
+        let c = self.w2c(w.to_float());
+        TruthValue::new_fc(ShortFloat::ONE, c)
+    }
+
+    /// 模拟`TruthFunctions.comparison`
+    /// * 🚩比对
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<M ==> S>, <M ==> P>} |- <S <=> P>
+    fn comparison(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝总频数=「双频之析取」与「双信之合取」之合取
+        // * 📝频率=「双频之合取」/「双频之析取」（📌根据函数图像，可以取"(0,0) -> 0"为可去间断点）
+        // * 📝信度=总频数转换（总是弱推理）
+        let f0 = f1 | f2;
+        let f = match f0.is_zero() {
+            true => ShortFloat::ZERO,
+            false => (f1 & f2) / f0,
+        };
+        let w = f0 & c1 & c2;
+        let c = self.w2c(w.to_float());
+        TruthValue::new_fc(f, c)
+    }
+
+    /* ----- desire-value functions, called in SyllogisticRules ----- */
+
+    /// 模拟`TruthFunctions.desireStrong`
+    /// * 💭强欲望推理
+    fn desire_strong(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝频率=双频之合取
+        // * 📝信度=双方信度 合取 第二方频率
+        let f = f1 & f2;
+        let c = c1 & c2 & f2;
+        TruthValue::new_fc(f, c)
+    }
+
+    /// 模拟`TruthFunctions.desireWeak`
+    /// * 💭弱欲望推理
+    fn desire_weak(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝频率=双频之合取
+        // * 📝信度=双方信度 合取 第二方频率 合取 单位数目信度（保证弱推理）
+        let f = f1 & f2;
+        let c = c1 & c2 & f2 & self.w2c(1.0);
+        TruthValue::new_fc(f, c)
+    }
+
+    /// 模拟`TruthFunctions.desireDed`
+    /// * 🚩欲望演绎
+    fn desire_deduction(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝频率=双频之合取
+        // * 📝信度=双信之合取
+        let f = f1 & f2;
+        let c = c1 & c2;
+        TruthValue::new_fc(f, c)
+    }
+
+    /// 模拟`TruthFunctions.desireInd`
+    /// * 🚩欲望归纳
+    fn desire_induction(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝总频数=第二方频率 合取 双信之合取
+        // * 📝频率=第一方频率
+        // * 📝信度=总频数转换（保证弱推理）
+        let w = f2 & c1 & c2;
+        let c = self.w2c(w.to_float());
+        TruthValue::new_fc(f1, c)
+    }
+
+    /* ----- double argument functions, called in CompositionalRules ----- */
+
+    /// 模拟`TruthFunctions.union`
+    /// * 🚩并集
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<M --> S>, <M <-> P>} |- <M --> (S|P)>
+    #[doc(alias = "union")]
+    fn union_(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝频率=双频之析取
+        // * 📝信度=双信之合取
+        let f = f1 | f2;
+        let c = c1 & c2;
+        TruthValue::new_fc(f, c)
+    }
+
+    /// 模拟`TruthFunctions.intersection`
+    /// * 🚩交集
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {<M --> S>, <M <-> P>} |- <M --> (S&P)>
+    fn intersection(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        let ([f1, c1], [f2, c2]) = (v1.fc(), v2.fc());
+        // * 📝频率=双频之合取
+        // * 📝信度=双信之合取
+        let f = f1 & f2;
+        let c = c1 & c2;
+        TruthValue::new_fc(f, c)
+    }
+
+    /// 模拟`TruthFunctions.reduceDisjunction`
+    /// * 🚩消去性析取
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {(||, A, B), (--, B)} |- A
+    fn reduce_disjunction(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        // * 🚩演绎（反向交集，依赖度=1）
+        let v0 = self.intersection(v1, &self.negation(v2));
+        self.analytic_deduction(&v0, ShortFloat::ONE)
+    }
+
+    /// 模拟`TruthFunctions.reduceConjunction`
+    /// * 🚩消去性合取
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {(--, (&&, A, B)), B} |- (--, A)
+    fn reduce_conjunction(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        // * 🚩否定演绎（反向交集（内部取反），依赖度=1）
+        let v0 = self.intersection(&self.negation(v1), v2);
+        self.negation(&self.analytic_deduction(&v0, ShortFloat::ONE))
+    }
+
+    /// 模拟`TruthFunctions.reduceConjunctionNeg`
+    /// * 🚩消去性合取（否定）
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {(--, (&&, A, (--, B))), (--, B)} |- (--, A)
+    fn reduce_conjunction_neg(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        // * 🚩消取，但对第二方套否定
+        self.reduce_conjunction(v1, &self.negation(v2))
+    }
+
+    /// 模拟`TruthFunctions.anonymousAnalogy`
+    /// * 🚩匿名溯因
+    ///
+    /// # 📄OpenNARS
+    ///
+    /// {(&&, <#x() ==> M>, <#x() ==> P>), S ==> M} |- <S ==> P>
+    fn anonymous_analogy(&self, v1: &dyn Truth, v2: &dyn Truth) -> TruthValue {
+        // * 📝中间频率=第一方频
+        // * 📝中间信度=第一方信度作为「总频数」（弱推理）
+        let [f1, c1] = v1.fc();
+        let v0 = TruthValue::new_fc(f1, self.w2c(c1.to_float()));
+        // * 🚩再参与「类比」（弱中之弱）
+        self.analogy(v2, &v0)
+    }
+}
+
+/// 对「真值演算」的静态引用
+/// * 🚩与[推理引擎](crate::inference::InferenceEngine)一样静态存在，可随意复制
+pub type TruthCalculusRef = &'static dyn TruthCalculus;
+
+/// 以「真值演算」计算的单真值函数
+pub type TruthCalculusFSingle = fn(TruthCalculusRef, &dyn Truth) -> TruthValue;
+/// 以「真值演算」计算的双真值函数
+pub type TruthCalculusFDouble = fn(TruthCalculusRef, &dyn Truth, &dyn Truth) -> TruthValue;
+
+/// 标准NAL演算
+/// * 📌与OpenNARS 1.5.8一致
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StandardNal;
+
+impl StandardNal {
+    /// 静态实例
+    pub const REF: TruthCalculusRef = &Self;
+}
+
+impl TruthCalculus for StandardNal {
+    fn name(&self) -> &str {
+        "standard"
+    }
+}
+
+/// 🆕指数证据视界演算
+/// * 🚩以指数曲线在「证据量」与「信度」间转换
+///   * 📝`c = 1 - e^(-w/k)`，`w = -k ln(1 - c)`
+/// * 📌相比标准的`w / (w + k)`：
+///   * 📍少量证据时，信度增长更快（单位证据的信度为`1 - 1/e ≈ 0.63`而非`0.5`）
+///   * 📍大量证据时，信度更快地逼近`1`
+/// * 📌其它真值函数与标准NAL相同
+/// * ⚠️有限证据的信度不超过[`ShortFloat::ALMOST_ONE`]
+///   * 📝`1 - e^(-w/k)`在证据较多时舍入为`1`，会被当作「无穷证据」
+///   * 📄修正时，信度为`1`的信念将无视新的证据
+#[derive(Debug, Clone, Copy)]
+pub struct ExponentialHorizon {
+    /// 证据视界`k`
+    pub horizon: Float,
+}
+
+impl ExponentialHorizon {
+    /// 使用默认证据视界的静态实例
+    pub const REF: TruthCalculusRef = &Self {
+        horizon: DEFAULT_PARAMETERS.horizon,
+    };
+}
+
+impl TruthCalculus for ExponentialHorizon {
+    fn name(&self) -> &str {
+        "exponential_horizon"
+    }
+
+    fn w2c(&self, w: Float) -> ShortFloat {
+        ShortFloat::from_float(1.0 - (-w / self.horizon).exp()).min(ShortFloat::ALMOST_ONE)
+    }
+
+    fn c2w(&self, c: ShortFloat) -> Float {
+        -self.horizon * (1.0 - c.to_float()).ln()
+    }
+}
+
+/// 所有内置的真值演算
+pub const TRUTH_CALCULI: &[TruthCalculusRef] = &[StandardNal::REF, ExponentialHorizon::REF];
+
+/// 按名称查找内置的真值演算
+pub fn truth_calculus_by_name(name: &str) -> Option<TruthCalculusRef> {
+    TRUTH_CALCULI
+        .iter()
+        .find(|calculus| calculus.name() == name)
+        .copied()
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ok, util::AResult};
+
+    /// 函数表：可以函数指针形式选用真值函数
+    #[test]
+    fn function_table() {
+        let _: [TruthCalculusFSingle; 4] = [
+            |c, v1| c.identity(v1),
+            |c, v1| c.conversion(v1),
+            |c, v1| c.negation(v1),
+            |c, v1| c.contraposition(v1),
+        ];
+        let _: [TruthCalculusFDouble; 3] = [
+            |c, v1, v2| c.deduction(v1, v2),
+            |c, v1, v2| c.induction(v1, v2),
+            |c, v1, v2| c.reduce_conjunction(v1, v2),
+        ];
+    }
+
+    /// 证据量转换互逆
+    #[test]
+    fn w2c_c2w() -> AResult {
+        for calculus in TRUTH_CALCULI {
+            for w in [0.0, 0.5, 1.0, 2.0, 9.0, 10.0, 20.0, 100.0, 1000.0] {
+                let c = calculus.w2c(w);
+                let w_back = calculus.c2w(c);
+                // 信度有精度限制
+                assert_eq!(calculus.w2c(w_back), c, "{} @ w = {w}", calculus.name());
+            }
+        }
+        ok!()
+    }
+
+    /// 有限证据的信度不为`1`：修正仍会改变饱和的信念
+    #[test]
+    fn saturated_revision() -> AResult {
+        for calculus in TRUTH_CALCULI {
+            let saturated = TruthValue::new_fc(ShortFloat::ONE, calculus.w2c(100.0));
+            assert!(!saturated.confidence().is_one(), "{}", calculus.name());
+            let revised = calculus.revision(&saturated, &TruthValue::from_fc(0.0, 0.9));
+            assert!(revised.frequency() < saturated.frequency(), "{}", calculus.name());
+        }
+        ok!()
+    }
+
+    /// 两种演算：强推理相同，弱推理不同
+    #[test]
+    fn compare_calculi() -> AResult {
+        let [standard, exponential] = [StandardNal::REF, ExponentialHorizon::REF];
+        let v1 = TruthValue::from_fc(1.0, 0.9);
+        let v2 = TruthValue::from_fc(0.8, 0.9);
+        // 演绎：相同
+        assert_eq!(standard.deduction(&v1, &v2), exponential.deduction(&v1, &v2));
+        // 归纳：指数视界下信度更高
+        let [ind_s, ind_e] = [standard, exponential].map(|c| c.induction(&v1, &v2));
+        assert_eq!(ind_s.frequency(), ind_e.frequency());
+        assert!(ind_e.confidence() > ind_s.confidence());
+        // 与标准真值函数一致
+        assert_eq!(ind_s, standard.abduction(&v2, &v1));
+        ok!()
+    }
+
+    /// 按名称查找
+    #[test]
+    fn by_name() {
+        for calculus in TRUTH_CALCULI {
+            let found = truth_calculus_by_name(calculus.name()).expect("内置演算应可查找");
+            assert_eq!(found.name(), calculus.name());
+        }
+        assert!(truth_calculus_by_name("nonexistent").is_none());
+    }
+}
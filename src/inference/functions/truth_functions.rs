@@ -1,7 +1,11 @@
 //! 🎯复刻OpenNARS `nars.inference.TruthFunctions`
 //! * 🚩【2024-06-21 00:31:46】现在基于[`Truth`]使用静态分派，并限定返回值为具体类型[`TruthValue`]
 //!   * 📝若使用`-> impl Truth`，会导致生命周期问题
+//! * 🚩现在所有函数均委托到[标准NAL演算](StandardNal)
+//!   * 📌具体公式见[`TruthCalculus`]
+//!   * ⚠️推理规则应使用推理器所选的演算，而非此处的扩展方法
 /// * 📝所有函数均【返回新真值对象】且【不修改所传入参数】
+use super::{StandardNal, TruthCalculus};
 use crate::{
     entity::{ShortFloat, TruthValue},
     inference::Truth,
@@ -24,10 +28,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v1 Truth value of the premise
     /// @return Truth value of the conclusion
     fn identity(&self) -> TruthValue {
-        let [f1, c1] = self.fc();
-        // * 📝频率=旧频率
-        // * 📝信度=旧信度
-        TruthValue::new_fc(f1, c1)
+        StandardNal.identity(self)
     }
 
     /// 模拟`TruthFunctions.conversion`
@@ -40,13 +41,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v1 Truth value of the premise
     /// @return Truth value of the conclusion
     fn conversion(&self) -> TruthValue {
-        let [f1, c1] = self.fc();
-        // * 📝总频数=频率、信度之合取
-        // * 📝频率=1（完全正面之猜测）
-        // * 📝信度=总频数转换（保证弱推理）
-        let w = f1 & c1;
-        let c = ShortFloat::w2c(w.to_float());
-        TruthValue::new_fc(ShortFloat::ONE, c)
+        StandardNal.conversion(self)
     }
 
     /* ----- Single argument functions, called in StructuralRules ----- */
@@ -61,10 +56,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v1 Truth value of the premise
     /// @return Truth value of the conclusion
     fn negation(&self) -> TruthValue {
-        // * 📝频率相反，信度相等
-        let f = !self.frequency();
-        let c = self.confidence();
-        TruthValue::new_fc(f, c)
+        StandardNal.negation(self)
     }
 
     /// 模拟`TruthFunctions.contraposition`
@@ -77,11 +69,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v1 Truth value of the premise
     /// @return Truth value of the conclusion
     fn contraposition(&self) -> TruthValue {
-        // * 📝频率为零，信度是弱
-        let [f1, c1] = self.fc();
-        let w = !f1 & c1;
-        let c = ShortFloat::w2c(w.to_float());
-        TruthValue::new_fc(ShortFloat::ZERO, c)
+        StandardNal.contraposition(self)
     }
 
     /* ----- double argument functions, called in SyllogisticRules ----- */
@@ -97,34 +85,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn revision(&self, v2: &impl Truth) -> TruthValue {
-        // * 📝转换为「频数视角」，频数相加，并转换回（频率，信度）二元组
-        // * ✅特别兼容「信度为1」的「无穷证据量」情况：覆盖 or 取平均
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        let is_inf_1 = c1.is_one();
-        let is_inf_2 = c2.is_one();
-        let ave_ari = ShortFloat::arithmetical_average;
-        // * ✅在Rust中可以直接使用模式匹配
-        let [f, c] = match [is_inf_1, is_inf_2] {
-            // * 1 & 2
-            [true, true] => [ave_ari([f1, f2]), ave_ari([c1, c2])],
-            // * 1
-            [true, false] => [f1, c1],
-            // * 2
-            [false, true] => [f2, c2],
-            // * _
-            [false, false] => {
-                let w1 = ShortFloat::c2w(&c1);
-                let w2 = ShortFloat::c2w(&c2);
-                let w = w1 + w2;
-                let f1 = f1.to_float();
-                let f2 = f2.to_float();
-                [
-                    ShortFloat::from_float((w1 * f1 + w2 * f2) / w),
-                    ShortFloat::w2c(w),
-                ]
-            }
-        };
-        TruthValue::new_fc(f, c)
+        StandardNal.revision(self, v2)
     }
 
     /// 模拟`TruthFunctions.deduction`
@@ -138,11 +99,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn deduction(&self, v2: &impl Truth) -> TruthValue {
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝频率二者合取，信度四者合取
-        let f = f1 & f2;
-        let c = c1 & c2 & f;
-        TruthValue::new_fc(f, c)
+        StandardNal.deduction(self, v2)
     }
 
     /// 模拟`TruthFunctions.deduction`
@@ -158,11 +115,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param reliance Confidence of the second (analytical) premise
     /// @return Truth value of the conclusion
     fn analytic_deduction(&self, reliance: ShortFloat) -> TruthValue {
-        let [f1, c1] = self.fc();
-        // * 📌对于第二个「分析性前提」使用「依赖度」衡量
-        // * 📝频率采用前者，信度合取以前者频率、依赖度，并标明这是「分析性」真值
-        let c = f1 & c1 & reliance;
-        TruthValue::new(f1, c, true)
+        StandardNal.analytic_deduction(self, reliance)
     }
 
     /// 模拟`TruthFunctions.analogy`
@@ -176,11 +129,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn analogy(&self, v2: &impl Truth) -> TruthValue {
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝类比：频率为二者合取，信度为双方信度、第二方频率三者合取
-        let f = f1 & f2;
-        let c = c1 & c2 & f2;
-        TruthValue::new_fc(f, c)
+        StandardNal.analogy(self, v2)
     }
 
     /// 模拟`TruthFunctions.resemblance`
@@ -194,11 +143,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn resemblance(&self, v2: &impl Truth) -> TruthValue {
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝类比：频率为二者合取，信度为「双方频率之析取」与「双方信度之合取」之合取
-        let f = f1 & f2;
-        let c = c1 & c2 & (f1 | f2);
-        TruthValue::new_fc(f, c)
+        StandardNal.resemblance(self, v2)
     }
 
     /// 模拟`TruthFunctions.abduction`
@@ -212,17 +157,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn abduction(&self, v2: &impl Truth) -> TruthValue {
-        // * 🚩分析性⇒无意义（信度清零）
-        if self.is_analytic() || v2.is_analytic() {
-            return TruthValue::new_analytic_default();
-        }
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝总频数=第二方频率与双方信度之合取
-        // * 📝频率=第一方频率
-        // * 📝信度=总频数转换（总是弱推理）
-        let w = f2 & c1 & c2;
-        let c = ShortFloat::w2c(w.to_float());
-        TruthValue::new_fc(f1, c)
+        StandardNal.abduction(self, v2)
     }
 
     /// 模拟`TruthFunctions.abduction`
@@ -236,17 +171,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param reliance Confidence of the second (analytical) premise
     /// @return Truth value of the conclusion
     fn analytic_abduction(&self, reliance: ShortFloat) -> TruthValue {
-        // * 🚩分析性⇒无意义（信度清零） | 只能「分析」一次
-        if self.is_analytic() {
-            return TruthValue::new_analytic_default();
-        }
-        let [f1, c1] = self.fc();
-        // * 📝总频数=频率与「依赖度」之合取
-        // * 📝频率=第一方频率
-        // * 📝信度=总频数转换（总是弱推理）
-        let w = c1 & reliance;
-        let c = ShortFloat::w2c(w.to_float());
-        TruthValue::new(f1, c, true)
+        StandardNal.analytic_abduction(self, reliance)
     }
 
     /// 模拟`TruthFunctions.induction`
@@ -260,8 +185,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn induction(&self, v2: &impl Truth) -> TruthValue {
-        // * 📝归纳是倒过来的归因
-        v2.abduction(self)
+        StandardNal.induction(self, v2)
     }
 
     /// 模拟`TruthFunctions.exemplification`
@@ -276,17 +200,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn exemplification(&self, v2: &impl Truth) -> TruthValue {
-        // * 🚩分析性⇒无意义（信度清零） | 只能「分析」一次
-        if self.is_analytic() || v2.is_analytic() {
-            return TruthValue::new_analytic_default();
-        }
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝总频数=四方值综合
-        // * 📝频率=1（无中生有）
-        // * 📝信度=总频数转换（总是弱推理）
-        let w = f1 & f2 & c1 & c2;
-        let c = ShortFloat::w2c(w.to_float());
-        TruthValue::new_fc(ShortFloat::ONE, c)
+        StandardNal.exemplification(self, v2)
     }
 
     /// 模拟`TruthFunctions.comparison`
@@ -301,18 +215,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn comparison(&self, v2: &impl Truth) -> TruthValue {
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝总频数=「双频之析取」与「双信之合取」之合取
-        // * 📝频率=「双频之合取」/「双频之析取」（📌根据函数图像，可以取"(0,0) -> 0"为可去间断点）
-        // * 📝信度=总频数转换（总是弱推理）
-        let f0 = f1 | f2;
-        let f = match f0.is_zero() {
-            true => ShortFloat::ZERO,
-            false => (f1 & f2) / f0,
-        };
-        let w = f0 & c1 & c2;
-        let c = ShortFloat::w2c(w.to_float());
-        TruthValue::new_fc(f, c)
+        StandardNal.comparison(self, v2)
     }
 
     /* ----- desire-value functions, called in SyllogisticRules ----- */
@@ -328,13 +231,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn desire_strong(&self, v2: &impl Truth) -> TruthValue {
-        // ? 此函数似乎是用在「目标」上的
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝频率=双频之合取
-        // * 📝信度=双方信度 合取 第二方频率
-        let f = f1 & f2;
-        let c = c1 & c2 & f2;
-        TruthValue::new_fc(f, c)
+        StandardNal.desire_strong(self, v2)
     }
 
     /// 模拟`TruthFunctions.desireWeak`
@@ -348,12 +245,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn desire_weak(&self, v2: &impl Truth) -> TruthValue {
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝频率=双频之合取
-        // * 📝信度=双方信度 合取 第二方频率 合取 单位数目信度（保证弱推理）
-        let f = f1 & f2;
-        let c = c1 & c2 & f2 & ShortFloat::W2C1();
-        TruthValue::new_fc(f, c)
+        StandardNal.desire_weak(self, v2)
     }
 
     /// 模拟`TruthFunctions.desireDed`
@@ -367,12 +259,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn desire_deduction(&self, v2: &impl Truth) -> TruthValue {
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝频率=双频之合取
-        // * 📝信度=双信之合取
-        let f = f1 & f2;
-        let c = c1 & c2;
-        TruthValue::new_fc(f, c)
+        StandardNal.desire_deduction(self, v2)
     }
 
     /// 模拟`TruthFunctions.desireInd`
@@ -386,13 +273,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn desire_induction(&self, v2: &impl Truth) -> TruthValue {
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝总频数=第二方频率 合取 双信之合取
-        // * 📝频率=第一方频率
-        // * 📝信度=总频数转换（保证弱推理）
-        let w = f2 & c1 & c2;
-        let c = ShortFloat::w2c(w.to_float());
-        TruthValue::new_fc(f1, c)
+        StandardNal.desire_induction(self, v2)
     }
 
     /* ----- double argument functions, called in CompositionalRules ----- */
@@ -410,12 +291,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @return Truth value of the conclusion
     #[doc(alias = "union")]
     fn union_(&self, v2: &impl Truth) -> TruthValue {
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝频率=双频之析取
-        // * 📝信度=双信之合取
-        let f = f1 | f2;
-        let c = c1 & c2;
-        TruthValue::new_fc(f, c)
+        StandardNal.union_(self, v2)
     }
 
     /// 模拟`TruthFunctions.intersection`
@@ -429,12 +305,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn intersection(&self, v2: &impl Truth) -> TruthValue {
-        let ([f1, c1], [f2, c2]) = self.fc_with(v2);
-        // * 📝频率=双频之合取
-        // * 📝信度=双信之合取
-        let f = f1 & f2;
-        let c = c1 & c2;
-        TruthValue::new_fc(f, c)
+        StandardNal.intersection(self, v2)
     }
 
     /// 模拟`TruthFunctions.reduceDisjunction`
@@ -443,9 +314,7 @@ pub trait TruthFunctions: Truth + Sized {
     ///
     /// # 📄OpenNARS
     fn reduce_disjunction(&self, v2: &impl Truth) -> TruthValue {
-        // * 🚩演绎（反向交集，依赖度=1）
-        let v0 = self.intersection(&v2.negation());
-        v0.analytic_deduction(ShortFloat::ONE)
+        StandardNal.reduce_disjunction(self, v2)
     }
 
     /// 模拟`TruthFunctions.reduceConjunction`
@@ -460,9 +329,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn reduce_conjunction(&self, v2: &impl Truth) -> TruthValue {
-        // * 🚩否定演绎（反向交集（内部取反），依赖度=1）
-        let v0 = self.negation().intersection(v2);
-        v0.analytic_deduction(ShortFloat::ONE).negation()
+        StandardNal.reduce_conjunction(self, v2)
     }
 
     /// 模拟`TruthFunctions.reduceConjunctionNeg`
@@ -477,8 +344,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn reduce_conjunction_neg(&self, v2: &impl Truth) -> TruthValue {
-        // * 🚩消取，但对第二方套否定
-        self.reduce_conjunction(&v2.negation())
+        StandardNal.reduce_conjunction_neg(self, v2)
     }
 
     /// 模拟`TruthFunctions.anonymousAnalogy`
@@ -493,12 +359,7 @@ pub trait TruthFunctions: Truth + Sized {
     /// @param v2 Truth value of the second premise
     /// @return Truth value of the conclusion
     fn anonymous_analogy(&self, v2: &impl Truth) -> TruthValue {
-        // * 📝中间频率=第一方频
-        // * 📝中间信度=第一方信度作为「总频数」（弱推理）
-        let [f1, c1] = self.fc();
-        let v0 = TruthValue::new_fc(f1, ShortFloat::w2c(c1.to_float()));
-        // * 🚩再参与「类比」（弱中之弱）
-        v2.analogy(&v0)
+        StandardNal.anonymous_analogy(self, v2)
     }
 }
 
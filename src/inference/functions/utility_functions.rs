@@ -479,6 +479,7 @@ mod tests {
 
     /// 测试/or_multi
     #[test]
+    #[cfg_attr(feature = "high_precision", ignore = "依赖四位精度的舍入")]
     fn or_multi() -> AResult {
         // 海测（健壮性测试） // * 🚩验证与二元运算的逻辑一致
         for_all_sf! {
@@ -536,6 +537,7 @@ mod tests {
 
     /// 测试/geometrical_average
     #[test]
+    #[cfg_attr(feature = "high_precision", ignore = "依赖四位精度的舍入")]
     fn geometrical_average() -> AResult {
         // * 🚩验证与浮点运算的逻辑一致
         for_all_sf! {
@@ -4,10 +4,13 @@
 
 use crate::{
     control::{ContextDerivation, ReasonContext, ReasonContextDirect},
-    entity::{BudgetValue, Concept, Judgement, Punctuation, RCTask, Sentence, ShortFloat, Stamp},
+    entity::{
+        BudgetValue, Concept, Judgement, Punctuation, RCTask, RevisionRecord, Sentence, ShortFloat,
+        Stamp,
+    },
     inference::{
         try_solution_apply_context, try_solution_apply_task, try_solution_calculate, Budget,
-        BudgetFunctions, BudgetInference, Evidential, TruthFunctions,
+        BudgetFunctions, BudgetInference, Evidential,
     },
     language::Term,
     util::ToDisplayAndBrief,
@@ -59,9 +62,12 @@ fn process_judgement(context: &mut ReasonContextDirect) {
         }
         // * 🚩不重复 && 可修正 ⇒ 修正
         else if judgment.revisable_to(old_belief) {
-            let has_overlap = judgment.evidential_overlap(old_belief);
+            // * 🚩🆕是否允许证据重合，由「证据重合策略」决定
+            let admitted = context
+                .evidence_overlap_policy()
+                .admits(&judgment, old_belief);
             // * 🚩现在将「当前信念」「新时间戳」移入「修正」调用中
-            if !has_overlap {
+            if admitted {
                 // * 📌【2024-06-07 11:38:02】现在由于「新时间戳」的内置，经检查不再需要设置「当前信念」
                 // * 📌此处的「当前信念」直接取`oldBelief`，并以此构造时间戳
                 revision_direct(context, judgment.clone(), old_belief.clone());
@@ -72,6 +78,7 @@ fn process_judgement(context: &mut ReasonContextDirect) {
     // * 🚩尝试用新的信念解决旧有问题
     // * 📄如：先输入`A?`再输入`A.`
     let budget_threshold = context.parameters().budget_threshold; // ! 需要单独分开：借用问题
+    let budget_policy = context.budget_policy();
     if context
         .current_task // ! 不能复用上头的task：可能会有借用问题
         .get_()
@@ -82,8 +89,12 @@ fn process_judgement(context: &mut ReasonContextDirect) {
         let mut results = vec![];
         // * 🚩先计算
         for existed_question in this.questions() {
-            let result =
-                try_solution_calculate(&judgment, &existed_question.get_(), budget_threshold);
+            let result = try_solution_calculate(
+                &judgment,
+                &existed_question.get_(),
+                budget_threshold,
+                budget_policy,
+            );
             // 拷贝「问题」的共享引用
             results.push((existed_question.clone_(), result));
         }
@@ -116,6 +127,7 @@ fn process_judgement(context: &mut ReasonContextDirect) {
 /// To answer a question by existing beliefs
 fn process_question(context: &mut ReasonContextDirect) {
     let budget_threshold = context.parameters().budget_threshold;
+    let budget_policy = context.budget_policy();
     // * 📝【2024-05-18 14:32:20】根据上游调用，此处「传入」的`task`只可能是`context.currentTask`
     let mut question_task = context.current_task.clone_(); // * 🚩引用拷贝，否则会涉及大量借用问题
     let question_task_ref = question_task.get_(); // * 🚩引用拷贝，否则会涉及大量借用问题
@@ -144,7 +156,12 @@ fn process_question(context: &mut ReasonContextDirect) {
     if let Some((answer, ..)) = new_answer {
         // ! 📝【2024-07-09 17:59:03】在「计算并应用求解」方面，使用的是「原任务」而不会用「已存在问题任务」
         let answer = answer.clone(); // ! 拷贝判断句以避免借用问题
-        let result = try_solution_calculate(&answer, &question_task.get_(), budget_threshold);
+        let result = try_solution_calculate(
+            &answer,
+            &question_task.get_(),
+            budget_threshold,
+            budget_policy,
+        );
         drop(question_task_ref);
         try_solution_apply_task(&result, &mut question_task.mut_(), &answer);
         try_solution_apply_context(result, &answer, context);
@@ -177,12 +194,20 @@ fn revision_direct(
     new_belief: impl Judgement,
     old_belief: impl Judgement,
 ) {
+    // * 🚩🆕检测新旧信念之间的冲突
+    context.report_conflict(&new_belief, &old_belief);
     // * 🚩词项
     let new_content = new_belief.clone_content();
     // * 🚩真值
-    let new_truth = new_belief.revision(&old_belief);
+    let new_truth = context.truth_calculus().revision(&new_belief, &old_belief);
+    // * 🚩🆕按「证据重合策略」折扣信度
+    let new_truth = context.overlap_discounted(new_truth, &new_belief, &old_belief);
+    // * 🚩🆕记录修正历史
+    let record = RevisionRecord::from_revision(context.time(), &new_belief, &old_belief, new_truth);
+    context.record_revision(&new_content, record);
     // * 🚩预算值
     let new_budget = BudgetValue::revise_direct(
+        context.budget_policy(),
         &new_belief,
         &old_belief,
         &new_truth,
@@ -197,6 +222,7 @@ fn revision_direct(
         &old_belief,
         context.time(),
         context.max_evidence_base_length(),
+        context.evidence_interleave_order(),
     );
     context.double_premise_task_revision(new_content, new_truth, new_budget, new_stamp);
 }
@@ -296,6 +322,234 @@ mod tests {
         );
     }
 
+    /// 推理器上设置的排行策略传到新建的概念
+    #[test]
+    fn rank_strategy_per_reasoner() {
+        use crate::parameters::{Parameters, RankStrategy, DEFAULT_PARAMETERS};
+        let mut vm = create_reasoner(
+            Parameters {
+                belief_rank_strategy: RankStrategy::Recency,
+                question_rank_strategy: RankStrategy::Confidence,
+                ..DEFAULT_PARAMETERS
+            },
+            ENGINE,
+        );
+        vm.input_cmds(
+            "
+            nse Sentence.
+            cyc 2
+            ",
+        );
+        // * 🚩概念的排行策略会随记忆区一同存档
+        let memory = serde_json::to_value(vm.memory()).unwrap();
+        let memory = memory.to_string();
+        assert!(memory.contains(r#""belief_rank_strategy":"Recency""#), "{memory}");
+        assert!(memory.contains(r#""question_rank_strategy":"Confidence""#), "{memory}");
+    }
+
+    /// 同一套规则，不同的预算策略
+    /// * 🚩概念激活由推理器所选的策略计算
+    #[test]
+    fn budget_policy_per_reasoner() {
+        use crate::{
+            entity::{BudgetValue, Concept},
+            inference::{BudgetPolicy, BudgetPolicyRef, StandardBudget},
+            language::Term,
+        };
+        /// 总是完全激活概念的策略
+        #[derive(Debug)]
+        struct FullActivation;
+        impl BudgetPolicy for FullActivation {
+            fn name(&self) -> &str {
+                "full_activation"
+            }
+            fn activate_to_concept(&self, _: &Concept, _: &BudgetValue) -> BudgetValue {
+                BudgetValue::from_floats(1.0, 1.0, 1.0)
+            }
+        }
+        let concept_priority = |policy: BudgetPolicyRef| {
+            let mut vm = reasoner();
+            vm.set_budget_policy(policy);
+            vm.input_cmds("nse <A --> B>.\ncyc 1");
+            let concept = vm.memory().term_to_concept(&"<A --> B>".parse::<Term>().unwrap());
+            concept.expect("应有概念").priority()
+        };
+        let standard = concept_priority(StandardBudget::REF);
+        let full = concept_priority(&FullActivation);
+        assert!(full > standard, "{full} <= {standard}");
+    }
+
+    /// 🆕修正时检测冲突
+    #[test]
+    fn report_conflict_on_revision() {
+        use crate::parameters::{Parameters, DEFAULT_PARAMETERS};
+        let n_conflicts = |parameters: Parameters| {
+            let mut vm = create_reasoner(parameters, ENGINE);
+            let outputs = vm.input_cmds_and_fetch_out(
+                "
+                nse Sentence. %1.0;0.9%
+                cyc 2
+                nse Sentence. %0.0;0.8%
+                cyc 2
+                ",
+            );
+            outputs
+                .iter()
+                .filter(|output| match output {
+                    Output::INFO { message } => message.contains("Conflict"),
+                    _ => false,
+                })
+                .count()
+        };
+        // * 🚩默认不检测
+        assert_eq!(n_conflicts(DEFAULT_PARAMETERS), 0);
+        // * 🚩频率差超过阈值⇒报告
+        let parameters = Parameters {
+            conflict_frequency_threshold: Some(0.5),
+            ..DEFAULT_PARAMETERS
+        };
+        assert_eq!(n_conflicts(parameters), 1);
+        // * 🚩频率差未超过阈值⇒不报告
+        let parameters = Parameters {
+            conflict_frequency_threshold: Some(1.0),
+            ..DEFAULT_PARAMETERS
+        };
+        assert_eq!(n_conflicts(parameters), 0);
+        // * 🚩信度过低⇒不报告
+        let parameters = Parameters {
+            conflict_frequency_threshold: Some(0.5),
+            conflict_minimum_confidence: 0.85,
+            ..DEFAULT_PARAMETERS
+        };
+        assert_eq!(n_conflicts(parameters), 0);
+    }
+
+    /// 🆕修正时记录修正历史
+    #[test]
+    fn record_revision_history() {
+        use crate::{
+            entity::TruthValue,
+            inference::Truth,
+            language::Term,
+            parameters::{Parameters, DEFAULT_PARAMETERS},
+        };
+        let revisions = |parameters: Parameters| {
+            let mut vm = create_reasoner(parameters, ENGINE);
+            vm.input_cmds(
+                "
+                nse Sentence. %1.0;0.9%
+                cyc 2
+                nse Sentence. %0.0;0.9%
+                cyc 2
+                nse Sentence. %0.0;0.9%
+                cyc 2
+                ",
+            );
+            let term = "Sentence".parse::<Term>().unwrap();
+            vm.revision_history(&term).cloned().collect::<Vec<_>>()
+        };
+        // * 🚩默认不记录
+        assert!(revisions(DEFAULT_PARAMETERS).is_empty());
+        // * 🚩记录：旧真值取自旧信念，时间戳取自新信念
+        let history = revisions(Parameters {
+            revision_history_length: 10,
+            ..DEFAULT_PARAMETERS
+        });
+        assert!(!history.is_empty(), "{history:?}");
+        let first = &history[0];
+        assert_eq!(first.old_truth, TruthValue::from_fc(1.0, 0.9));
+        assert!(first.new_truth.confidence() > first.old_truth.confidence());
+        // * 🚩有界：只保留最近的记录
+        let history = revisions(Parameters {
+            revision_history_length: 1,
+            ..DEFAULT_PARAMETERS
+        });
+        assert_eq!(history.len(), 1);
+    }
+
+    /// 🆕证据重合策略：部分重合的信念亦可修正，但信度被折扣
+    #[test]
+    fn revision_overlap_policy() {
+        use crate::{
+            inference::{OverlapPolicy, Truth},
+            language::Term,
+            parameters::{Parameters, DEFAULT_PARAMETERS},
+        };
+        let revisions = |evidence_overlap_policy| {
+            let mut vm = create_reasoner(
+                Parameters {
+                    revision_history_length: 100,
+                    evidence_overlap_policy,
+                    ..DEFAULT_PARAMETERS
+                },
+                ENGINE,
+            );
+            vm.input_cmds(
+                "
+                nse Sentence. %1.0;0.9%
+                cyc 2
+                nse Sentence. %0.0;0.9%
+                cyc 20
+                ",
+            );
+            let term = "Sentence".parse::<Term>().unwrap();
+            vm.revision_history(&term).cloned().collect::<Vec<_>>()
+        };
+        // * 🚩修正后信度低于前提⇒被折扣
+        let discounted = |history: &[RevisionRecord]| {
+            history
+                .iter()
+                .any(|r| r.new_truth.confidence() < r.old_truth.confidence())
+        };
+        // * 🚩严格：只有证据不重合的修正，信度不降
+        let strict = revisions(OverlapPolicy::Strict);
+        assert!(!strict.is_empty());
+        assert!(!discounted(&strict), "{strict:?}");
+        // * 🚩折扣：部分重合的修正亦被允许，但信度被折扣
+        let discount = revisions(OverlapPolicy::Discount);
+        assert!(discount.len() > strict.len(), "{strict:?}\n{discount:?}");
+        assert!(discounted(&discount), "{discount:?}");
+    }
+
+    /// 🆕「定义」不被修正
+    #[test]
+    fn definition_not_revised() {
+        use crate::{
+            entity::{Judgement, ShortFloat, TruthValue},
+            inference::Truth,
+            language::Term,
+            parameters::{Parameters, DEFAULT_PARAMETERS},
+        };
+        let mut vm = create_reasoner(
+            Parameters {
+                revision_history_length: 10,
+                ..DEFAULT_PARAMETERS
+            },
+            ENGINE,
+        );
+        vm.input_cmds(
+            "
+            def <square --> rectangle>. %1.0;0.9%
+            cyc 2
+            nse <square --> rectangle>. %0.0;0.9%
+            cyc 2
+            nse <square --> rectangle>. %0.0;0.9%
+            cyc 2
+            ",
+        );
+        let term = "<square --> rectangle>".parse::<Term>().unwrap();
+        // * 🚩定义与经验信念都不修正对方：修正历史中只有经验信念
+        assert!(vm
+            .revision_history(&term)
+            .all(|record| record.old_truth.frequency() == ShortFloat::ZERO));
+        // * 🚩定义保持原样，排在信念表首位
+        let concept = vm.memory().term_to_concept(&term).unwrap();
+        let first = concept.beliefs().next().unwrap();
+        assert!(first.is_definition());
+        assert_eq!(TruthValue::from(first), TruthValue::from_fc(1.0, 0.9));
+        assert!(concept.has_definition());
+    }
+
     /// 多次回答相同问题
     #[test]
     fn answer_question_multiple_time() {
@@ -45,10 +45,11 @@ pub fn compose_as_set(
     // ! 📌分派上级「构造复合词项」已断言此处为「前向推理」
     debug_assert_eq!(context.reason_direction(), Forward);
 
-    let truth_t = TruthValue::from(context.current_task().get_().unwrap_judgement());
-    let truth_b = context.current_belief().unwrap();
-    let truth_or = Some(truth_t.union_(truth_b)); // 后续统一类型
-    let truth_and = Some(truth_t.intersection(truth_b)); // 后续统一类型
+    let truth_t = context.premise_truth(context.current_task().get_().unwrap_judgement());
+    let truth_b = context.premise_truth(context.current_belief().unwrap());
+    let calculus = context.truth_calculus();
+    let truth_or = Some(calculus.union_(&truth_t, &truth_b)); // 后续统一类型
+    let truth_and = Some(calculus.intersection(&truth_t, &truth_b)); // 后续统一类型
     let truth_dif;
     let [term_or, term_and, term_dif];
 
@@ -84,13 +85,13 @@ pub fn compose_as_set(
                 // * 📝正负流向：任务→信念
                 [true, false] => (
                     make_term_dif(component_t(), component_b()),
-                    Some(truth_t.intersection(&truth_b.negation())),
+                    Some(calculus.intersection(&truth_t, &calculus.negation(&truth_b))),
                 ),
                 // * 🚩任务负，信念正 ⇒ 词项="(信念-任务)"，真值=信念 ∩ ¬任务
                 // * 📝正负流向：信念→任务
                 [false, true] => (
                     make_term_dif(component_b(), component_t()),
-                    Some(truth_b.intersection(&truth_t.negation())),
+                    Some(calculus.intersection(&truth_b, &calculus.negation(&truth_t))),
                 ),
             }
         }
@@ -183,32 +184,37 @@ pub fn decompose_as_set(
     let content = unwrap_or_return!(?Term::make_statement(&task_content, subject, predicate));
 
     // * 🚩真值 * //
-    let belief_truth: TruthValue = context.current_belief().unwrap().into();
-    let task_truth: TruthValue = context.current_task().get_().unwrap_judgement().into();
+    let belief_truth = context.premise_truth(context.current_belief().unwrap());
+    let task_truth = context.premise_truth(context.current_task().get_().unwrap_judgement());
     let [v1, v2] = compound_from.select([task_truth, belief_truth]);
 
     /// 反向的「合取消去」
     /// * 🎯格式整齐——让后边直接使用真值函数（指针）而无需凑表达式
-    fn reduce_disjunction_rev(v1: &impl Truth, v2: &impl Truth) -> TruthValue {
-        v2.reduce_disjunction(v1)
+    fn reduce_disjunction_rev(
+        calculus: TruthCalculusRef,
+        v1: &dyn Truth,
+        v2: &dyn Truth,
+    ) -> TruthValue {
+        calculus.reduce_disjunction(v2, v1)
     }
 
     // * 🚩预先获取各个上下文「主项/谓项」的「与或非」真值函数
-    let [truth_f_and, truth_f_or]: [TruthFDouble; 2] = side.select([
-        TruthFunctions::reduce_conjunction,
-        TruthFunctions::reduce_disjunction,
+    let [truth_f_and, truth_f_or]: [TruthCalculusFDouble; 2] = side.select([
+        |c, v1, v2| c.reduce_conjunction(v1, v2),
+        |c, v1, v2| c.reduce_disjunction(v1, v2),
     ]);
-    let truth_f_not = match *compound.component_at(0).unwrap() == *component {
+    let truth_f_not: TruthCalculusFDouble = match *compound.component_at(0).unwrap() == *component
+    {
         // * 🚩内容正好为被减项 ⇒ 析取（反向）
         true => reduce_disjunction_rev,
         // * 🚩其它 ⇒ 合取否定
-        false => TruthFunctions::reduce_conjunction_neg,
+        false => |c, v1, v2| c.reduce_conjunction_neg(v1, v2),
     };
 
     // * 🚩根据各词项类型分派
     let task_content_type = task_content.identifier();
     let compound_type = compound.identifier();
-    let truth_f: TruthFDouble = match task_content_type {
+    let truth_f: TruthCalculusFDouble = match task_content_type {
         // * 🚩任务内容 <: 继承
         INHERITANCE_RELATION => match compound_type {
             // * 🚩外延交 ⇒ 合取/析取
@@ -238,7 +244,7 @@ pub fn decompose_as_set(
         // * 🚩其它 ⇒ 否决
         _ => return,
     };
-    let truth = truth_f(&v1, &v2);
+    let truth = truth_f(context.truth_calculus(), &v1, &v2);
 
     // * 🚩预算 * //
     let budget = context.budget_compound_forward(&truth, &content);
@@ -263,8 +269,8 @@ pub fn decompose_statement(
         .current_task()
         .get_()
         .as_judgement()
-        .map(TruthValue::from);
-    let belief_truth = TruthValue::from(context.current_belief().unwrap());
+        .map(|judgement| context.premise_truth(judgement));
+    let belief_truth = context.premise_truth(context.current_belief().unwrap());
     // * 🚩删去指定的那个元素，用删去之后的剩余元素做结论
     let content = unwrap_or_return!(?compound.reduce_components(component));
     let direction = context.reason_direction();
@@ -276,13 +282,13 @@ pub fn decompose_statement(
             // * 🚩选取前提真值 | ⚠️前后件语义不同
             let [v1, v2] = compound_from.select([&task_truth, &belief_truth]);
             // * 🚩选取真值函数
-            let truth_f: TruthFDouble = match compound.identifier() {
-                CONJUNCTION_OPERATOR => TruthFunctions::reduce_conjunction,
-                DISJUNCTION_OPERATOR => TruthFunctions::reduce_disjunction,
+            let truth_f: TruthCalculusFDouble = match compound.identifier() {
+                CONJUNCTION_OPERATOR => |c, v1, v2| c.reduce_conjunction(v1, v2),
+                DISJUNCTION_OPERATOR => |c, v1, v2| c.reduce_disjunction(v1, v2),
                 _ => return,
             };
             // * 🚩构造真值、预算值，双前提结论
-            let truth = truth_f(v1, v2);
+            let truth = truth_f(context.truth_calculus(), v1, v2);
             let budget = context.budget_compound_forward(&truth, &content);
             context.double_premise_task(content, Some(truth), budget)
         }
@@ -307,7 +313,7 @@ pub fn decompose_statement(
             let content_concept = unwrap_or_return!(?context.term_to_concept(&content));
             // * 🚩只在「内容对应了概念」时，取出「概念」中的信念
             let content_belief = unwrap_or_return!(
-                ?content_concept.get_belief(task)
+                ?content_concept.get_belief(task, context.evidence_overlap_policy())
             );
             // * 🚩只在「概念中有信念」时，以这个信念作为「当前信念」构建新任务
             let new_stamp = Stamp::from_merge_unchecked(
@@ -315,6 +321,7 @@ pub fn decompose_statement(
                 content_belief, // * 🚩实际上就是需要与「已有信念」的证据基合并
                 context.time(),
                 context.max_evidence_base_length(),
+                context.evidence_interleave_order(),
             );
             let task_budget = BudgetValue::from(task);
             drop(task_ref);
@@ -323,7 +330,9 @@ pub fn decompose_statement(
                 ?Term::make_conjunction(component.clone(), content)
             );
             // * ↓不会用到`context.getCurrentTask()`、`newStamp`
-            let truth = content_belief.intersection(&belief_truth);
+            let truth = context
+                .truth_calculus()
+                .intersection(&context.premise_truth(content_belief), &belief_truth);
             // * 🚩【2024-06-07 13:41:16】现在直接从「任务」构造新的「预算值」
             let sentence = content_belief.clone(); // 提取出变量以规避借用问题
             let content_task = Task::from_input(
@@ -480,7 +489,10 @@ pub fn intro_var_same_subject_or_predicate(
     };
 
     // * 🚩真值 * //
-    let truth = original_main_sentence.induction(sub_sentence);
+    let truth = context.truth_calculus().induction(
+        &context.premise_truth(original_main_sentence),
+        &context.premise_truth(sub_sentence),
+    );
 
     // * 🚩预算 * //
     let budget = context.budget_compound_forward(&truth, &content);
@@ -517,8 +529,8 @@ pub fn intro_var_outer(
 ) {
     // * 🚩任务/信念 的真值 | 仅适用于前向推理
     debug_assert!(context.current_task().get_().is_judgement());
-    let truth_t = TruthValue::from(context.current_task().get_().unwrap_judgement());
-    let truth_b = TruthValue::from(context.current_belief().unwrap());
+    let truth_t = context.premise_truth(context.current_task().get_().unwrap_judgement());
+    let truth_b = context.premise_truth(context.current_belief().unwrap());
 
     // * 🚩词项初步：引入变量 * //
     let [state_i1, state_i2] = intro_var_states_ind(task_content, belief_content, shared_term_i);
@@ -539,16 +551,16 @@ pub fn intro_var_outer(
     type IntroVarOuterParameters = (
         UsesVar,                                    // 用独立变量还是用非独变量
         fn(Term, Term) -> Option<Term>,             // 制作词项
-        fn(&TruthValue, &TruthValue) -> TruthValue, // 制作真值
+        TruthCalculusFDouble,                       // 制作真值
         bool,                                       // 词项、真值的顺序是否要交换
     );
     const T: bool = true; // 💭【2024-08-07 23:57:50】为了简写无所不用其极。。
     const F: bool = false; // 💭【2024-08-07 23:57:50】为了简写无所不用其极。。
     let will_intro_parameters: [IntroVarOuterParameters; 4] = [
-        (I, Term::make_implication, TruthFunctions::induction, F), // "<<$1 --> A> ==> <$1 --> B>>"
-        (I, Term::make_implication, TruthFunctions::induction, T), // "<<$1 --> B> ==> <$1 --> A>>"
-        (I, Term::make_equivalence, TruthFunctions::comparison, F), // "<<$1 --> A> <=> <$1 --> B>>"
-        (D, Term::make_conjunction, TruthFunctions::intersection, F), // "(&&,<#1 --> A>,<#1 --> B>)"
+        (I, Term::make_implication, |c, v1, v2| c.induction(v1, v2), F), // "<<$1 --> A> ==> <$1 --> B>>"
+        (I, Term::make_implication, |c, v1, v2| c.induction(v1, v2), T), // "<<$1 --> B> ==> <$1 --> A>>"
+        (I, Term::make_equivalence, |c, v1, v2| c.comparison(v1, v2), F), // "<<$1 --> A> <=> <$1 --> B>>"
+        (D, Term::make_conjunction, |c, v1, v2| c.intersection(v1, v2), F), // "(&&,<#1 --> A>,<#1 --> B>)"
     ];
     for (uses_var, make_content, truth_f, reverse_order) in will_intro_parameters {
         // * 🚩决定要填进去的词项
@@ -648,7 +660,7 @@ fn intro_var_outer_derive(
     [state_1, state_2]: [Option<Term>; 2],
     [truth_t, truth_b]: [&TruthValue; 2],
     make_content: fn(Term, Term) -> Option<Term>,
-    truth_f: fn(&TruthValue, &TruthValue) -> TruthValue,
+    truth_f: TruthCalculusFDouble,
     reverse_order: bool,
     context: &mut ReasonContextConcept,
     // 预算函数默认是「复合前向」
@@ -661,7 +673,7 @@ fn intro_var_outer_derive(
     let content = unwrap_or_return!(?make_content(state_1, state_2));
     // * 🚩真值
     let [truth_1, truth_2] = reverse_order.select([truth_t, truth_b]);
-    let truth = truth_f(truth_1, truth_2);
+    let truth = truth_f(context.truth_calculus(), truth_1, truth_2);
     // * 🚩预算：统一为「复合前向」
     let budget = context.budget_compound_forward(&truth, &content);
     // * 🚩结论
@@ -686,8 +698,8 @@ pub fn intro_var_inner(
 ) {
     // * 🚩任务/信念 的真值 | 仅适用于前向推理
     debug_assert!(context.current_task().get_().is_judgement());
-    let truth_t = TruthValue::from(context.current_task().get_().unwrap_judgement());
-    let truth_b = TruthValue::from(context.current_belief().unwrap());
+    let truth_t = context.premise_truth(context.current_task().get_().unwrap_judgement());
+    let truth_b = context.premise_truth(context.current_belief().unwrap());
 
     // * 🚩前提1与前提2必须是相同类型，且「旧复合词项」不能包括前提1
     if !premise_1.is_same_type(&premise_2) || old_compound.contain_component(&premise_1) {
@@ -764,7 +776,7 @@ fn intro_var_inner1(
     }
 
     // * 🚩真值 * //
-    let truth = truth_t.intersection(truth_b);
+    let truth = context.truth_calculus().intersection(truth_t, truth_b);
 
     // * 🚩预算 * //
     let budget = context.budget_forward(&truth);
@@ -817,7 +829,7 @@ fn intro_var_inner2(
     //   * 📄前提1 != 任务 ⇒ 归纳 任务→信念
     let premise1_eq_task = *premise_1 == *context.current_task().get_().content();
     let [truth_1, truth_2] = premise1_eq_task.select([truth_t, truth_b]);
-    let truth = truth_1.induction(truth_2);
+    let truth = context.truth_calculus().induction(truth_1, truth_2);
 
     // * 🚩预算 * //
     let budget = context.budget_forward(&truth);
@@ -877,8 +889,8 @@ pub fn eliminate_var_dep(
         .current_task()
         .get_()
         .as_judgement()
-        .map(TruthValue::from);
-    let truth_b = TruthValue::from(context.current_belief().unwrap());
+        .map(|judgement| context.premise_truth(judgement));
+    let truth_b = context.premise_truth(context.current_belief().unwrap());
     let direction = context.reason_direction();
 
     // * 🚩词项 * //
@@ -901,7 +913,9 @@ pub fn eliminate_var_dep(
             //   * 📄任务 ⇒ `[任务, 信念]`
             //   * 📄否则 ⇒ `[信念, 任务]`
             let [truth_1, truth_2] = compound_from.select([truth_t.unwrap(), truth_b]);
-            truth_1.anonymous_analogy(&truth_2)
+            context
+                .truth_calculus()
+                .anonymous_analogy(&truth_1, &truth_2)
         }),
         Backward => None,
     };
@@ -925,7 +939,40 @@ pub fn eliminate_var_dep(
 
 #[cfg(test)]
 mod tests {
-    use crate::expectation_tests;
+    use crate::{
+        entity::ShortFloat,
+        expectation_tests,
+        inference::{
+            tests::tools::{create_reasoner_from_engine, ENGINE_DEV},
+            Truth,
+        },
+        language::Term,
+        util::AResult,
+    };
+
+    /// 🆕以经验信念或「定义」（指令头`head`）作为第二前提，得到复合结论的信度
+    fn composed_confidence(head: &str) -> AResult<ShortFloat> {
+        let conclusion = "<(|, square, tweety) --> rectangle>".parse::<Term>()?;
+        let mut reasoner = create_reasoner_from_engine(ENGINE_DEV);
+        reasoner.input_cmds(&format!(
+            "nse <tweety --> rectangle>.\n{head} <square --> rectangle>. %1.0;0.8%\ncyc 100"
+        ));
+        let concept = reasoner
+            .memory()
+            .term_to_concept(&conclusion)
+            .expect("应有结论的概念");
+        let belief = concept.beliefs().next().expect("应有结论");
+        Ok(belief.confidence())
+    }
+
+    /// 🆕「定义」作为复合规则的前提时，以「依赖度」代替其信度
+    #[test]
+    fn composition_with_definition() -> AResult {
+        let empirical = composed_confidence("nse")?;
+        let definitional = composed_confidence("def")?;
+        assert!(definitional > empirical, "{definitional} <= {empirical}");
+        Ok(())
+    }
 
     expectation_tests! {
         compose_as_sub_inh_and: {
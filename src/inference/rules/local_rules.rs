@@ -10,8 +10,9 @@ use crate::{
     control::{util_outputs, ContextDerivation, ReasonContext},
     entity::{BudgetValue, Judgement, JudgementV1, Sentence, ShortFloat, Task},
     global::Float,
-    inference::{Budget, BudgetFunctions},
+    inference::{Budget, BudgetFunctions, BudgetPolicyRef, Evidential, Truth},
 };
+use nar_dev_utils::RefCount;
 use navm::output::Output;
 
 /// [`try_solution`]的复合返回值
@@ -36,16 +37,17 @@ pub(in crate::inference) enum SolutionResult {
 
 /// 尝试对「问题任务」求解
 /// * 🚩【2024-06-30 11:31:00】此处不再引入「推理上下文」，以便在「问题任务」中解耦
+/// * 🚩新信念的预算值由传入的「预算策略」计算
 #[must_use]
 pub(in crate::inference) fn try_solution_calculate(
     belief: &impl Judgement,
     question_task: &Task,
     budget_threshold: Float, // 在「激活任务」时使用
+    budget_policy: BudgetPolicyRef,
 ) -> SolutionResult {
     use SolutionResult::*;
     // * 🚩预设&断言
     debug_assert!(question_task.is_question(), "要解决的必须是「问题」");
-    let problem = question_task.as_question().unwrap();
 
     // * 🚩验证这个信念是否为「解决问题的最优解」
     let new_q = BudgetValue::solution_quality(question_task, belief);
@@ -67,7 +69,7 @@ pub(in crate::inference) fn try_solution_calculate(
     };
 
     // * 🚩计算新预算值
-    let budget = BudgetValue::solution_eval(problem, belief, question_task);
+    let budget = budget_policy.solution_eval(new_q, belief, &BudgetValue::from(question_task));
     // * 🚩计算「候选信念」
     // * 📝在「解决问题」时，需要使用「当前问题的上游信念」作推断
     let parent_belief = question_task.parent_belief();
@@ -136,6 +138,9 @@ pub(in crate::inference) fn try_solution_apply_context(
             params_to_activate_task,
             ..
         } => {
+            // * 🚩🆕问题的最优解已变⇒重新排行「当前概念」的问题集
+            // * 📌被解答的问题只可能在「当前概念」中（或尚未加入）
+            context.current_concept_mut().re_rank_questions();
             // * 🚩尝试「激活任务」
             if let Some((budget, candidate_belief)) = params_to_activate_task {
                 context.activated_task(budget, solution, &candidate_belief);
@@ -143,7 +148,52 @@ pub(in crate::inference) fn try_solution_apply_context(
             // * 🚩报告输出
             if let Some(output) = new_output {
                 context.report(output);
+                report_answer_derivation(context, solution);
             }
         }
     }
 }
+
+/// 🆕报告「引出回答的导出问题」
+/// * 🎯追溯反向推理：哪些导出的问题最终引出了对输入问题的回答
+/// * 🚩沿以下任务及其所有父任务，收集其中非输入的问题
+///   * 📌「当前任务」：新的判断句解答已有问题时，即为解答本身
+///   * 📌「解答所源自的任务」：问题被已有信念解答时，信念本身不带父任务
+///     * 🚩在「当前概念」的任务中查找内容、真值、证据基均与信念相同的判断句
+///     * ⚠️任务已被遗忘⇒无从追溯
+///   * 📝导出问题被解答后，所激活的信念以之为父任务，继而参与正向推理
+/// * 📌没有这样的问题（直接回答）⇒不报告
+fn report_answer_derivation(context: &mut impl ReasonContext, solution: &JudgementV1) {
+    let current_task = context.current_task().clone();
+    let solution_task = context
+        .current_concept()
+        .iter_tasks()
+        .find(|task| {
+            let task = task.get_();
+            task.as_judgement().is_some_and(|judgement| {
+                judgement.content() == solution.content()
+                    && judgement.fc() == solution.fc()
+                    && judgement.evidential_eq(solution)
+            })
+        })
+        .cloned();
+    let mut derived_questions = vec![];
+    for task in std::iter::once(current_task).chain(solution_task) {
+        let parents = task.get_().parents().map(|(task, _)| task).collect::<Vec<_>>();
+        for task in std::iter::once(task).chain(parents) {
+            let task = task.get_();
+            if task.is_question() && !task.is_input() {
+                let question = task.sentence_to_display();
+                if !derived_questions.contains(&question) {
+                    derived_questions.push(question);
+                }
+            }
+        }
+    }
+    if !derived_questions.is_empty() {
+        context.report_comment(format!(
+            "!!! Answered via derived questions: {}",
+            derived_questions.join(" <= ")
+        ));
+    }
+}
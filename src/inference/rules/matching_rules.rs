@@ -5,10 +5,10 @@ use crate::{
     control::{
         ContextDerivationConcept, ReasonContext, ReasonContextConcept, ReasonContextWithLinks,
     },
-    entity::{Judgement, PunctuatedSentenceRef, Sentence, Stamp, TruthValue},
+    entity::{Judgement, PunctuatedSentenceRef, RevisionRecord, Sentence, Stamp, TruthValue},
     inference::{
         try_solution_apply_context, try_solution_apply_task, try_solution_calculate,
-        BudgetInferenceContext, TruthFunctions,
+        BudgetInferenceContext,
     },
     language::{variable_process, Term},
 };
@@ -39,6 +39,9 @@ pub fn match_task_and_belief(context: &mut ReasonContextConcept) {
             if judgement.revisable_to(current_belief) {
                 // 先复用不可变元素，推理导出结果
                 let (content, truth, stamp) = revision(judgement, current_belief, context);
+                // * 🚩🆕修正历史：先构造记录，待借用结束后再记入
+                let record =
+                    RevisionRecord::from_revision(context.time(), judgement, current_belief, truth);
                 // 计算预算值：需要修改上下文
                 let [current_task_truth, current_belief_truth] = [
                     TruthValue::from(judgement),
@@ -46,6 +49,7 @@ pub fn match_task_and_belief(context: &mut ReasonContextConcept) {
                 ]; // ! 防止借用问题
                 drop(current_task);
                 drop(current_task_rc);
+                context.record_revision(&content, record);
                 let budget =
                     context.revise_matching(&current_task_truth, &current_belief_truth, &truth);
                 // * 🚩创建并导入结果：双前提 | 📝仅在此处用到「当前信念」作为「导出信念」
@@ -72,6 +76,7 @@ pub fn match_task_and_belief(context: &mut ReasonContextConcept) {
                     current_belief,
                     &current_task,
                     context.parameters().budget_threshold,
+                    context.budget_policy(),
                 );
                 // 应用 @ 任务
                 drop(current_task);
@@ -101,13 +106,16 @@ fn revision(
     // * 🚩内容
     let content = new_belief.content().clone();
     // * 🚩计算真值
-    let revised_truth = new_belief.revision(old_belief);
+    let revised_truth = context.truth_calculus().revision(new_belief, old_belief);
+    // * 🚩🆕按「证据重合策略」折扣信度
+    let revised_truth = context.overlap_discounted(revised_truth, new_belief, old_belief);
     // * 🚩【2024-06-06 08:52:56】现场构建「新时间戳」
     let new_stamp = Stamp::from_merge_unchecked(
         new_belief,
         old_belief,
         context.time(),
         context.max_evidence_base_length(),
+        context.evidence_interleave_order(),
     );
     // * 🚩返回
     (content, revised_truth, new_stamp)
@@ -113,16 +113,17 @@ pub fn structural_compose_both(
         .current_task()
         .get_()
         .as_judgement()
-        .map(TruthValue::from);
+        .map(|judgement| context.premise_truth(judgement));
 
     // * 🚩真值 * //
+    let calculus = context.truth_calculus();
     let truth = match direction {
         // * 🚩前向推理
         Forward => match compound.size() {
             // * 🚩任务项多于一个元素⇒分析性演绎
-            2.. => task_truth.map(|task| task.analytic_deduction(context.reasoning_reliance())),
+            2.. => task_truth.map(|task| calculus.analytic_deduction(&task, context.reasoning_reliance())),
             // * 🚩其它⇒恒等@当前任务
-            _ => task_truth.map(|task| task.identity()),
+            _ => task_truth.map(|task| calculus.identity(&task)),
         },
         // * 🚩反向推理⇒空
         Backward => None,
@@ -184,7 +185,7 @@ pub fn structural_decompose_both(
         .current_task()
         .get_()
         .as_judgement()
-        .map(TruthValue::from);
+        .map(|judgement| context.premise_truth(judgement));
     if !(direction == Forward) // ? 💭【2024-08-05 23:37:40】这个「前向推理又是判断」似乎不可能发生
         && !sub.instanceof_product()
         && sub.size() > 1
@@ -194,9 +195,10 @@ pub fn structural_decompose_both(
     }
 
     // * 🚩真值 * //
+    let calculus = context.truth_calculus();
     let truth = match direction {
         // * 🚩前向推理⇒直接用任务的真值
-        Forward => task_truth.map(|truth| truth.identity()),
+        Forward => task_truth.map(|truth| calculus.identity(&truth)),
         // * 🚩反向推理⇒空
         Backward => None,
     };
@@ -232,8 +234,9 @@ pub fn structural_compose_one(
     }
 
     // * 🚩预先计算真值
-    let task_truth = TruthValue::from(context.current_task().get_().unwrap_judgement());
-    let truth_deduction = task_truth.analytic_deduction(context.reasoning_reliance());
+    let task_truth = context.premise_truth(context.current_task().get_().unwrap_judgement());
+    let calculus = context.truth_calculus();
+    let truth_deduction = calculus.analytic_deduction(&task_truth, context.reasoning_reliance());
 
     // * 🚩部分计算词项，并向下分派
     //   * 📄"P@(P&Q)" => "P"
@@ -302,7 +305,7 @@ pub fn structural_compose_one(
     };
     // * 🚩统一构造陈述
     let truth = match to_not_ded {
-        true => truth_deduction.negation(), // 要取否定取否定
+        true => calculus.negation(&truth_deduction), // 要取否定取否定
         false => truth_deduction,           // 否则就是原样
     };
     structural_statement(sub_pre, truth, context);
@@ -327,8 +330,9 @@ pub fn structural_decompose_one(
     }
 
     // * 🚩预先计算真值
-    let task_truth = TruthValue::from(context.current_task().get_().unwrap_judgement());
-    let truth_deduction = task_truth.analytic_deduction(context.reasoning_reliance());
+    let task_truth = context.premise_truth(context.current_task().get_().unwrap_judgement());
+    let calculus = context.truth_calculus();
+    let truth_deduction = calculus.analytic_deduction(&task_truth, context.reasoning_reliance());
 
     // * 🚩部分计算词项，并向下分派
     //   * 📄"S@(S&T)" => "S"
@@ -410,7 +414,7 @@ pub fn structural_decompose_one(
     };
     // * 🚩统一构造陈述
     let truth = match to_not_ded {
-        true => truth_deduction.negation(), // 要取否定取否定
+        true => calculus.negation(&truth_deduction), // 要取否定取否定
         false => truth_deduction,           // 否则就是原样
     };
     structural_statement(sub_pre, truth, context);
@@ -502,13 +506,14 @@ pub fn transform_set_relation(
         .current_task()
         .get_()
         .as_judgement()
-        .map(TruthValue::from);
+        .map(|judgement| context.premise_truth(judgement));
     let direction = context.reason_direction();
 
     // * 🚩真值 * //
+    let calculus = context.truth_calculus();
     let truth = match direction {
         // * 🚩前向 ⇒ 恒等
-        Forward => task_truth.map(|truth| truth.identity()),
+        Forward => task_truth.map(|truth| calculus.identity(&truth)),
         // * 🚩反向 ⇒ 空
         Backward => None,
     };
@@ -551,7 +556,7 @@ pub fn structural_junction(
         .current_task()
         .get_()
         .as_judgement()
-        .map(TruthValue::from);
+        .map(|judgement| context.premise_truth(judgement));
     let direction = context.reason_direction();
 
     // * 🚩词项 * //
@@ -563,18 +568,19 @@ pub fn structural_junction(
     };
 
     // * 🚩真值 * //
+    let calculus = context.truth_calculus();
     let truth = match direction {
         // * 🚩前向推理⇒根据「复合词项从任务中来 == 复合词项是合取」决策
         // * 📝from OpenNARS 3.0.4：前向推理同时对「判断」「目标」成立，因此「任务是判断」的条件可省去
         Forward => task_truth.map(|truth| {
             match (compound_from == PremiseSource::Task) == compound.instanceof_conjunction() {
                 // * 🚩满足⇒分析性演绎
-                true => truth.analytic_deduction(context.reasoning_reliance()),
+                true => calculus.analytic_deduction(&truth, context.reasoning_reliance()),
                 // * 🚩满足⇒分析性反演（非⇒演绎⇒非）
-                false => truth
-                    .negation()
-                    .analytic_deduction(context.reasoning_reliance())
-                    .negation(),
+                false => calculus.negation(&calculus.analytic_deduction(
+                    &calculus.negation(&truth),
+                    context.reasoning_reliance(),
+                )),
             }
         }),
         Backward => None,
@@ -637,12 +643,13 @@ pub fn transform_negation(
         .current_task()
         .get_()
         .as_judgement()
-        .map(TruthValue::from);
+        .map(|judgement| context.premise_truth(judgement));
 
     // * 🚩真值 * //
+    let calculus = context.truth_calculus();
     let truth = match direction {
         // * 🚩前向推理⇒否定
-        Forward => task_truth.map(|truth| truth.negation()),
+        Forward => task_truth.map(|truth| calculus.negation(&truth)),
         // * 🚩反向推理⇒空
         Backward => None,
     };
@@ -677,14 +684,14 @@ pub fn contraposition(
             let task_rc = context.current_task();
             let task = &*task_rc.get_();
             (
-                task.as_judgement().map(TruthValue::from),
+                task.as_judgement().map(|judgement| context.premise_truth(judgement)),
                 task.punctuation(),
             )
         }
         PremiseSource::Belief => {
             let belief = context.current_belief().unwrap();
             (
-                belief.as_judgement().map(TruthValue::from),
+                belief.as_judgement().map(|judgement| context.premise_truth(judgement)),
                 belief.punctuation(),
             )
         }
@@ -701,12 +708,13 @@ pub fn contraposition(
     );
 
     // * 🚩真值 * //
+    let calculus = context.truth_calculus();
     let truth = match direction {
         Forward => match content.instanceof_implication() {
             // * 🚩蕴含⇒双重否定
-            true => main_sentence_truth.map(|truth| truth.contraposition()),
+            true => main_sentence_truth.map(|truth| calculus.contraposition(&truth)),
             // * 🚩其它⇒恒等
-            false => main_sentence_truth.map(|truth| truth.identity()),
+            false => main_sentence_truth.map(|truth| calculus.identity(&truth)),
         },
         Backward => None,
     };
@@ -33,9 +33,12 @@ pub fn deduction(
     let content = unwrap_or_return!(
         ?Term::make_statement(task.content(), sub, pre)
     );
-    // * 🚩真值
+    // * 🚩真值 | 🆕「定义」以「依赖度」代替其信度
     let truth = match context.reason_direction() {
-        Forward => Some(task.unwrap_judgement().deduction(belief)),
+        Forward => Some(context.truth_calculus().deduction(
+            &context.premise_truth(task.unwrap_judgement()),
+            &context.premise_truth(belief),
+        )),
         Backward => None,
     };
     // * 🚩预算
@@ -59,9 +62,12 @@ pub fn exemplification(
     let content = unwrap_or_return!(
         ?Term::make_statement(task.content(), pre, sub)
     );
-    // * 🚩真值
+    // * 🚩真值 | 🆕「定义」以「依赖度」代替其信度
     let truth = match context.reason_direction() {
-        Forward => Some(task.unwrap_judgement().exemplification(belief)),
+        Forward => Some(context.truth_calculus().exemplification(
+            &context.premise_truth(task.unwrap_judgement()),
+            &context.premise_truth(belief),
+        )),
         Backward => None,
     };
     // * 🚩预算
@@ -85,9 +91,12 @@ pub fn abduction(
     let content = unwrap_or_return!(
         ?Term::make_statement(task.content(), sub, pre)
     );
-    // * 🚩真值
+    // * 🚩真值 | 🆕「定义」以「依赖度」代替其信度
     let truth = match context.reason_direction() {
-        Forward => Some(task.unwrap_judgement().abduction(belief)),
+        Forward => Some(context.truth_calculus().abduction(
+            &context.premise_truth(task.unwrap_judgement()),
+            &context.premise_truth(belief),
+        )),
         Backward => None,
     };
     // * 🚩预算
@@ -111,9 +120,12 @@ pub fn induction(
     let content = unwrap_or_return!(
         ?Term::make_statement(task.content(), pre, sub)
     );
-    // * 🚩真值
+    // * 🚩真值 | 🆕「定义」以「依赖度」代替其信度
     let truth = match context.reason_direction() {
-        Forward => Some(task.unwrap_judgement().induction(belief)),
+        Forward => Some(context.truth_calculus().induction(
+            &context.premise_truth(task.unwrap_judgement()),
+            &context.premise_truth(belief),
+        )),
         Backward => None,
     };
     // * 🚩预算
@@ -137,9 +149,12 @@ pub fn comparison(
     let content = unwrap_or_return!(
         ?Term::make_statement_symmetric(task.content(), sub, pre)
     );
-    // * 🚩真值
+    // * 🚩真值 | 🆕「定义」以「依赖度」代替其信度
     let truth = match context.reason_direction() {
-        Forward => Some(task.unwrap_judgement().comparison(belief)),
+        Forward => Some(context.truth_calculus().comparison(
+            &context.premise_truth(task.unwrap_judgement()),
+            &context.premise_truth(belief),
+        )),
         Backward => None,
     };
     // * 🚩预算
@@ -175,13 +190,12 @@ pub fn analogy(
     let asymmetric_statement = asymmetric.content().as_statement().unwrap();
     let content = unwrap_or_return!(?Term::make_statement(&asymmetric_statement, sub, pre));
 
-    // * 🚩真值
+    // * 🚩真值 | 🆕「定义」以「依赖度」代替其信度
     let truth = match direction {
-        Forward => Some(
-            asymmetric
-                .unwrap_judgement()
-                .analogy(symmetric.unwrap_judgement()),
-        ),
+        Forward => Some(context.truth_calculus().analogy(
+            &context.premise_truth(asymmetric.unwrap_judgement()),
+            &context.premise_truth(symmetric.unwrap_judgement()),
+        )),
         Backward => None,
     };
     // * 🚩预算
@@ -238,8 +252,8 @@ pub fn conditional_abduction(
         .current_task()
         .get_()
         .as_judgement()
-        .map(TruthValue::from);
-    let belief_truth = TruthValue::from(unwrap_or_return!(
+        .map(|judgement| context.premise_truth(judgement));
+    let belief_truth = context.premise_truth(unwrap_or_return!(
         ?context.current_belief() => false
     ));
     let direction = context.reason_direction();
@@ -295,7 +309,7 @@ pub fn conditional_abduction(
                     unwrap_or_return!(?other_truth => break 'derive false),
                 ];
                 // 计算 @ 归因
-                Some(other_truth.abduction(self_truth))
+                Some(context.truth_calculus().abduction(other_truth, self_truth))
             }
             Backward => None,
         };
@@ -332,6 +346,7 @@ pub fn conditional_abduction(
 /// * 📝条件演绎/条件归纳
 /// * ♻️【2024-08-05 15:31:25】不再直接传入「信念」句：可能其中的内容是旧的
 ///   * ⚠️在调用此方法前，有可能经过了「变量归一化」的过程
+///   * 🆕传入的信念只用其真值与「定义」标记，不用其内容
 ///
 /// ```nal
 /// {<(&&, S1, S2, S3) ==> P>, S1} |- <(&&, S2, S3) ==> P>
@@ -342,7 +357,7 @@ pub fn conditional_deduction_induction(
     conditional: Statement,
     index_in_condition: usize,
     premise2: Term,
-    belief_truth: &impl Truth,
+    belief: &impl Judgement,
     conditional_from: PremiseSource, // ! 📝【2024-08-05 01:15:51】暂时用不着：「当前任务是否为条件句」不重要
     side: SyllogismSide,
     context: &mut ReasonContextConcept,
@@ -355,7 +370,8 @@ pub fn conditional_deduction_induction(
         .current_task()
         .get_()
         .as_judgement()
-        .map(TruthValue::from);
+        .map(|judgement| context.premise_truth(judgement));
+    let belief_truth = &context.premise_truth(belief);
     // * 🚩若条件句来自任务，则取premise2作为「信念内容」；否则取来自信念的conditional
     // * ✅【2024-08-05 15:29:10】经测试基本成功
     // println!("{unified_belief_content} 🆚 {}", belief.content());
@@ -447,15 +463,16 @@ pub fn conditional_deduction_induction(
     };
 
     // * 🚩真值 * //
+    let calculus = context.truth_calculus();
     let truth = match direction {
         Forward => Some(match deduction {
-            true => task_truth.unwrap().deduction(belief_truth),
+            true => calculus.deduction(&task_truth.unwrap(), belief_truth),
             // * 🚩演绎 ⇒ 演绎
             false => match conditional_task {
                 // * 🚩任务是条件句 ⇒ 归纳（任务→信念，就是反过来的归因）
-                true => belief_truth.induction(&task_truth.unwrap()),
+                true => calculus.induction(belief_truth, &task_truth.unwrap()),
                 // * 🚩其它 ⇒ 归纳（信念⇒任务）
-                false => task_truth.unwrap().induction(belief_truth),
+                false => calculus.induction(&task_truth.unwrap(), belief_truth),
             },
         }),
         Backward => None,
@@ -483,7 +500,7 @@ pub fn conditional_analogy(
     index_in_condition: usize,
     mut task_implication: Statement, // 前提2
     common_term_side: SyllogismSide,
-    belief_truth: &impl Truth,
+    belief: &impl Judgement,
     context: &mut ReasonContextConcept,
 ) {
     let [rng_seed1, rng_seed2, rng_seed3] = context.shuffle_rng_seeds();
@@ -492,7 +509,8 @@ pub fn conditional_analogy(
         .current_task()
         .get_()
         .as_judgement()
-        .map(TruthValue::from);
+        .map(|judgement| context.premise_truth(judgement));
+    let belief_truth = &context.premise_truth(belief);
     let direction = context.reason_direction();
     let conditional_task =
         variable_process::has_unification_i(&task_implication, &belief_equivalence, rng_seed1);
@@ -548,12 +566,13 @@ pub fn conditional_analogy(
     };
 
     // * 🚩真值 * //
+    let calculus = context.truth_calculus();
     let truth = match direction {
         Forward => Some(match conditional_task {
             // * 🚩条件性任务 ⇒ 比较
-            true => task_truth.unwrap().comparison(belief_truth),
+            true => calculus.comparison(&task_truth.unwrap(), belief_truth),
             // * 🚩其它 ⇒ 类比
-            false => task_truth.unwrap().analogy(belief_truth),
+            false => calculus.analogy(&task_truth.unwrap(), belief_truth),
         }),
         Backward => None,
     };
@@ -586,7 +605,10 @@ pub fn infer_to_sym(
     );
 
     // * 🚩真值 * //
-    let truth = judgement1.intersection(judgement2);
+    let truth = context.truth_calculus().intersection(
+        &context.premise_truth(judgement1),
+        &context.premise_truth(judgement2),
+    );
 
     // * 🚩预算 * //
     let budget = context.budget_forward(&truth);
@@ -615,7 +637,9 @@ pub fn infer_to_asy(
     );
 
     // * 🚩真值 * //
-    let truth = sym.reduce_conjunction(asy);
+    let truth = context
+        .truth_calculus()
+        .reduce_conjunction(&context.premise_truth(sym), &context.premise_truth(asy));
 
     // * 🚩预算 * //
     let budget = context.budget_forward(&truth);
@@ -632,7 +656,7 @@ pub fn infer_to_asy(
 /// reversed Inheritance/Implication
 pub fn conversion(belief: &impl Judgement, context: &mut ReasonContextConcept) {
     // * 🚩真值 * //
-    let truth = belief.conversion();
+    let truth = context.truth_calculus().conversion(belief);
 
     // * 🚩预算 * //
     let budget = context.budget_forward(&truth);
@@ -654,11 +678,12 @@ pub fn convert_relation(task_question: &impl Question, context: &mut ReasonConte
     let belief = unwrap_or_return!(
         ?context.current_belief()
     );
+    let calculus = context.truth_calculus();
     let truth = match task_question.content().is_commutative() {
         // * 🚩可交换（相似/等价）⇒归纳
-        true => belief.analytic_abduction(ShortFloat::ONE),
+        true => calculus.analytic_abduction(belief, ShortFloat::ONE),
         // * 🚩不可交换（继承/蕴含）⇒演绎
-        false => belief.analytic_deduction(ShortFloat::ONE),
+        false => calculus.analytic_deduction(belief, ShortFloat::ONE),
     };
     // * 🚩预算 * //
     let budget = context.budget_forward(&truth);
@@ -739,9 +764,12 @@ pub fn resemblance(
     let content = unwrap_or_return!(
         ?Term::make_statement(belief.content(), sub, pre)
     );
-    // * 🚩真值
+    // * 🚩真值 | 🆕「定义」以「依赖度」代替其信度
     let truth = match direction {
-        Forward => Some(belief.resemblance(task.unwrap_judgement())),
+        Forward => Some(context.truth_calculus().resemblance(
+            &context.premise_truth(belief),
+            &context.premise_truth(task.unwrap_judgement()),
+        )),
         Backward => None,
     };
     // * 🚩预算
@@ -805,21 +833,22 @@ pub fn detachment(
         }
     }
     // * 🚩真值
+    let calculus = context.truth_calculus();
     let truth = match direction {
         Forward => {
             // 提取主句、副句
             let [main_sentence_truth, sub_sentence_truth] = high_order_position.select([
-                TruthValue::from(task_sentence.unwrap_judgement()),
-                TruthValue::from(belief),
+                context.premise_truth(task_sentence.unwrap_judgement()),
+                context.premise_truth(belief),
             ]);
             // 计算真值
             Some(match (high_order_symmetric, position_sub_in_hi) {
                 // * 🚩等价⇒类比
-                (true, _) => sub_sentence_truth.analogy(&main_sentence_truth),
+                (true, _) => calculus.analogy(&sub_sentence_truth, &main_sentence_truth),
                 // * 🚩非对称 & 主词 ⇒ 演绎
-                (_, Subject) => main_sentence_truth.deduction(&sub_sentence_truth),
+                (_, Subject) => calculus.deduction(&main_sentence_truth, &sub_sentence_truth),
                 // * 🚩其它 ⇒ 归纳
-                (_, Predicate) => sub_sentence_truth.abduction(&main_sentence_truth),
+                (_, Predicate) => calculus.abduction(&sub_sentence_truth, &main_sentence_truth),
             })
         }
         // * 🚩反向推理⇒空
@@ -843,7 +872,75 @@ pub fn detachment(
 
 #[cfg(test)]
 mod tests {
-    use crate::expectation_tests;
+    use crate::{
+        entity::{Sentence, ShortFloat},
+        expectation_tests,
+        inference::{
+            tests::tools::{create_reasoner_from_engine, ENGINE_DEV},
+            ExponentialHorizon, StandardNal, Truth, TruthCalculusRef,
+        },
+        language::Term,
+        ok, test_term as term,
+        util::AResult,
+    };
+
+    /// 同一套规则，不同的真值演算
+    /// * 🚩归纳（弱推理）的结论信度随演算变化
+    #[test]
+    fn truth_calculus_per_reasoner() -> AResult {
+        let conclusion = term!("<B --> C>");
+        let induced_confidence = |calculus: TruthCalculusRef| {
+            let mut reasoner = create_reasoner_from_engine(ENGINE_DEV);
+            reasoner.set_truth_calculus(calculus);
+            reasoner.input_cmds("nse <A --> B>.\nnse <A --> C>.\ncyc 100");
+            let concept = reasoner
+                .memory()
+                .term_to_concept(&conclusion)
+                .expect("应有归纳结论的概念");
+            let belief = concept.beliefs().next().expect("应有归纳结论");
+            assert_eq!(*belief.content(), conclusion);
+            belief.confidence()
+        };
+        let standard = induced_confidence(StandardNal::REF);
+        let exponential = induced_confidence(ExponentialHorizon::REF);
+        assert!(exponential > standard, "{exponential} <= {standard}");
+        ok!()
+    }
+
+    /// 🆕以经验信念或「定义」（指令头`head`）作为第二前提，得到结论的信度
+    /// * 📌第二前提的信度为`0.8`：低于依赖度（0.9）
+    ///   * ⚠️信度过低⇒弱推理结论的预算过低，不会被保留
+    fn derived_confidence(premise: &str, head: &str, conclusion: &str) -> AResult<ShortFloat> {
+        let conclusion = conclusion.parse::<Term>()?;
+        let mut reasoner = create_reasoner_from_engine(ENGINE_DEV);
+        reasoner.input_cmds(&format!(
+            "nse {premise}.\n{head} <square --> rectangle>. %1.0;0.8%\ncyc 100"
+        ));
+        let concept = reasoner
+            .memory()
+            .term_to_concept(&conclusion)
+            .expect("应有结论的概念");
+        let belief = concept.beliefs().next().expect("应有结论");
+        Ok(belief.confidence())
+    }
+
+    /// 🆕「定义」作为前提时，以「依赖度」代替其信度
+    /// * 🚩经验：依第二前提的信度（0.8）；定义：依依赖度（0.9）
+    /// * 📌定义不令结论失效：弱推理的结论信度亦不为零
+    #[test]
+    fn syllogisms_with_definition() -> AResult {
+        for (rule, premise, conclusion) in [
+            ("deduction", "<tweety --> square>", "<tweety --> rectangle>"),
+            ("abduction", "<tweety --> rectangle>", "<square --> tweety>"),
+            ("induction", "<tweety --> rectangle>", "<tweety --> square>"),
+        ] {
+            let empirical = derived_confidence(premise, "nse", conclusion)?;
+            let definitional = derived_confidence(premise, "def", conclusion)?;
+            assert!(definitional > ShortFloat::ZERO, "{rule}");
+            assert!(definitional > empirical, "{rule}: {definitional} <= {empirical}");
+        }
+        ok!()
+    }
 
     expectation_tests! {
         deduction: {
@@ -6,7 +6,7 @@ use crate::{
         ReasonDirection,
     },
     entity::{Sentence, TLink, TruthValue},
-    inference::{BudgetInferenceContext, TruthFunctions},
+    inference::BudgetInferenceContext,
     language::{CompoundTermRef, StatementRef, Term},
     symbols::*,
 };
@@ -94,7 +94,9 @@ fn truth_transforming(
     // * 🚩真值 * //
     let truth = match direction {
         Forward => Some(
-            context.current_task().get_().unwrap_judgement().identity(), // 真值函数：恒等
+            context
+                .truth_calculus()
+                .identity(&context.premise_truth(context.current_task().get_().unwrap_judgement())), // 真值函数：恒等
         ),
         Backward => None,
     };
@@ -5,8 +5,14 @@
 // 工具
 pub mod tools;
 
+// NAL示例集
+mod nal_examples;
+
 // 稳定性测试
 mod stability;
 
+// 性质测试
+mod properties;
+
 /// debug测试
 mod debugging;
@@ -0,0 +1,318 @@
+//! 性质测试
+//! * 🎯随机生成真值、预算值，检验NAL数值函数的代数性质
+//!   * 📌覆盖所有内置的[真值演算](crate::inference::TRUTH_CALCULI)与[预算策略](crate::inference::BUDGET_POLICIES)
+//! * 🚩基于`proptest`：失败时自动收缩到最小反例
+//!
+//! ## 所检验的性质
+//!
+//! * 📍结果不越界：所有真值、预算值均在`[0, 1]`内（且不panic）
+//! * 📍修正可交换：`revision(a, b) = revision(b, a)`
+//! * 📍修正不降低信度：`c ≥ max(c1, c2)`
+//! * 📍演绎不超出前提：`f ≤ min(f1, f2)`，`c ≤ min(c1, c2)`
+//! * 📍证据量转换互逆：`w2c(c2w(c)) = c`
+//! * 📍有限证据的信度小于`1`：大量证据的信念仍可被修正
+
+use crate::{
+    entity::{BudgetValue, ShortFloat, TruthValue},
+    global::Float,
+    inference::{
+        Budget, BudgetFunctions, BudgetInferenceFunction, Truth, TruthCalculusFDouble,
+        TruthCalculusFSingle, BUDGET_POLICIES, TRUTH_CALCULI,
+    },
+    language::Term,
+};
+use proptest::prelude::*;
+
+/// 比较时允许的浮点误差
+/// * 📌远小于短浮点的精度：四位精度下等价于精确比较
+/// * 🎯兼容`high_precision`特性下的舍入误差
+const EPSILON: Float = 1e-9;
+
+/// 所有单真值函数
+const SINGLE_FUNCTIONS: &[(&str, TruthCalculusFSingle)] = &[
+    ("identity", |c, v1| c.identity(v1)),
+    ("conversion", |c, v1| c.conversion(v1)),
+    ("negation", |c, v1| c.negation(v1)),
+    ("contraposition", |c, v1| c.contraposition(v1)),
+];
+
+/// 所有双真值函数
+const DOUBLE_FUNCTIONS: &[(&str, TruthCalculusFDouble)] = &[
+    ("revision", |c, v1, v2| c.revision(v1, v2)),
+    ("deduction", |c, v1, v2| c.deduction(v1, v2)),
+    ("analogy", |c, v1, v2| c.analogy(v1, v2)),
+    ("resemblance", |c, v1, v2| c.resemblance(v1, v2)),
+    ("abduction", |c, v1, v2| c.abduction(v1, v2)),
+    ("induction", |c, v1, v2| c.induction(v1, v2)),
+    ("exemplification", |c, v1, v2| c.exemplification(v1, v2)),
+    ("comparison", |c, v1, v2| c.comparison(v1, v2)),
+    ("desire_strong", |c, v1, v2| c.desire_strong(v1, v2)),
+    ("desire_weak", |c, v1, v2| c.desire_weak(v1, v2)),
+    ("desire_deduction", |c, v1, v2| c.desire_deduction(v1, v2)),
+    ("desire_induction", |c, v1, v2| c.desire_induction(v1, v2)),
+    ("union", |c, v1, v2| c.union_(v1, v2)),
+    ("intersection", |c, v1, v2| c.intersection(v1, v2)),
+    ("reduce_disjunction", |c, v1, v2| {
+        c.reduce_disjunction(v1, v2)
+    }),
+    ("reduce_conjunction", |c, v1, v2| {
+        c.reduce_conjunction(v1, v2)
+    }),
+    ("reduce_conjunction_neg", |c, v1, v2| {
+        c.reduce_conjunction_neg(v1, v2)
+    }),
+    ("anonymous_analogy", |c, v1, v2| c.anonymous_analogy(v1, v2)),
+];
+
+/// 所有预算推理函数
+const BUDGET_INFERENCE_FUNCTIONS: [BudgetInferenceFunction; 6] = {
+    use BudgetInferenceFunction::*;
+    [
+        Forward,
+        Backward,
+        BackwardWeak,
+        CompoundForward,
+        CompoundBackward,
+        CompoundBackwardWeak,
+    ]
+};
+
+/* 生成器 */
+
+/// 任意短浮点（含`0`与`1`）
+fn short_float() -> impl Strategy<Value = ShortFloat> {
+    prop_oneof![
+        1 => Just(ShortFloat::ZERO),
+        1 => Just(ShortFloat::ONE),
+        8 => (0.0..=1.0).prop_map(ShortFloat::from_float),
+    ]
+}
+
+/// 任意真值
+/// * 📌信度小于`1`：信度为`1`的「无穷证据」仅作为特例单独测试
+fn truth() -> impl Strategy<Value = TruthValue> {
+    (short_float(), 0.0..1.0).prop_map(|(f, c)| {
+        // * ⚠️舍入后仍可能为`1`
+        let c = ShortFloat::from_float(c).min(ShortFloat::from_float(0.9999));
+        TruthValue::new_fc(f, c)
+    })
+}
+
+/// 任意预算值
+fn budget() -> impl Strategy<Value = BudgetValue> {
+    (short_float(), short_float(), short_float()).prop_map(|(p, d, q)| BudgetValue::new(p, d, q))
+}
+
+/* 断言 */
+
+/// 检验浮点数在`[0, 1]`内
+fn in_unit(x: Float) -> bool {
+    (0.0..=1.0).contains(&x)
+}
+
+/// 检验真值不越界
+fn assert_truth_in_range(name: &str, truth: &TruthValue) -> Result<(), TestCaseError> {
+    let [f, c] = truth.fc().map(|v| v.to_float());
+    prop_assert!(in_unit(f) && in_unit(c), "{name} => {truth:?}");
+    Ok(())
+}
+
+/// 检验预算值不越界
+fn assert_budget_in_range(name: &str, budget: &BudgetValue) -> Result<(), TestCaseError> {
+    let [p, d, q] = budget.pdq_float();
+    prop_assert!(
+        in_unit(p) && in_unit(d) && in_unit(q),
+        "{name} => {budget:?}"
+    );
+    Ok(())
+}
+
+proptest! {
+    /// 真值函数：结果不越界
+    #[test]
+    fn truth_functions_in_range(v1 in truth(), v2 in truth(), reliance in short_float()) {
+        for &calculus in TRUTH_CALCULI {
+            for (name, f) in SINGLE_FUNCTIONS {
+                assert_truth_in_range(name, &f(calculus, &v1))?;
+            }
+            for (name, f) in DOUBLE_FUNCTIONS {
+                assert_truth_in_range(name, &f(calculus, &v1, &v2))?;
+            }
+            assert_truth_in_range("analytic_deduction", &calculus.analytic_deduction(&v1, reliance))?;
+            assert_truth_in_range("analytic_abduction", &calculus.analytic_abduction(&v1, reliance))?;
+        }
+    }
+
+    /// 修正：可交换
+    #[test]
+    fn revision_commutative(v1 in truth(), v2 in truth()) {
+        for &calculus in TRUTH_CALCULI {
+            prop_assert_eq!(
+                calculus.revision(&v1, &v2),
+                calculus.revision(&v2, &v1),
+                "{}", calculus.name()
+            );
+        }
+    }
+
+    /// 修正：不降低信度
+    #[test]
+    fn revision_never_lowers_confidence(v1 in truth(), v2 in truth()) {
+        for &calculus in TRUTH_CALCULI {
+            let revised = calculus.revision(&v1, &v2);
+            let max = v1.confidence().max(v2.confidence());
+            prop_assert!(
+                revised.confidence().to_float() + EPSILON >= max.to_float(),
+                "{}: {v1:?} + {v2:?} => {revised:?}", calculus.name()
+            );
+        }
+    }
+
+    /// 修正：「无穷证据」不被有限证据改变
+    #[test]
+    fn revision_infinite_evidence(f1 in short_float(), v2 in truth()) {
+        let v1 = TruthValue::new_fc(f1, ShortFloat::ONE);
+        for &calculus in TRUTH_CALCULI {
+            prop_assert_eq!(calculus.revision(&v1, &v2).fc(), v1.fc());
+        }
+    }
+
+    /// 演绎：频率、信度均不超出前提
+    #[test]
+    fn deduction_within_premises(v1 in truth(), v2 in truth()) {
+        for &calculus in TRUTH_CALCULI {
+            let deduced = calculus.deduction(&v1, &v2);
+            prop_assert!(deduced.frequency() <= v1.frequency().min(v2.frequency()));
+            prop_assert!(deduced.confidence() <= v1.confidence().min(v2.confidence()));
+        }
+    }
+
+    /// 证据量转换：互逆
+    #[test]
+    fn w2c_c2w_round_trip(truth in truth()) {
+        let c = truth.confidence();
+        for &calculus in TRUTH_CALCULI {
+            let round_trip = calculus.w2c(calculus.c2w(c));
+            prop_assert!(
+                (round_trip.to_float() - c.to_float()).abs() <= EPSILON,
+                "{}: {c:?} => {round_trip:?}", calculus.name()
+            );
+        }
+    }
+
+    /// 证据量转换：单调
+    #[test]
+    fn w2c_monotonic(w1 in 0.0..1000.0, w2 in 0.0..1000.0) {
+        let (w_min, w_max) = (Float::min(w1, w2), Float::max(w1, w2));
+        for &calculus in TRUTH_CALCULI {
+            prop_assert!(calculus.w2c(w_min) <= calculus.w2c(w_max));
+        }
+    }
+
+    /// 证据量转换：有限证据的信度小于`1`，且可转换回去
+    #[test]
+    fn w2c_finite_evidence(w in 0.0..1000.0) {
+        for &calculus in TRUTH_CALCULI {
+            let c = calculus.w2c(w);
+            prop_assert!(!c.is_one(), "{}: w = {w} => {c:?}", calculus.name());
+            let round_trip = calculus.w2c(calculus.c2w(c));
+            prop_assert!(
+                (round_trip.to_float() - c.to_float()).abs() <= EPSILON,
+                "{}: w = {w} => {c:?} => {round_trip:?}", calculus.name()
+            );
+        }
+    }
+
+    /// 修正：相反的证据总会改变「有限证据」的信念
+    /// * 📌包括证据多到信度饱和的信念
+    #[test]
+    fn revision_moves_finite_evidence(w1 in 0.0..1000.0, c2 in 0.5..0.9999) {
+        for &calculus in TRUTH_CALCULI {
+            let v1 = TruthValue::new_fc(ShortFloat::ONE, calculus.w2c(w1));
+            let v2 = TruthValue::new_fc(ShortFloat::ZERO, ShortFloat::from_float(c2));
+            let revised = calculus.revision(&v1, &v2);
+            prop_assert!(
+                revised.frequency() < v1.frequency(),
+                "{}: {v1:?} + {v2:?} => {revised:?}", calculus.name()
+            );
+        }
+    }
+
+    /// 预算推理：结果不越界
+    #[test]
+    fn budget_inference_in_range(
+        truth in truth(),
+        task_link in budget(),
+        belief_link in proptest::option::of(budget()),
+        target_activation in short_float(),
+    ) {
+        let content = "<(&&, A, B) --> C>".parse::<Term>().unwrap();
+        for &policy in BUDGET_POLICIES {
+            for function in BUDGET_INFERENCE_FUNCTIONS {
+                use BudgetInferenceFunction::*;
+                // * 🚩按各函数的要求，传入真值、词项
+                let (truth, content) = match function {
+                    Forward | Backward | BackwardWeak => (Some(&truth as &dyn Truth), None),
+                    CompoundForward => (Some(&truth as &dyn Truth), Some(&content)),
+                    CompoundBackward | CompoundBackwardWeak => (None, Some(&content)),
+                };
+                let result = policy.budget_inference(
+                    function,
+                    truth,
+                    content,
+                    &task_link,
+                    belief_link.as_ref(),
+                    target_activation,
+                );
+                let name = format!("{} @ {function:?}", policy.name());
+                assert_budget_in_range(&name, &result.new_budget)?;
+                if let Some(new_belief_link) = result.new_belief_link_budget {
+                    assert_budget_in_range(&name, &new_belief_link)?;
+                }
+            }
+        }
+    }
+
+    /// 修正、求解的预算函数：结果不越界
+    /// * 📌修正后的真值由「修正」真值函数得出：其信度不低于前提
+    #[test]
+    fn budget_revise_solution_in_range(
+        [v1, v2] in [truth(), truth()],
+        [task, t_link, b_link] in [budget(), budget(), budget()],
+        quality in short_float(),
+    ) {
+        for &calculus in TRUTH_CALCULI {
+            let revised = calculus.revision(&v1, &v2);
+            for &policy in BUDGET_POLICIES {
+                let name = policy.name();
+                let result = policy.revise(&v1, &v2, &revised, &task, Some((&t_link, &b_link)));
+                assert_budget_in_range(name, &result.new_budget)?;
+                assert_budget_in_range(name, &result.new_task_budget)?;
+                for link in result.new_links_budget.iter().flatten() {
+                    assert_budget_in_range(name, link)?;
+                }
+                assert_budget_in_range(name, &policy.solution_eval(quality, &v1, &task))?;
+            }
+        }
+    }
+
+    /// 袋相关的预算函数：结果不越界
+    #[test]
+    fn budget_bag_functions_in_range(
+        b1 in budget(),
+        b2 in budget(),
+        n in 1_usize..100,
+        forget_rate in 1.0..100.0,
+        relative_threshold in 0.0..=1.0,
+    ) {
+        assert_budget_in_range("merge", &b1.merge(&b2))?;
+        assert_budget_in_range("distribute_among_links", &b1.distribute_among_links(n))?;
+        let forgot = b1.forget(forget_rate, relative_threshold);
+        prop_assert!(in_unit(forgot), "forget => {forgot}");
+        // * 📝遗忘后的优先级介于「放缩后质量」与原优先级之间
+        let scaled_q = b1.quality().to_float() * relative_threshold;
+        let p = b1.priority().to_float();
+        prop_assert!(forgot + EPSILON >= scaled_q.min(p), "forget => {forgot}");
+        prop_assert!(forgot <= scaled_q.max(p) + EPSILON, "forget => {forgot}");
+    }
+}
@@ -82,6 +82,15 @@ impl Reasoner {
         use Cmd::*;
         match cmd {
             NSE(task) => self.input_task(task),
+            // * 🆕定义：同虚拟机Alpha的自定义指令`DEF`
+            Custom { head, tail } if head.eq_ignore_ascii_case("DEF") => {
+                let task = FORMAT_ASCII
+                    .parse(&tail)
+                    .map_err(|e| anyhow::anyhow!("{e}"))?
+                    .try_into_task_compatible()
+                    .map_err(|e| anyhow::anyhow!("{e:?}"))?;
+                self.input_definition(task)
+            }
             CYC(steps) => self.cycle(steps),
             VOL(volume) => self.set_volume(volume),
             RES { .. } => self.reset(),
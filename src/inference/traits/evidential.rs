@@ -1,9 +1,14 @@
 //! 复刻抽象的「证据基」特征
 //! * 🎯以「时间戳」为基本结构，使「语句」「任务」直接支持其中的功能
 
-use crate::{global::ClockTime, symbols::*, util::ToDisplayAndBrief};
+use crate::{
+    global::{ClockTime, Float},
+    symbols::*,
+    util::ToDisplayAndBrief,
+};
 use nar_dev_utils::{join, JoinTo};
 use narsese::lexical::Stamp as LexicalStamp;
+use serde::{Deserialize, Serialize};
 
 /// [`Vec`]集合判等
 fn set_vec_eq<T: Clone + Ord>(v1: &[T], v2: &[T]) -> bool {
@@ -15,6 +20,59 @@ fn set_vec_eq<T: Clone + Ord>(v1: &[T], v2: &[T]) -> bool {
     // v1 == v2
 }
 
+/// 🆕「证据重合」的处理策略
+/// * 🎯决定证据基有重合的两个前提能否一同推理
+///   * 📝重复计入证据会虚增信度：原版一律拒绝
+/// * 📌检查时机：直接推理中的「修正」、概念推理中选取「当前信念」
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum OverlapPolicy {
+    /// 严格拒绝（原版）
+    /// * 📍有任何重合证据⇒不修正、不作为「当前信念」
+    #[default]
+    Strict,
+    /// 按重合比例折扣
+    /// * 📍允许部分重合的前提一同推理，结论信度乘以`1 - 重合比例`
+    /// * 📍重合比例：共有证据占全部（不重复）证据的比例
+    /// * ⚠️证据完全相同⇒仍然拒绝
+    Discount,
+}
+
+impl OverlapPolicy {
+    /// 两个前提能否（按证据基）一同推理
+    pub fn admits(self, first: &impl Evidential, second: &impl Evidential) -> bool {
+        match self {
+            Self::Strict => !first.evidential_overlap(second),
+            Self::Discount => first.evidential_overlap_ratio(second) < 1.0,
+        }
+    }
+
+    /// 结论信度的折扣系数
+    /// * 📌取值范围：`[0, 1]`；没有重合证据⇒`1`
+    pub fn confidence_discount(self, first: &impl Evidential, second: &impl Evidential) -> Float {
+        match self {
+            Self::Strict => 1.0,
+            Self::Discount => 1.0 - first.evidential_overlap_ratio(second),
+        }
+    }
+}
+
+/// 🆕合并证据基时的交织顺序
+/// * 🎯决定证据基超长截断时，保留哪些证据
+/// * 📌合并后的证据基总是不含重复的证据
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum InterleaveOrder {
+    /// 原版：较长者在前，两者交替（拉链式）
+    /// * 📍截断时两个前提的证据大致各保留一半
+    #[default]
+    Original,
+    /// 顺序拼接：先第一前提的全部证据，再第二前提的
+    /// * 📍截断时优先保留第一前提（一般为「当前任务」）的证据
+    Sequential,
+    /// 新近优先：按证据序列号从大到小
+    /// * 📍截断时优先丢弃最早的证据
+    Newest,
+}
+
 /// 🆕证据（基）
 /// * 🎯抽象描述「时间戳」的特征
 /// * 📝核心：记载一系列「证据时间」，提供「证据是否重复」方法，以避免「重复推理」
@@ -72,10 +130,12 @@ pub trait Evidential: ToDisplayAndBrief {
     ///
     /// @param first  The first Stamp
     /// @param second The second Stamp
+    /// * 🆕交织顺序由[`InterleaveOrder`]决定；原版顺序即如下源码所示
     fn merged_evidential_base(
         first: &[ClockTime],
         second: &[ClockTime],
         max_evidence_base_length: usize,
+        order: InterleaveOrder,
     ) -> Vec<ClockTime> {
         /* 📄OpenNARS
         // * 🚩计算新证据基长度：默认长度相加，一定长度后截断
@@ -113,29 +173,42 @@ pub trait Evidential: ToDisplayAndBrief {
         return evidentialBase; */
         // * 🚩计算新证据基长度：默认长度相加，一定长度后截断
         let base_length = ClockTime::min(first.len() + second.len(), max_evidence_base_length);
-        // * 🚩计算长短证据基
-        let [longer, shorter] = match first.len() > second.len() {
-            true => [first, second],
-            false => [second, first],
-        };
         // * 🚩构造返回值
-        let mut j = 0;
-        let mut evidential_base = vec![0; base_length];
+        let mut evidential_base = Vec::with_capacity(base_length);
         let mut put_in_base = |evidence| {
             // * 🚩【2024-06-27 00:45:30】使用一个闭包来简化「放置前判断」
-            if j < base_length {
-                evidential_base[j] = evidence;
-                j += 1;
+            // * 🚩🆕重复的证据只放一次：允许证据重合时，避免浪费证据基长度
+            if evidential_base.len() < base_length && !evidential_base.contains(&evidence) {
+                evidential_base.push(evidence);
             }
         };
-        // * 🚩填充数据：拉链式填充，1-2-1-2……
-        for (&evidence_l, &evidence_s) in longer.iter().zip(shorter.iter()) {
-            put_in_base(evidence_l);
-            put_in_base(evidence_s);
-        }
-        // * 🚩2的长度比1小，所以此后随1填充
-        for &evidence_l_residual in longer.iter().skip(shorter.len()) {
-            put_in_base(evidence_l_residual);
+        match order {
+            InterleaveOrder::Original => {
+                // * 🚩计算长短证据基
+                let [longer, shorter] = match first.len() > second.len() {
+                    true => [first, second],
+                    false => [second, first],
+                };
+                // * 🚩填充数据：拉链式填充，1-2-1-2……
+                for (&evidence_l, &evidence_s) in longer.iter().zip(shorter.iter()) {
+                    put_in_base(evidence_l);
+                    put_in_base(evidence_s);
+                }
+                // * 🚩2的长度比1小，所以此后随1填充
+                for &evidence_l_residual in longer.iter().skip(shorter.len()) {
+                    put_in_base(evidence_l_residual);
+                }
+            }
+            InterleaveOrder::Sequential => {
+                // * 🚩先1后2
+                first.iter().chain(second).for_each(|&evidence| put_in_base(evidence));
+            }
+            InterleaveOrder::Newest => {
+                // * 🚩序列号从大到小
+                let mut all = [first, second].concat();
+                all.sort_unstable_by(|a, b| b.cmp(a));
+                all.into_iter().for_each(put_in_base);
+            }
         }
         // 返回
         evidential_base
@@ -152,6 +225,20 @@ pub trait Evidential: ToDisplayAndBrief {
             .any(|i| second.evidential_base().contains(i))
     }
 
+    /// 🆕两个「证据基」的重合比例
+    /// * 🎯按「证据重合策略」折扣结论信度
+    /// * 🚩共有证据数 / 全部（不重复）证据数
+    /// * 📌取值范围：`[0, 1]`；两者皆空⇒`0`
+    fn evidential_overlap_ratio(&self, second: &impl Evidential) -> Float {
+        let [base1, base2] = [self.evidential_base(), second.evidential_base()];
+        let n_shared = base1.iter().filter(|i| base2.contains(i)).count();
+        let n_union = base1.len() + base2.len() - n_shared;
+        match n_union {
+            0 => 0.0,
+            _ => n_shared as Float / n_union as Float,
+        }
+    }
+
     /// 判断是否【在证据上】相等
     fn evidential_eq(&self, other: &impl Evidential) -> bool {
         set_vec_eq(self.evidential_base(), other.evidential_base())
@@ -12,6 +12,9 @@ use narsese::lexical::Truth as LexicalTruth;
 /// # 📄OpenNARS
 ///
 /// Frequency and confidence.
+///
+/// * 📌可作为特征对象使用：其它真值的参数均为`&dyn Truth`
+///   * 🎯供[真值演算](crate::inference::TruthCalculus)以函数指针形式存储、动态分派
 pub trait Truth: ToDisplayAndBrief {
     /// 一种类型只可能有一种「证据值」
     /// * ✅兼容OpenNARS `ShortFloat`
@@ -34,7 +37,7 @@ pub trait Truth: ToDisplayAndBrief {
     }
 
     /// 🆕用于快捷获取双倍的「频率-信度」对
-    fn fc_with(&self, other: &impl Truth) -> ([ShortFloat; 2], [ShortFloat; 2]) {
+    fn fc_with(&self, other: &dyn Truth) -> ([ShortFloat; 2], [ShortFloat; 2]) {
         (self.fc(), other.fc())
     }
 
@@ -86,7 +89,7 @@ pub trait Truth: ToDisplayAndBrief {
     /// @return The absolute difference
     #[doc(alias = "get_exp_dif_abs")]
     #[doc(alias = "expectation_absolute_difference")]
-    fn expectation_abs_dif(&self, other: &impl Truth) -> Float {
+    fn expectation_abs_dif(&self, other: &dyn Truth) -> Float {
         /* 📄OpenNARS源码：
         return Math.abs(getExpectation() - t.getExpectation()); */
         (self.expectation() - other.expectation()).abs()
@@ -116,7 +119,7 @@ pub trait Truth: ToDisplayAndBrief {
     /// * 🎯将两个对象作为「真值」比较
     /// * 🎯用于「判断句」中「真值方面的比较」
     /// * ⚠️不一定等于
-    fn truth_eq(&self, other: &impl Truth) -> bool {
+    fn truth_eq(&self, other: &dyn Truth) -> bool {
         self.frequency() == other.frequency() && self.confidence() == other.frequency()
     }
 
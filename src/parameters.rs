@@ -6,7 +6,10 @@
 //! * ♻️【2024-09-05 01:22:19】现移至模块根部，以统领全局超参数
 //!   * ℹ️理由：避免让`control`模块与其它模块耦合——让`entity`、`storage`与之解耦
 
-use crate::global::Float;
+use crate::{
+    global::Float,
+    inference::{InterleaveOrder, OverlapPolicy},
+};
 use nar_dev_utils::macro_once;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +17,31 @@ use serde::{Deserialize, Serialize};
 /// * 🚩🆕【2024-05-03 16:00:14】根据在「真值函数」中的实际用途，此处将其修改为「浮点数」[`Float`]类型
 pub type EvidentialHorizon = Float;
 
+/// 🆕「排行表」的排行策略
+/// * 🎯决定「概念」中信念表、问题表的排序与淘汰
+///   * 📄易变的事实⇒新近优先；稳定的事实⇒信度优先
+/// * 📌具体的排行函数见[`Concept`](crate::entity::Concept)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RankStrategy {
+    /// 原版策略
+    /// * 📍信念：信度与原创性（证据基长度）的「或」
+    /// * 📍问题：新近优先（同原先的先进先出）
+    #[default]
+    Original,
+    /// 信度优先
+    /// * 📍问题：取其最优解的信度
+    Confidence,
+    /// 期望优先
+    /// * 📍问题：取其最优解的期望
+    Expectation,
+    /// 新近优先：按创建时间
+    Recency,
+    /// 信度、期望、新近的加权混合
+    /// * 📍权重固定为`0.4`、`0.4`、`0.2`
+    /// * 📍新近程度为`t / (t + 100)`：随时间饱和，长时间运行后近乎不起作用
+    Mixed,
+}
+
 macro_once! {
     // * 🚩模式：自动为「属性 = 值」生成[`Default`]实现
     macro parameters(
@@ -264,6 +292,75 @@ macro_once! {
         ///   * 📌默认值数据来自「概念袋」
         #[serde(default = "default_values::novel_task_forgetting_cycle")]
         pub novel_task_forgetting_cycle: usize = 10,
+
+        /// 🆕概念中「信念表」的排行策略
+        #[serde(default = "default_values::belief_rank_strategy")]
+        pub belief_rank_strategy: RankStrategy = RankStrategy::Original,
+
+        /// 🆕概念中「问题表」的排行策略
+        #[serde(default = "default_values::question_rank_strategy")]
+        pub question_rank_strategy: RankStrategy = RankStrategy::Original,
+
+        /// 🆕「问题导出」的最大深度
+        /// * 🎯避免「反向推理」无限制地导出越来越远的问题
+        /// * 📌深度：导出的问题经由[`Task::parents`](crate::entity::Task::parents)追溯到的祖先中，问题的数目
+        ///   * 📝只计问题：判断句（如解答导出问题后被激活的信念）不计入深度
+        /// * 📌空值⇒不限制（同OpenNARS）
+        #[serde(default = "default_values::maximum_question_derivation_depth")]
+        pub maximum_question_derivation_depth: Option<usize> = None,
+
+        /// 🆕每个概念每次推理中「导出的问题」的最大数目
+        /// * 🎯避免大型知识库中的推测性问题挤占任务缓冲区
+        /// * 📌对一个概念的一次推理（直接推理、转换推理、概念推理），已导出这么多问题时，忽略新导出的问题
+        ///   * 📝导出的任务在推理结束后才进入任务缓冲区
+        /// * 📌空值⇒不限制（同OpenNARS）
+        #[serde(default = "default_values::maximum_derived_questions_per_concept")]
+        pub maximum_derived_questions_per_concept: Option<usize> = None,
+
+        /// 🆕「冲突检测」的频率阈值
+        /// * 🎯定位知识库中的矛盾：两个同内容判断的频率相差超过此阈值时，报告冲突
+        /// * 📌检测时机：直接推理/匹配推理中的「修正」、概念推理中选取「当前信念」
+        /// * 📌空值⇒不检测（同OpenNARS）
+        #[serde(default = "default_values::conflict_frequency_threshold")]
+        pub conflict_frequency_threshold: Option<Float> = None,
+
+        /// 🆕「冲突检测」的最低信度
+        /// * 🎯只报告「高信度」判断之间的冲突，忽略证据尚少的猜测
+        /// * 📌两个判断的信度均不低于此值时，才报告冲突
+        #[serde(default = "default_values::conflict_minimum_confidence")]
+        pub conflict_minimum_confidence: Float = 0.0,
+
+        /// 🆕报告「导出结论」的最低信度
+        /// * 🎯在较高音量下减少低信度结论刷屏
+        /// * 📌只影响`OUT`输出，不影响推理；问题不受影响
+        /// * 📌`0`⇒不过滤（同OpenNARS）
+        #[serde(default = "default_values::derived_output_minimum_confidence")]
+        pub derived_output_minimum_confidence: Float = 0.0,
+
+        /// 🆕报告「导出结论」的最低期望
+        /// * 📌只影响`OUT`输出，不影响推理；问题不受影响
+        /// * 📌`0`⇒不过滤（同OpenNARS）
+        #[serde(default = "default_values::derived_output_minimum_expectation")]
+        pub derived_output_minimum_expectation: Float = 0.0,
+
+        /// 🆕概念中「信念修正历史」的长度
+        /// * 🎯记录每次修正前后的真值，分析信念随证据的演变
+        /// * 📌超出长度时丢弃最早的记录
+        /// * 📌`0`⇒不记录（同OpenNARS）
+        #[serde(default = "default_values::revision_history_length")]
+        pub revision_history_length: usize = 0,
+
+        /// 🆕「证据重合」的处理策略
+        /// * 🎯研究「重复计入证据」对推理结果的影响
+        /// * 📌默认严格拒绝（同OpenNARS）
+        #[serde(default = "default_values::evidence_overlap_policy")]
+        pub evidence_overlap_policy: OverlapPolicy = OverlapPolicy::Strict,
+
+        /// 🆕合并证据基时的交织顺序
+        /// * 🎯决定证据基超过[最大长度](Self::maximum_stamp_length)而截断时，保留哪些证据
+        /// * 📌默认拉链式交替（同OpenNARS）
+        #[serde(default = "default_values::evidence_interleave_order")]
+        pub evidence_interleave_order: InterleaveOrder = InterleaveOrder::Original,
     }
 }
 
@@ -341,6 +438,17 @@ mod tests {
             maximum_questions_length         => 5
             novel_task_bag_size              => 1000
             novel_task_forgetting_cycle      => 10
+            belief_rank_strategy             => RankStrategy::Original
+            question_rank_strategy           => RankStrategy::Original
+            maximum_question_derivation_depth     => None
+            maximum_derived_questions_per_concept => None
+            conflict_frequency_threshold          => None
+            conflict_minimum_confidence           => 0.0
+            derived_output_minimum_confidence     => 0.0
+            derived_output_minimum_expectation    => 0.0
+            revision_history_length               => 0
+            evidence_overlap_policy               => OverlapPolicy::Strict
+            evidence_interleave_order             => InterleaveOrder::Original
         }
     }
 
@@ -256,6 +256,12 @@ impl BagItemLevel // * 需要在「具体值匹配删除」时用到
         self.0.pop_front();
     }
 
+    /// 🆕移除某个元素
+    /// * 🎯溢出时跳过「被钉住」的元素
+    pub fn remove(&mut self, key: &str) {
+        self.0.retain(|k| k != key);
+    }
+
     /// 从0到「层数」遍历所有元素
     /// * 🎯调试用输出
     pub(super) fn iter(&self) -> impl DoubleEndedIterator<Item = &String> {
@@ -9,6 +9,7 @@ use crate::{
     util::ToDisplayAndBrief,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 // ! 删除「具体类型」特征：能直接`struct`就直接`struct`
 
@@ -509,6 +510,16 @@ impl<E: Item> Bag<E> {
     /// @return Whether the new Item is added into the Bag
     #[must_use]
     pub fn put_in(&mut self, new_item: E) -> Option<E> {
+        self.put_in_pinned(new_item, |_| false)
+    }
+
+    /// 🆕「放入」，但不让「被钉住」的元素溢出
+    /// * 🎯让某些元素（如含有「定义」的概念）常驻袋中
+    /// * 🚩溢出时，从低到高（至多到新元素所在层）找首个未被钉住的元素移除
+    ///   * 📌找不到⇒新元素未被钉住则添加失败；否则仍然添加，此时袋的大小可超出容量
+    /// * 📌`pinned`总返回`false`⇒与[`put_in`](Self::put_in)相同
+    #[must_use]
+    pub fn put_in_pinned(&mut self, new_item: E, pinned: impl Fn(&E) -> bool) -> Option<E> {
         /* String newKey = newItem.getKey();
         E oldItem = nameTable.put(newKey, newItem);
         if (oldItem != null) { // merge duplications
@@ -552,7 +563,7 @@ impl<E: Item> Bag<E> {
         // 置入「层级映射」
         // 若在「层级映射」中溢出了：若有「溢出」则在「元素映射」中移除
         // ! 📌【2024-05-04 11:35:45】↓此处`__into_base`仅传入「元素id」是为了规避借用问题（此时`new_item`已失效）
-        if let Some(overflow_key) = self.item_into_base(&new_key) {
+        if let Some(overflow_key) = self.item_into_base(&new_key, pinned) {
             // 直接返回「根据『溢出的元素之id』在『元素映射』中移除」的结果
             // * 🚩若与自身相同⇒返回`Some`，添加失败
             // * 🚩若与自身不同⇒返回`None`，添加仍然成功
@@ -729,6 +740,15 @@ impl<E: Item> Bag<E> {
         Some(name_value.0)
     }
 
+    /// 🆕挑出所有元素，清空袋
+    /// * 🎯合并记忆区时，逐个转移外来概念及其链接
+    /// * 🚩按「键」逐个挑出，而非按优先级随机取出
+    #[must_use]
+    pub fn pick_out_all(&mut self) -> Vec<E> {
+        let keys = self.iter().map(|item| item.key().clone()).collect::<Vec<_>>();
+        keys.iter().filter_map(|key| self.pick_out(key)).collect()
+    }
+
     /// 模拟`Bag.emptyLevel`
     ///
     /// # 📄OpenNARS
@@ -788,7 +808,8 @@ impl<E: Item> Bag<E> {
     ///
     /// @param newItem The Item to put in
     /// @return The overflow Item
-    fn item_into_base(&mut self, new_key: &str) -> Option<String> {
+    /// * 🆕`pinned`：被钉住的元素不会溢出，见[`put_in_pinned`](Self::put_in_pinned)
+    fn item_into_base(&mut self, new_key: &str, pinned: impl Fn(&E) -> bool) -> Option<String> {
         /* 📄OpenNARS源码：
         E oldItem = null;
         int inLevel = getLevel(newItem);
@@ -810,21 +831,34 @@ impl<E: Item> Bag<E> {
         let new_item = self.get(new_key).expect("不能没有所要获取的值"); // * 🚩🆕（在调用方处）重新获取「置入后的新项」（⚠️一定有）
         let mut old_item = None;
         let in_level = self.calculate_level_for_item(new_item);
+        let new_pinned = pinned(new_item);
 
         // 🆕先假设「新元素已被置入」，「先加后减」防止usize溢出
         self.status.mass += in_level + 1;
         if self.size() > self.capacity() {
             // * 📝逻辑：低优先级溢出——从低到高找到「第一个非空层」然后弹出其中第一个（最先的）元素
             // * 🚩【2024-05-04 13:14:02】实际上与Java代码等同；但若直接按源码来做就会越界
-            let out_level = (0..self.parameters.total_level)
-                .find(|level| !self.empty_level(*level))
-                .unwrap_or(self.parameters.total_level);
-            if out_level > in_level {
+            // * 🆕跳过被钉住的元素
+            let out = (0..=in_level).find_map(|level| {
+                let key = self.level_map.get(level).iter().find(|key| {
+                    let item = self.get(key).expect("层级映射中的元素应在元素映射中");
+                    !pinned(item)
+                })?;
+                Some((level, key.clone()))
+            });
+            match out {
+                Some((out_level, out_key)) => {
+                    self.level_map.get_mut(out_level).remove(&out_key);
+                    self.status.mass -= out_level + 1;
+                    old_item = Some(out_key);
+                }
+                // 被钉住⇒仍然添加
+                None if new_pinned => {}
                 // 若到了自身所在层⇒弹出自身（相当于「添加失败」）
-                self.status.mass -= in_level + 1; // 🆕失败，减去原先相加的数
-                return Some(new_key.to_string()); // 提早返回
-            } else {
-                old_item = self.take_out_first(out_level);
+                None => {
+                    self.status.mass -= in_level + 1; // 🆕失败，减去原先相加的数
+                    return Some(new_key.to_string()); // 提早返回
+                }
             }
         }
         // 继续增加元素
@@ -983,6 +1017,93 @@ impl<E: Item> Bag<E> {
     }
 }
 
+/// 「袋」的增量快照（引用）
+/// * 🎯只序列化「自上次检查点以来变化了的元素」
+/// * 📌层级映射与状态变量总是完整保存：其只存储键与基础类型，体积较小
+///   * ✅由此可在重放时删去「已被移出」的元素，并复现原本的取出顺序
+#[derive(Debug, Serialize)]
+pub struct BagDeltaRef<'b, E: Item> {
+    /// 变化了的元素（连同其所在层级）
+    changed: Vec<&'b NameValue<E>>,
+    /// 完整的层级映射
+    level_map: &'b BagItemTable,
+    /// 完整的状态变量
+    status: &'b BagStatus,
+}
+
+/// 「袋」的增量快照
+/// * 🎯先反序列化到此类型，再由「袋」应用
+#[derive(Debug, Deserialize)]
+pub struct BagDelta<E: Item> {
+    /// 变化了的元素（连同其所在层级）
+    changed: Vec<NameValue<E>>,
+    /// 完整的层级映射
+    level_map: BagItemTable,
+    /// 完整的状态变量
+    status: BagStatus,
+}
+
+impl<E: Item> BagDelta<E> {
+    /// 可变迭代其中所有变化了的元素
+    /// * 🎯应用前归一化其中的「任务共享引用」
+    pub(crate) fn iter_changed_mut(&mut self) -> impl Iterator<Item = &mut E> {
+        self.changed.iter_mut().map(|(item, _)| item)
+    }
+}
+
+/// 有关「增量快照」的方法
+impl<E: Item> Bag<E> {
+    /// 根据「变化了的键」生成增量快照
+    /// * 🚩已不在袋中的键将被忽略：其删除由「层级映射」体现
+    pub fn delta_ref<'b>(&'b self, changed_keys: impl IntoIterator<Item = &'b String>) -> BagDeltaRef<'b, E> {
+        let changed = changed_keys
+            .into_iter()
+            .filter_map(|key| self.item_map.get(key))
+            .collect();
+        BagDeltaRef {
+            changed,
+            level_map: &self.level_map,
+            status: &self.status,
+        }
+    }
+
+    /// 应用增量快照
+    /// * 🚩删除所有不在新「层级映射」中的元素
+    /// * 🚩用变化了的元素覆盖旧有元素
+    /// * 🚩直接覆盖层级映射与状态变量
+    pub fn apply_delta(&mut self, delta: BagDelta<E>) {
+        let BagDelta {
+            changed,
+            level_map,
+            status,
+        } = delta;
+        // 删除已被移出的元素
+        let remaining_keys = level_map
+            .iter()
+            .flat_map(|level| level.iter())
+            .collect::<HashSet<_>>();
+        let removed_keys = self
+            .item_map
+            .iter()
+            .map(|(key, _)| key)
+            .filter(|key| !remaining_keys.contains(key))
+            .cloned()
+            .collect::<Vec<_>>();
+        for key in removed_keys {
+            self.item_map.remove(&key);
+        }
+        // 覆盖变化了的元素
+        for (item, level) in changed {
+            let key = item.key().clone();
+            self.item_map.put(&key, item, level);
+        }
+        // 覆盖层级映射与状态变量
+        self.level_map = level_map;
+        self.status = status;
+        self.assert_valid();
+    }
+}
+
 // 显示呈现方法
 impl<E: Item> ToDisplayAndBrief for Bag<E> {
     fn to_display(&self) -> String {
@@ -1349,4 +1470,60 @@ mod tests {
         // 最后完成
         ok!()
     }
+
+    /// 🆕测试/钉住元素
+    /// * 🎯放入 [`Bag::put_in_pinned`]
+    #[test]
+    fn pinned_item() -> AResult {
+        let mut bag = Bag1::new(2, 1);
+        bag.init();
+        let pinned = |item: &Item1| item.key().starts_with("pinned");
+
+        // 低优先级的钉住元素，先于其它元素放入
+        let low = new_item("pinned_low", 0.1, 0.0, 0.0);
+        let overflowed = bag.put_in_pinned(low, pinned);
+        asserts! {
+            overflowed.is_none(),
+        }
+        let overflowed = bag.put_in_pinned(new_item("item001", 0.5, 0.0, 0.0), pinned);
+        asserts! {
+            overflowed.is_none(),
+        }
+
+        // 溢出：跳过钉住的元素，移除未钉住的
+        let overflowed = bag.put_in_pinned(new_item("item002", 0.9, 0.0, 0.0), pinned);
+        asserts! {
+            overflowed.is_none(),
+            bag.size() == 2,
+            bag.has("pinned_low"),
+            !bag.has("item001"),
+            bag.has("item002"),
+        }
+
+        // 所有低层元素均被钉住⇒未钉住的新元素添加失败
+        let _ = bag.pick_out("item002");
+        let _ = bag.put_in_pinned(new_item("pinned_high", 0.9, 0.0, 0.0), pinned);
+        let overflowed = bag.put_in_pinned(new_item("item003", 0.1, 0.0, 0.0), pinned);
+        asserts! {
+            overflowed.map(|item| item.key().clone()) => Some("item003".to_string()),
+            bag.size() == 2,
+        }
+
+        // 钉住的新元素仍然添加：超出容量
+        let overflowed = bag.put_in_pinned(new_item("pinned_new", 0.1, 0.0, 0.0), pinned);
+        asserts! {
+            overflowed.is_none(),
+            bag.size() == 3,
+        }
+
+        // 不钉住⇒与「放入」一致：移除最低层的元素
+        let overflowed = bag.put_in(new_item("item004", 0.9, 0.0, 0.0));
+        asserts! {
+            overflowed.is_none(),
+            bag.size() == 3,
+            !bag.has("pinned_low"),
+        }
+
+        ok!()
+    }
 }
@@ -6,15 +6,19 @@
 //! * ✅【2024-05-08 17:17:41】目前已初步完成所有方法的模拟
 //! * ♻️【2024-06-24 20:40:08】开始基于改版OpenNARS重写
 
-use super::Bag;
+use super::{Bag, BagDelta, BagDeltaRef};
 use crate::{
     control::prepare_term_link_templates,
-    entity::{BudgetValue, Concept, Item, RCTask},
-    inference::{Budget, BudgetFunctions},
+    entity::{BudgetValue, Concept, Item, JudgementV1, RCTask},
+    global::ClockTime,
+    inference::{Budget, BudgetPolicyRef},
     language::Term,
     parameters::{Parameters, DEFAULT_PARAMETERS},
+    util::{IterInnerRcSelf, RcSerial, Serial, ToDisplayAndBrief},
 };
+use nar_dev_utils::RefCount;
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashSet;
 
 /// 记忆区
 #[derive(Debug, Serialize, Deserialize)]
@@ -33,6 +37,13 @@ pub struct Memory {
     /// * 📌【2024-09-04 10:15:43】目前作为整个「推理器状态」的参数存储
     ///  * ❓TODO: 【2024-09-04 10:17:29】考虑是否特化到「所用参数」并在「推理器状态」中独立存储
     pub(crate) parameters: Parameters,
+
+    /// 🆕自上次「检查点」以来，可能被修改过的概念（的键）
+    /// * 🎯增量快照：只保存变化了的概念
+    /// * 🚩在「可变获取」「放入/放回」概念时标记
+    /// * 📌不参与序列反序列化：加载后的状态即为新的检查点
+    #[serde(skip)]
+    dirty_concepts: HashSet<String>,
 }
 
 impl Memory {
@@ -66,6 +77,8 @@ impl Memory {
             ),
             // * 🚩超参数
             parameters,
+            // * 🚩脏标记
+            dirty_concepts: HashSet::new(),
         }
     }
 
@@ -73,6 +86,7 @@ impl Memory {
     /// * 🚩初始化「概念袋」
     pub fn init(&mut self) {
         self.concepts.init();
+        self.dirty_concepts.clear();
     }
 
     /// # 📄OpenNARS
@@ -86,6 +100,7 @@ impl Memory {
     }
     #[doc(alias = "name_to_concept_mut")]
     pub fn key_to_concept_mut(&mut self, key: &str) -> Option<&mut Concept> {
+        self.mark_dirty(key);
         self.concepts.get_mut(key)
     }
 
@@ -135,8 +150,9 @@ impl Memory {
             prepare_term_link_templates(term),
         );
         let new_key = concept.key().clone();
+        self.mark_dirty(&new_key);
         // * 🚩将新概念放入「记忆区」
-        let old_concept = self.concepts.put_in(concept);
+        let old_concept = self.put_in_concept(concept);
         let make_success = match old_concept {
             None => true,
             Some(old) => old.key() != &new_key,
@@ -164,14 +180,17 @@ impl Memory {
     /// * 🚩实际上也被「直接推理」调用
     /// * 🚩【2024-06-25 01:46:20】此处为了避免「借用冲突」选择靠「词项」而非「概念」查询
     /// * 🚩【2024-06-25 02:03:57】目前因为「激活时需要使用不可变引用，修改时又需要可变引用」改为「返回新预算值」机制
+    /// * 🚩「激活」由传入的「预算策略」计算，「遗忘」仍由记忆区自身的概念袋完成
     #[must_use]
     pub fn activate_concept_calculate(
         &self,
+        budget_policy: BudgetPolicyRef,
         concept: &Concept,
         incoming_budget: &impl Budget,
     ) -> BudgetValue {
         // * 📝先「激活」
-        let mut activated = incoming_budget.activate_to_concept(concept);
+        let mut activated =
+            budget_policy.activate_to_concept(concept, &BudgetValue::from(incoming_budget));
         // * 🚩分「是否已有」判断
         match self.has_concept(concept.term()) {
             // * 🚩已有：只需「激活」 | 后续「放回」将由「袋」自己的机制做
@@ -204,8 +223,19 @@ impl Memory {
     }
 
     /// 🆕对外接口：往「概念袋」放回一个概念
-    pub fn put_back_concept(&mut self, concept: Concept) -> Option<Concept> {
-        self.concepts.put_back(concept)
+    /// * 🆕含有「定义」的概念不被遗忘：原样放入，不衰减优先级
+    pub fn put_back_concept(&mut self, mut concept: Concept) -> Option<Concept> {
+        self.mark_dirty(concept.key());
+        if !concept.has_definition() {
+            self.concepts.forget(&mut concept);
+        }
+        self.put_in_concept(concept)
+    }
+
+    /// 🆕往「概念袋」放入一个概念
+    /// * 🆕含有「定义」的概念被钉住：不因概念袋满而溢出
+    fn put_in_concept(&mut self, concept: Concept) -> Option<Concept> {
+        self.concepts.put_in_pinned(concept, Concept::has_definition)
     }
 
     /// 🆕对外接口：只读迭代内部所有「概念」
@@ -214,6 +244,81 @@ impl Memory {
     }
 }
 
+/// 有关「记忆区合并」的方法
+/// * 🎯将另一个（外来的）记忆区并入自身
+impl Memory {
+    /// 重映射其内所有「证据基」与「任务序列号」
+    /// * 🎯合并前，使外来记忆区的序列号与自身的不冲突
+    /// * 🚩信念、修正历史：直接重映射时间戳
+    /// * 🚩任务：先按旧序列号去重（含各级父任务），逐个重映射，再同步所有共享引用的序列号
+    pub(crate) fn remap_serials(
+        &mut self,
+        remap_evidence: impl Fn(ClockTime) -> ClockTime,
+        remap_serial: impl Fn(Serial) -> Serial,
+    ) {
+        // 信念
+        for concept in self.concepts.iter_mut() {
+            for belief in concept.iter_beliefs_mut() {
+                belief.stamp_mut().remap_evidential_base(&remap_evidence);
+            }
+            for record in concept.iter_revision_history_mut() {
+                record.stamp.remap_evidential_base(&remap_evidence);
+            }
+        }
+        // 收集所有（不重复的）任务，包括「父任务」
+        let mut visited = HashSet::new();
+        let mut tasks = vec![];
+        for task_rc in self.all_task_rcs() {
+            let parents = task_rc.get_().parents().map(|(parent, _)| parent);
+            for rc in std::iter::once(task_rc.clone()).chain(parents) {
+                if visited.insert(rc.get_().rc_serial()) {
+                    tasks.push(rc);
+                }
+            }
+        }
+        // 重映射任务
+        for task_rc in tasks.iter_mut() {
+            task_rc.mut_().remap_serials(&remap_evidence, &remap_serial);
+        }
+        // 同步序列号：「父任务」字段与顶层引用
+        for task_rc in tasks.iter_mut() {
+            task_rc.mut_().iter_inner_rc_self().for_each(RCTask::sync_serial);
+            task_rc.sync_serial();
+        }
+        self.all_task_rcs().for_each(RCTask::sync_serial);
+    }
+
+    /// 取出所有概念，清空概念袋
+    pub(crate) fn take_all_concepts(&mut self) -> Vec<Concept> {
+        self.concepts.pick_out_all()
+    }
+
+    /// 将一个外来概念并入自身
+    /// * 🚩已有同名概念⇒吸收其内容；否则直接放入
+    /// * 📌返回所有「溢出」的信息
+    pub(crate) fn merge_concept(
+        &mut self,
+        incoming: Concept,
+        revise: impl Fn(&JudgementV1, &JudgementV1) -> JudgementV1,
+    ) -> Vec<String> {
+        let (concept, mut overflowed) = match self.pick_out_concept(incoming.key()) {
+            Some(mut existed) => {
+                let overflowed = existed.absorb(incoming, revise);
+                (existed, overflowed)
+            }
+            None => (incoming, vec![]),
+        };
+        self.mark_dirty(concept.key());
+        if let Some(overflowed_concept) = self.put_in_concept(concept) {
+            overflowed.push(format!(
+                "!!! Overflowed Concept: {}",
+                overflowed_concept.to_display_brief()
+            ));
+        }
+        overflowed
+    }
+}
+
 impl Default for Memory {
     fn default() -> Self {
         // * 🚩超参数实现了[`Copy`]
@@ -221,6 +326,68 @@ impl Default for Memory {
     }
 }
 
+/// 有关「增量快照」的方法
+/// * 🎯只保存自上次「检查点」以来变化了的概念
+impl Memory {
+    /// 标记一个概念「可能已被修改」
+    fn mark_dirty(&mut self, key: &str) {
+        // * 🚩先查后插，避免频繁分配字符串
+        if !self.dirty_concepts.contains(key) {
+            self.dirty_concepts.insert(key.to_string());
+        }
+    }
+
+    /// 将所有概念标记为「可能已被修改」
+    /// * 🎯覆盖加载记忆区后，上一个检查点已不再适用
+    pub(crate) fn mark_all_dirty(&mut self) {
+        self.dirty_concepts = self.concepts.iter().map(|c| c.key().clone()).collect();
+    }
+
+    /// 清除所有脏标记，作为新的检查点
+    pub(crate) fn clear_dirty(&mut self) {
+        self.dirty_concepts.clear();
+    }
+
+    /// 生成自上次检查点以来的增量快照
+    pub(crate) fn delta_ref(&self) -> MemoryDeltaRef<'_> {
+        MemoryDeltaRef {
+            concepts: self.concepts.delta_ref(&self.dirty_concepts),
+        }
+    }
+
+    /// 应用增量快照
+    /// * 🚩先归一化「任务共享引用」，再覆盖概念
+    ///   * 📌快照中的任务优先：其为同一序列号下较新的版本
+    pub(crate) fn apply_delta(&mut self, mut delta: MemoryDelta) {
+        let delta_refs = delta
+            .concepts
+            .iter_changed_mut()
+            .flat_map(Concept::iter_tasks_mut);
+        RCTask::unify_rcs(delta_refs.chain(self.all_task_rcs()));
+        delta
+            .concepts
+            .iter_changed_mut()
+            .for_each(Concept::restore_rank_fns);
+        self.concepts.apply_delta(delta.concepts);
+    }
+}
+
+/// 记忆区的增量快照（引用）
+/// * 🎯从记忆区构造引用，并由此序列化
+#[derive(Debug, Serialize)]
+pub(crate) struct MemoryDeltaRef<'m> {
+    /// 概念袋的增量快照
+    concepts: BagDeltaRef<'m, Concept>,
+}
+
+/// 记忆区的增量快照
+/// * 🎯先反序列化到此类型，再让记忆区应用
+#[derive(Debug, Deserialize)]
+pub(crate) struct MemoryDelta {
+    /// 概念袋的增量快照
+    concepts: BagDelta<Concept>,
+}
+
 /// 针对[`serde`]做特殊调整
 /// * 🎯原本需求是「在自动派生之方法的基础上，归一化其中的『任务共享引用』」
 /// * 💡目前实际上「任务共享引用」只存在于「概念袋」中，那为何不在「概念袋」处做优化？
@@ -229,6 +396,7 @@ impl Default for Memory {
 impl Memory {
     /// 反序列化「概念袋」
     /// * 🚩在默认反序列化逻辑上，再加对内部所有「任务共享引用」的归一化处理
+    /// * 🚩同时恢复各概念的「排行策略」
     fn deserialize_concepts<'de, D>(deserializer: D) -> Result<Bag<Concept>, D::Error>
     where
         D: Deserializer<'de>,
@@ -238,6 +406,8 @@ impl Memory {
         // 开始遍历所有「任务共享引用」，并归一化其值
         let all_task_rcs = Self::concept_bag_all_task_rcs(&mut bag);
         RCTask::unify_rcs(all_task_rcs);
+        // 恢复排行函数
+        bag.iter_mut().for_each(Concept::restore_rank_fns);
         // 返回归一化后的概念袋
         Ok(bag)
     }
@@ -474,4 +644,55 @@ pub mod tests_memory {
         }
         ok!()
     }
+
+    /// 信念表的排行策略
+    /// * 🎯不同策略下，满员时淘汰不同的信念
+    /// * 🎯序列反序列化后策略不丢失
+    #[test]
+    fn belief_rank_strategy() -> AResult {
+        use crate::{inference::Evidential, parameters::RankStrategy};
+        /// 按「策略」构造只有两个信念位的概念，并依次加入三个信念
+        /// * 📌信念`(创建时间, 信度)`：新近者信度低
+        fn memory_with(strategy: RankStrategy) -> AResult<Memory> {
+            let parameters = Parameters {
+                belief_rank_strategy: strategy,
+                maximum_belief_length: 2,
+                ..DEFAULT_PARAMETERS
+            };
+            let mut memory = Memory::new(parameters);
+            let content = term!("<A --> B>");
+            let concept = memory.get_concept_or_create(&content).unwrap();
+            for (time, confidence) in [(1, 0.9), (2, 0.5), (3, 0.3)] {
+                let truth = TruthValue::new_fc(ShortFloat::ONE, ShortFloat::from_float(confidence));
+                let stamp = Stamp::new(time, [time]);
+                let belief = JudgementV1::new(content.clone(), truth, stamp, true);
+                let _ = concept.add_belief(belief);
+            }
+            Ok(memory)
+        }
+        /// 获取所有信念的创建时间
+        fn belief_times(memory: &Memory) -> Vec<ClockTime> {
+            let concept = memory.key_to_concept("(A --> B)").unwrap();
+            concept.beliefs().map(Evidential::creation_time).collect()
+        }
+        for (strategy, expected) in [
+            (RankStrategy::Original, [1, 2]),
+            (RankStrategy::Confidence, [1, 2]),
+            (RankStrategy::Recency, [3, 2]),
+        ] {
+            let memory = memory_with(strategy)?;
+            assert_eq_try!(belief_times(&memory), expected, "策略{strategy:?}下排行有误");
+            // 序列反序列化后，再加入新信念
+            let mut de = serde_json::from_str::<Memory>(&serde_json::to_string(&memory)?)?;
+            let concept = de.key_to_concept_mut("(A --> B)").unwrap();
+            let truth = TruthValue::new_fc(ShortFloat::ONE, ShortFloat::from_float(0.1));
+            let belief = JudgementV1::new(term!("<A --> B>"), truth, Stamp::new(4, [4]), true);
+            let overflowed = concept.add_belief(belief);
+            match strategy {
+                RankStrategy::Recency => assert!(overflowed.is_some_and(|b| b.creation_time() == 2)),
+                _ => assert!(overflowed.is_some_and(|b| b.creation_time() == 4)),
+            }
+        }
+        ok!()
+    }
 }
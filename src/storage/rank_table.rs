@@ -216,6 +216,15 @@ impl<T> ArrayRankTable<T> {
         self.is_compatible_to_add_f = is_compatible_to_add_f;
     }
 
+    /// 🆕按排行函数重新排序
+    /// * 🎯排行依据可变时（如「问题」的最优解），在其变化后恢复「从高到低」的顺序
+    /// * 🚩稳定排序：排行相同者保持原有先后
+    pub fn re_rank(&mut self) {
+        let rank_f = self.rank_f;
+        self.inner
+            .sort_by(|a, b| Float::total_cmp(&rank_f(b), &rank_f(a)));
+    }
+
     /// 用于序列化产生「空白函数」
     pub fn default_rank_f() -> RankF<T> {
         /// 🚩【2024-08-11 22:37:05】目前将默认返回值定位为「默认panic」
@@ -265,4 +274,18 @@ mod tests {
 
         ok!()
     }
+
+    #[test]
+    fn re_rank() {
+        use std::cell::Cell;
+        // * 🚩排行依据可变：插入后再修改
+        let mut table = ArrayRankTable::new(10, |item: &Cell<i32>| item.get() as Float, |_, _| true);
+        for i in [3, 2, 1] {
+            table.add(Cell::new(i));
+        }
+        table.iter().last().unwrap().set(4);
+        table.re_rank();
+        let items = table.iter().map(Cell::get).collect::<Vec<_>>();
+        assert_eq!(items, [4, 3, 2]);
+    }
 }
@@ -54,6 +54,12 @@ pub struct TaskBuffer {
     /// 🆕相关的「参数变量」
     #[serde(default)] // 🎯向下兼容旧有序列反序列化机制
     parameters: TaskBufferParameters,
+
+    /// 🆕自上次「检查点」以来是否可能被修改过
+    /// * 🎯增量快照：未变化时不保存
+    /// * 📌不参与序列反序列化：加载后的状态即为新的检查点
+    #[serde(skip)]
+    dirty: bool,
 }
 
 /// 🆕有关「任务缓冲区」的参数变量
@@ -101,6 +107,7 @@ impl TaskBuffer {
                 parameters,
             ),
             parameters: TaskBufferParameters::new(parameters),
+            dirty: false,
         }
     }
     /// 重置推理导出数据
@@ -108,6 +115,7 @@ impl TaskBuffer {
     pub fn reset(&mut self) {
         self.new_tasks.clear();
         self.novel_tasks.init();
+        self.dirty = true;
     }
 }
 
@@ -117,25 +125,30 @@ impl TaskBuffer {
 impl TaskBuffer {
     /// 向「新任务队列」中添加一个任务
     fn add_new_task(&mut self, task: Task) {
+        self.dirty = true;
         self.new_tasks.push_back(task);
     }
 
     /// 从「新任务」中拿出（第）一个任务
     #[must_use]
     fn pop_new_task(&mut self) -> Option<Task> {
-        self.new_tasks.pop_front()
+        let task = self.new_tasks.pop_front();
+        self.dirty |= task.is_some();
+        task
     }
 
     /// 将一个任务放进「新近任务袋」
     /// * 🚩同时返回「溢出的新近任务」
     #[must_use]
     fn put_in_novel_tasks(&mut self, task: Task) -> Option<Task> {
+        self.dirty = true;
         self.novel_tasks.put_in(task)
     }
 
     /// 从「新近任务袋」拿出一个任务
     #[must_use]
     fn take_a_novel_task(&mut self) -> Option<Task> {
+        self.dirty = true;
         self.novel_tasks.take_out()
     }
 }
@@ -357,6 +370,19 @@ impl TaskBuffer {
     }
 }
 
+/// 用于「增量快照」的功能
+impl TaskBuffer {
+    /// 自上次检查点以来是否可能被修改过
+    pub(crate) fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// 清除脏标记，作为新的检查点
+    pub(crate) fn clear_dirty(&mut self) {
+        self.dirty = false;
+    }
+}
+
 /// 测试用方法
 #[cfg(test)]
 pub(crate) mod tests_task_buffer {
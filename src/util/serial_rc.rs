@@ -59,7 +59,8 @@ impl<T: RcSerial> SerialRef<T> {
     /// 同步化
     /// * 🚩将自身的序列号变为内部内容的指针地址
     ///   * 📝后者不会因为引用的拷贝而改变
-    fn sync_serial(&mut self) {
+    /// * 🎯亦用于「记忆区合并」中，内容序列号被重映射之后
+    pub(crate) fn sync_serial(&mut self) {
         self.serial = self.inner_serial();
     }
 }
@@ -0,0 +1,398 @@
+//! 🆕后台自动步进的运行时
+//! * 🎯让推理器在两次输入之间持续「思考」
+//!   * 📄交互式演示、智能体：无需外部不断发送`CYC`指令
+//! * 🚩在工作线程中持有一个[`RuntimeAlpha`]，持续运行工作周期
+//!   * 📌每个工作周期前，处理一次所有输入通道（含外部追加的通道，如[文件跟踪](super::FileTailInputChannel)）
+//!   * 📌输入输出经由线程间通道并发传递
+//! * ℹ️推理器内部使用非线程安全的共享引用，故运行时只能在工作线程中创建
+//!   * 🚩由[启动器](LauncherAlpha)在工作线程中启动
+//!
+//! ## 控制
+//!
+//! * 📍方法：[`pause`](RuntimeAlphaAuto::pause)、[`resume`](RuntimeAlphaAuto::resume)、[`set_throttle`](RuntimeAlphaAuto::set_throttle)
+//! * 📍自定义NAVM指令（不区分大小写）
+//!   * `PAUSE`：暂停自动步进（仍处理输入）
+//!   * `RESUME`：恢复自动步进
+//!   * `THROTTLE <每秒周期数>`：限速；`THROTTLE 0`⇒不限速
+//!     * ⚠️低于[下限](MIN_CYCLES_PER_SECOND)⇒报错，不改变限速
+
+use super::{LauncherAlpha, RuntimeAlpha};
+use anyhow::{anyhow, Result};
+use navm::{
+    cmd::Cmd,
+    output::Output,
+    vm::{VmLauncher, VmRuntime, VmStatus},
+};
+use std::{
+    sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender, TryRecvError},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+/// 工作线程已结束时的报错
+const WORKER_STOPPED: &str = "Auto-step worker thread has stopped";
+
+/// 限速的下限：每秒周期数
+/// * 🎯避免周期间隔过长，无法表示为时间
+/// * 📌约每十二天一个周期
+pub const MIN_CYCLES_PER_SECOND: f64 = 1e-6;
+
+/// 自动步进的配置
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AutoStepConfig {
+    /// 限速：每秒周期数
+    /// * 📌`None`⇒不限速
+    pub cycles_per_second: Option<f64>,
+    /// 是否以「暂停」状态启动
+    pub start_paused: bool,
+}
+
+/// 发往工作线程的控制消息
+#[derive(Debug)]
+enum Control {
+    /// 输入NAVM指令
+    Cmd(Cmd),
+    /// 暂停自动步进
+    Pause,
+    /// 恢复自动步进
+    Resume,
+    /// 设置限速
+    Throttle(Option<f64>),
+    /// 报告错误
+    Error(String),
+    /// 结束工作线程
+    Stop,
+}
+
+impl Control {
+    /// 从NAVM指令解释控制消息
+    /// * 🚩识别自定义指令`PAUSE`、`RESUME`、`THROTTLE`
+    fn from_cmd(cmd: Cmd) -> Self {
+        let Cmd::Custom { head, tail } = &cmd else {
+            return Self::Cmd(cmd);
+        };
+        match head.to_uppercase().as_str() {
+            "PAUSE" => Self::Pause,
+            "RESUME" => Self::Resume,
+            "THROTTLE" => match tail.trim().parse::<f64>() {
+                Ok(cps) => match normalize_throttle(Some(cps)) {
+                    Ok(cps) => Self::Throttle(cps),
+                    Err(e) => Self::Error(e.to_string()),
+                },
+                // * 🚩解析失败⇒交给运行时报错
+                Err(..) => Self::Cmd(cmd),
+            },
+            _ => Self::Cmd(cmd),
+        }
+    }
+}
+
+/// 规范化限速
+/// * 🚩非正数的限速视作「不限速」
+/// * 🚩低于[下限](MIN_CYCLES_PER_SECOND)⇒报错
+pub fn normalize_throttle(cycles_per_second: Option<f64>) -> Result<Option<f64>> {
+    match cycles_per_second.filter(|cps| *cps > 0.0) {
+        Some(cps) if cps < MIN_CYCLES_PER_SECOND => Err(anyhow!(
+            "Cycles per second {cps} is below the minimum {MIN_CYCLES_PER_SECOND}"
+        )),
+        cps => Ok(cps),
+    }
+}
+
+/// 后台自动步进的运行时
+/// * 🚩作为工作线程的「句柄」：发送输入，接收输出
+/// * 📌丢弃时结束工作线程
+#[derive(Debug)]
+pub struct RuntimeAlphaAuto {
+    /// 发往工作线程的控制消息
+    control: Sender<Control>,
+    /// 来自工作线程的输出
+    outputs: Receiver<Output>,
+    /// 工作线程
+    worker: Option<JoinHandle<()>>,
+}
+
+impl RuntimeAlphaAuto {
+    /// 启动工作线程
+    /// * 🚩`setup`：在工作线程中，于启动后调用一次
+    ///   * 🎯添加额外的输入输出通道
+    pub fn spawn(
+        launcher: LauncherAlpha,
+        config: AutoStepConfig,
+        setup: impl FnOnce(&mut RuntimeAlpha) + Send + 'static,
+    ) -> Self {
+        let (control, control_receiver) = channel();
+        let (output_sender, outputs) = channel();
+        let worker = thread::spawn(move || {
+            // * 🚩启动器不会失败
+            let Ok(mut runtime) = launcher.launch() else {
+                return;
+            };
+            setup(&mut runtime);
+            auto_step(runtime, config, control_receiver, output_sender)
+        });
+        Self {
+            control,
+            outputs,
+            worker: Some(worker),
+        }
+    }
+
+    /// 发送控制消息
+    fn send(&self, control: Control) -> Result<()> {
+        self.control
+            .send(control)
+            .map_err(|_| anyhow!(WORKER_STOPPED))
+    }
+
+    /// 暂停自动步进
+    /// * 📌暂停期间仍处理输入
+    pub fn pause(&self) -> Result<()> {
+        self.send(Control::Pause)
+    }
+
+    /// 恢复自动步进
+    pub fn resume(&self) -> Result<()> {
+        self.send(Control::Resume)
+    }
+
+    /// 设置限速：每秒周期数
+    /// * 📌`None`或非正数⇒不限速
+    /// * ⚠️低于[下限](MIN_CYCLES_PER_SECOND)⇒报错
+    pub fn set_throttle(&self, cycles_per_second: Option<f64>) -> Result<()> {
+        self.send(Control::Throttle(normalize_throttle(cycles_per_second)?))
+    }
+
+    /// 结束工作线程，并等待其退出
+    /// * 📌此前输入所产生的输出仍可取出
+    fn stop(&mut self) {
+        let _ = self.control.send(Control::Stop);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for RuntimeAlphaAuto {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// 工作线程的主循环
+/// * 🚩处理所有控制消息⇒（未暂停时）运行一个工作周期⇒转发所有输出⇒限速
+/// * 🚩暂停时阻塞等待控制消息，不占用CPU
+/// * 🚩收到结束消息⇒先转发已产生的输出，再结束
+fn auto_step(
+    mut runtime: RuntimeAlpha,
+    config: AutoStepConfig,
+    controls: Receiver<Control>,
+    outputs: Sender<Output>,
+) {
+    let mut paused = config.start_paused;
+    // * 🚩配置的限速无效⇒报错，不限速
+    let mut throttle = normalize_throttle(config.cycles_per_second).unwrap_or_else(|e| {
+        let _ = outputs.send(Output::ERROR {
+            description: e.to_string(),
+        });
+        None
+    });
+    let mut next_cycle = Instant::now();
+    loop {
+        // * 🚩收取控制消息
+        let mut pending = vec![];
+        match paused {
+            // 暂停⇒阻塞等待
+            true => match controls.recv() {
+                Ok(control) => pending.push(control),
+                Err(..) => return,
+            },
+            // 运行⇒等待到下一周期
+            false => match controls.recv_timeout(next_cycle.saturating_duration_since(Instant::now())) {
+                Ok(control) => pending.push(control),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            },
+        }
+        loop {
+            match controls.try_recv() {
+                Ok(control) => pending.push(control),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return,
+            }
+        }
+        // * 🚩处理控制消息
+        let mut stopping = false;
+        for control in pending {
+            match control {
+                Control::Cmd(cmd) => {
+                    let _ = VmRuntime::input_cmd(&mut runtime, cmd);
+                }
+                Control::Pause => paused = true,
+                Control::Resume => {
+                    paused = false;
+                    next_cycle = Instant::now();
+                }
+                Control::Throttle(cps) => {
+                    throttle = cps;
+                    next_cycle = Instant::now();
+                }
+                Control::Error(description) => {
+                    let _ = outputs.send(Output::ERROR { description });
+                }
+                Control::Stop => {
+                    stopping = true;
+                    break;
+                }
+            }
+        }
+        // * 🚩运行一个工作周期（其间亦处理所有输入通道）
+        if !paused && !stopping && Instant::now() >= next_cycle {
+            let _ = VmRuntime::input_cmd(&mut runtime, Cmd::CYC(1));
+            // * 🚩限速：落后至多一秒，避免长时间暂停后集中补上周期
+            // * ⚠️`Instant`可能无法表示一秒之前（如刚开机时）⇒不设下限
+            next_cycle = match throttle {
+                Some(cps) => {
+                    let floor = Instant::now().checked_sub(Duration::from_secs(1));
+                    floor.map_or(next_cycle, |floor| next_cycle.max(floor))
+                        + Duration::from_secs_f64(1.0 / cps)
+                }
+                None => Instant::now(),
+            };
+        }
+        // * 🚩转发输出
+        while let Ok(Some(output)) = runtime.try_fetch_output() {
+            // * 🚩句柄已被丢弃⇒结束
+            if outputs.send(output).is_err() {
+                return;
+            }
+        }
+        if stopping {
+            return;
+        }
+    }
+}
+
+/// 实现[虚拟机运行时](VmRuntime)
+impl VmRuntime for RuntimeAlphaAuto {
+    /// 发送指令到工作线程
+    /// * 🚩自定义指令`PAUSE` `RESUME` `THROTTLE`⇒控制自动步进
+    fn input_cmd(&mut self, cmd: Cmd) -> Result<()> {
+        self.send(Control::from_cmd(cmd))
+    }
+
+    /// 阻塞等待下一个输出
+    fn fetch_output(&mut self) -> Result<Output> {
+        self.outputs
+            .recv()
+            .map_err(|_| anyhow!(WORKER_STOPPED))
+    }
+
+    fn try_fetch_output(&mut self) -> Result<Option<Output>> {
+        Ok(self.outputs.try_recv().ok())
+    }
+
+    fn status(&self) -> &VmStatus {
+        &VmStatus::Running
+    }
+
+    fn terminate(&mut self) -> Result<()> {
+        self.stop();
+        Ok(())
+    }
+}
+
+impl LauncherAlpha {
+    /// 以「后台自动步进」模式启动
+    /// * 📌不同于[`VmLauncher::launch`]：推理器在工作线程中持续运行
+    pub fn launch_auto_step(self, config: AutoStepConfig) -> RuntimeAlphaAuto {
+        RuntimeAlphaAuto::spawn(self, config, |_| {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{inference::InferenceEngine, ok, parameters::DEFAULT_PARAMETERS, util::AResult};
+
+    fn launcher() -> LauncherAlpha {
+        LauncherAlpha::new("test", DEFAULT_PARAMETERS, InferenceEngine::VOID)
+    }
+
+    /// 查询推理器时钟
+    /// * 🚩借由`INF summary`的输出，取其中「当前时间」一行
+    fn fetch_clock(runtime: &mut RuntimeAlphaAuto) -> AResult<String> {
+        runtime.input_cmd(Cmd::INF {
+            source: "summary".into(),
+        })?;
+        loop {
+            if let Output::INFO { message } = runtime.fetch_output()? {
+                if let Some(line) = message.lines().find(|line| line.contains("current time")) {
+                    return Ok(line.to_string());
+                }
+            }
+        }
+    }
+
+    /// 等待推理器时钟变化
+    /// * 🚩反复查询，直至时钟不同于`old`
+    /// * ⚠️只设宽松的超时，以免在繁忙的机器上误报
+    fn wait_clock_changed(runtime: &mut RuntimeAlphaAuto, old: &str) -> AResult<String> {
+        let deadline = Instant::now() + Duration::from_secs(30);
+        loop {
+            let clock = fetch_clock(runtime)?;
+            if clock != old {
+                return Ok(clock);
+            }
+            assert!(Instant::now() < deadline, "推理器未自动步进");
+            thread::yield_now();
+        }
+    }
+
+    /// 过低的限速⇒报错，工作线程仍然运行
+    #[test]
+    fn throttle_too_low() -> AResult {
+        let mut runtime = launcher().launch_auto_step(AutoStepConfig::default());
+        assert!(runtime.set_throttle(Some(1e-20)).is_err());
+        runtime.input_cmd(Cmd::parse("THROTTLE 1e-20")?)?;
+        assert!(matches!(runtime.fetch_output()?, Output::ERROR { .. }));
+        // 仍然步进，并回应输入
+        let clock = fetch_clock(&mut runtime)?;
+        wait_clock_changed(&mut runtime, &clock)?;
+        runtime.input_cmd(Cmd::parse("NSE <A --> B>.")?)?;
+        while !matches!(runtime.fetch_output()?, Output::IN { .. }) {}
+        // 极低但有效的限速：不令工作线程崩溃
+        runtime.input_cmd(Cmd::parse(&format!("THROTTLE {MIN_CYCLES_PER_SECOND}"))?)?;
+        fetch_clock(&mut runtime)?;
+        ok!()
+    }
+
+    /// 🚩不依赖计时：控制消息按序处理，暂停时工作线程只等待消息
+    #[test]
+    fn auto_step() -> AResult {
+        let config = AutoStepConfig {
+            cycles_per_second: None,
+            start_paused: true,
+        };
+        let mut runtime = launcher().launch_auto_step(config);
+        // 暂停时不步进
+        runtime.input_cmd(Cmd::parse("NSE <A --> B>.")?)?;
+        assert!(matches!(runtime.fetch_output()?, Output::IN { .. }));
+        let paused = fetch_clock(&mut runtime)?;
+        runtime.input_cmd(Cmd::parse("NSE <B --> C>.")?)?;
+        assert_eq!(paused, fetch_clock(&mut runtime)?);
+        // 恢复后自动步进
+        runtime.input_cmd(Cmd::parse("RESUME")?)?;
+        let running = wait_clock_changed(&mut runtime, &paused)?;
+        // 再次暂停：暂停之后的查询结果不再变化
+        runtime.pause()?;
+        let paused = fetch_clock(&mut runtime)?;
+        assert_ne!(running, paused);
+        runtime.input_cmd(Cmd::parse("NSE <C --> D>.")?)?;
+        assert_eq!(paused, fetch_clock(&mut runtime)?);
+        runtime.terminate()?;
+        // 工作线程结束后报错
+        assert!(runtime.pause().is_err());
+        ok!()
+    }
+}
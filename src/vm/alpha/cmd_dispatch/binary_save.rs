@@ -0,0 +1,138 @@
+//! 「二进制存档」的编解码
+//! * 🎯为`SAV`/`LOA`提供比JSON更紧凑的存档格式
+//! * 📌数据格式：`base64(文件头 + 数据)`
+//!   * 📍文件头：魔数[`MAGIC`] + 格式版本[`FORMAT_VERSION`] + 压缩方式[`Compression`]
+//!   * 📍数据：CBOR编码（可选地经过压缩）
+//!   * ℹ️因NAVM输出为文本消息，故最外层用base64编码
+//! * 📌「任务共享引用」仍通过「序列号」在加载时归一化，与JSON存档一致
+//! * ⚠️需要启用特性`binary_save`；否则所有编解码均报错
+
+use crate::{
+    control::{Reasoner, ReasonerStatusData},
+    storage::Memory,
+};
+use anyhow::Result;
+
+/// 文件头中的魔数
+/// * 🎯识别「是否为本程序的二进制存档」
+#[cfg(feature = "binary_save")]
+const MAGIC: &[u8] = b"NARUST158";
+
+/// 当前的二进制存档格式版本
+/// * 🚩格式不兼容地变化时递增
+/// * 📌加载时版本不一致⇒报错，而非尝试加载
+#[cfg(feature = "binary_save")]
+const FORMAT_VERSION: u8 = 1;
+
+/// 二进制存档的压缩方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// 不压缩
+    None = 0,
+    /// DEFLATE压缩（zlib格式）
+    Deflate = 1,
+}
+
+#[cfg(feature = "binary_save")]
+impl Compression {
+    /// 从文件头中的标记解析
+    fn from_flag(flag: u8) -> Result<Self> {
+        match flag {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Deflate),
+            _ => Err(anyhow::anyhow!("Unknown compression flag in header: {flag}")),
+        }
+    }
+}
+
+/// 将记忆区编码为二进制存档
+pub fn memory_to_binary(reasoner: &Reasoner, compression: Compression) -> Result<String> {
+    encode(reasoner.memory(), compression)
+}
+
+/// 将「推理器状态」编码为二进制存档
+pub fn status_to_binary(reasoner: &Reasoner, compression: Compression) -> Result<String> {
+    encode(&reasoner.status_serializable(), compression)
+}
+
+/// 从二进制存档解码出记忆区
+/// * ✅记忆区反序列化时已做引用归一化
+pub fn memory_from_binary(data: impl AsRef<str>) -> Result<Memory> {
+    decode(data.as_ref())
+}
+
+/// 从二进制存档解码出「推理器状态」
+/// * ℹ️引用归一化在[加载](Reasoner::load_status_data)时进行
+pub fn status_from_binary(data: impl AsRef<str>) -> Result<ReasonerStatusData> {
+    decode(data.as_ref())
+}
+
+/// 编码：CBOR ⇒ 压缩 ⇒ 加文件头 ⇒ base64
+#[cfg(feature = "binary_save")]
+fn encode(value: &impl serde::Serialize, compression: Compression) -> Result<String> {
+    use base64::Engine;
+    use std::io::Write;
+    // 文件头
+    let mut bytes = MAGIC.to_vec();
+    bytes.push(FORMAT_VERSION);
+    bytes.push(compression as u8);
+    // 数据
+    match compression {
+        Compression::None => ciborium::into_writer(value, &mut bytes)?,
+        Compression::Deflate => {
+            let mut encoder = flate2::write::ZlibEncoder::new(bytes, flate2::Compression::best());
+            ciborium::into_writer(value, &mut encoder)?;
+            encoder.flush()?;
+            bytes = encoder.finish()?;
+        }
+    }
+    Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
+}
+
+/// 解码：base64 ⇒ 校验文件头 ⇒ 解压 ⇒ CBOR
+#[cfg(feature = "binary_save")]
+fn decode<T: serde::de::DeserializeOwned>(data: &str) -> Result<T> {
+    use base64::Engine;
+    let data = data.trim();
+    // * 🚩提前识别JSON存档，给出更明确的提示
+    if data.starts_with('{') {
+        return Err(anyhow::anyhow!(
+            "Data looks like a JSON save; load it with the JSON target instead"
+        ));
+    }
+    let bytes = base64::engine::general_purpose::STANDARD.decode(data)?;
+    // 文件头
+    let body = bytes
+        .strip_prefix(MAGIC)
+        .ok_or_else(|| anyhow::anyhow!("Not a binary save: missing header"))?;
+    let [version, flag, body @ ..] = body else {
+        return Err(anyhow::anyhow!("Not a binary save: truncated header"));
+    };
+    if *version != FORMAT_VERSION {
+        return Err(anyhow::anyhow!(
+            "Unsupported binary save format version {version} (expected {FORMAT_VERSION})"
+        ));
+    }
+    // 数据
+    let value = match Compression::from_flag(*flag)? {
+        Compression::None => ciborium::from_reader(body)?,
+        Compression::Deflate => ciborium::from_reader(flate2::read::ZlibDecoder::new(body))?,
+    };
+    Ok(value)
+}
+
+/// 未启用特性时的替代：总是报错
+#[cfg(not(feature = "binary_save"))]
+fn encode(_value: &impl serde::Serialize, _compression: Compression) -> Result<String> {
+    Err(anyhow::anyhow!(
+        "Binary save is disabled: enable feature `binary_save`"
+    ))
+}
+
+/// 未启用特性时的替代：总是报错
+#[cfg(not(feature = "binary_save"))]
+fn decode<T: serde::de::DeserializeOwned>(_data: &str) -> Result<T> {
+    Err(anyhow::anyhow!(
+        "Binary save is disabled: enable feature `binary_save`"
+    ))
+}
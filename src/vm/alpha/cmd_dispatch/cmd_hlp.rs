@@ -38,6 +38,7 @@ const CMD_INF: &str = "# cmd `INF`
   - `beliefs`: Beliefs in memory
   - `questions`: Questions in memory
   - `summary`: The summary of status of reasoner, no detailed mode yet
+  - `revisions`: Revision history of beliefs in each concept, no detailed mode yet
 ";
 
 /// 有关「示例输入」的帮助
@@ -52,6 +53,14 @@ CYC 10
 INF tasks
 ```
 
+## Inputting definitions
+Definitions are judgements that are never revised or forgotten.
+In inference, their confidence is replaced by the reasoner's `reliance`.
+Narsese scripts saved by `SAV narsese` keep them as `DEF` lines.
+```navm-cmd
+DEF <square --> rectangle>.
+```
+
 ## Comments
 ```navm-cmd
 REM This is a comment, it will be ignored
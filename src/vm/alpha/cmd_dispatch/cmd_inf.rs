@@ -33,6 +33,7 @@ pub fn inf_dispatch(reasoner: &mut Reasoner, query: impl AsRef<str>) -> Result<S
         "concepts" => reasoner.report_concepts()     // 推理器中所有概念
         "links" => reasoner.report_links()           // 推理器中所有链接
         "summary" => reasoner.report_summary()       // 推理器中所有链接
+        "revisions" => reasoner.report_revisions()   // 🆕推理器中所有信念修正历史
 
         // * 🚩更详尽的信息
         "#parameters" => reasoner.report_parameters_detailed() // 具有缩进层级
@@ -1,3 +1,4 @@
+use super::binary_save::{memory_from_binary, status_from_binary};
 use crate::{control::Reasoner, storage::Memory};
 use nar_dev_utils::macro_once;
 
@@ -5,6 +6,9 @@ use nar_dev_utils::macro_once;
 /// * 🎯用于在测试用例中重用
 const MESSAGE_MEMORY_LOAD_SUCCESS: &str = "Memory loading success";
 const MESSAGE_STATUS_LOAD_SUCCESS: &str = "Status loading success";
+const MESSAGE_MEMORY_MERGE_SUCCESS: &str = "Memory merging success";
+const MESSAGE_DELTA_LOAD_SUCCESS: &str = "Status delta loading success";
+const MESSAGE_REPLAY_SUCCESS: &str = "Status replaying success";
 
 /// 指令[`Cmd::LOA`]的入口函数
 /// * 📌传入的`query`默认为小写字串引用
@@ -40,6 +44,31 @@ pub fn loa_dispatch(
             reasoner.load_status_from_json(data).as_ref().map_err(ToString::to_string)?;
             MESSAGE_STATUS_LOAD_SUCCESS
         }
+        // 记忆区（二进制存档，自动识别压缩方式）
+        "memory_cbor" => {
+            reasoner.load_memory_from_binary(data).as_ref().map_err(ToString::to_string)?;
+            MESSAGE_MEMORY_LOAD_SUCCESS
+        }
+        // 推理器整体状态（二进制存档，自动识别压缩方式）
+        "status_cbor" => {
+            reasoner.load_status_from_binary(data).as_ref().map_err(ToString::to_string)?;
+            MESSAGE_STATUS_LOAD_SUCCESS
+        }
+        // 合并记忆区（不覆盖）
+        "merge" => {
+            reasoner.merge_memory_from_json(data).as_ref().map_err(ToString::to_string)?;
+            MESSAGE_MEMORY_MERGE_SUCCESS
+        }
+        // 推理器状态的增量快照：在当前状态上应用
+        "delta" => {
+            reasoner.load_status_delta_from_json(data).as_ref().map_err(ToString::to_string)?;
+            MESSAGE_DELTA_LOAD_SUCCESS
+        }
+        // 重放：基础快照 + 一系列增量快照
+        "replay" => {
+            reasoner.replay_status_from_json(data).as_ref().map_err(ToString::to_string)?;
+            MESSAGE_REPLAY_SUCCESS
+        }
     }
 }
 
@@ -63,6 +92,68 @@ impl Reasoner {
         self.load_from_deserialized_status(&mut deserializer_json)?;
         Ok(())
     }
+
+    /// 从二进制存档加载记忆区
+    /// * ⚠️覆盖自身原本的「记忆区」
+    fn load_memory_from_binary(&mut self, data: impl AsRef<str>) -> anyhow::Result<Memory> {
+        let memory = memory_from_binary(data)?;
+        let old_memory = self.load_memory(memory);
+        Ok(old_memory)
+    }
+
+    /// 从二进制存档加载状态
+    /// * ⚠️覆盖自身原本数据
+    fn load_status_from_binary(&mut self, data: impl AsRef<str>) -> anyhow::Result<()> {
+        let status = status_from_binary(data)?;
+        self.load_status_data(status);
+        Ok(())
+    }
+
+    /// 从JSON合并记忆区
+    /// * 🚩将JSON中的记忆区并入自身，而非覆盖
+    fn merge_memory_from_json(&mut self, data: impl AsRef<str>) -> anyhow::Result<()> {
+        let memory = serde_json::from_str(data.as_ref())?;
+        self.merge_memory(memory);
+        Ok(())
+    }
+
+    /// 从JSON加载状态的增量快照
+    /// * ⚠️须在对应的基础快照之上应用
+    fn load_status_delta_from_json(&mut self, data: impl AsRef<str>) -> anyhow::Result<()> {
+        let mut deserializer_json = serde_json::Deserializer::from_str(data.as_ref());
+        self.load_from_deserialized_status_delta(&mut deserializer_json)?;
+        Ok(())
+    }
+
+    /// 从JSON重放状态
+    /// * 📌格式：多个依次拼接的JSON值（可用空白分隔）
+    ///   * 首个为基础快照（`SAV status`的数据）
+    ///   * 其后为增量快照（`SAV delta`的数据），按保存顺序排列
+    /// * 🎯可直接拼接各次保存所得的文件
+    fn replay_status_from_json(&mut self, data: impl AsRef<str>) -> anyhow::Result<()> {
+        use serde::de::IgnoredAny;
+        let data = data.as_ref();
+        // 先切分出各个JSON值：只检查语法，不构造数据
+        let mut stream = serde_json::Deserializer::from_str(data).into_iter::<IgnoredAny>();
+        let mut slices = vec![];
+        let mut start = 0;
+        while let Some(value) = stream.next() {
+            value?;
+            let end = stream.byte_offset();
+            slices.push(&data[start..end]);
+            start = end;
+        }
+        // 再依次加载
+        let mut slices = slices.into_iter();
+        let base = slices
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Missing base status to replay"))?;
+        self.load_status_from_json(base)?;
+        for delta in slices {
+            self.load_status_delta_from_json(delta)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -73,9 +164,11 @@ mod tests {
         inference::{
             match_task_and_belief, process_direct, reason, transform_task, InferenceEngine,
         },
+        language::Term,
         ok,
         parameters::DEFAULT_PARAMETERS,
         storage::tests_memory::{memory_consistent, memory_synced, GetMemory},
+        test_term as term,
         util::AResult,
         vm::alpha::{RuntimeAlpha, SavCallback},
     };
@@ -439,6 +532,211 @@ mod tests {
         ok!()
     }
 
+    /// 二进制存档的保存与加载
+    /// * 🎯与JSON存档等价：状态一致，共享引用归一化
+    /// * 🎯压缩后更小；非法数据报错而不覆盖
+    #[test]
+    #[cfg(feature = "binary_save")]
+    fn load_status_from_binary() -> AResult {
+        let vm_old = vm_after_inputs(SAMPLE_INPUTS);
+        let mut vm = vm_after_inputs(SAMPLE_INPUTS);
+        let json = save_xxx_by_cmd(&mut vm, "status", "");
+        let cbor = save_xxx_by_cmd(&mut vm, "status_cbor", "");
+        let deflate = save_xxx_by_cmd(&mut vm, "status_cbor_deflate", "");
+        assert!(deflate.len() < cbor.len(), "压缩后未变小");
+        assert!(deflate.len() < json.len(), "压缩后仍比JSON大");
+
+        // 加载到新的推理器中：不论是否压缩，都应一致
+        for data in [cbor, deflate] {
+            let mut vm2 = default_vm();
+            load_xxx_by_cmd(
+                &mut vm2,
+                "status_cbor",
+                data,
+                "推理器状态",
+                MESSAGE_STATUS_LOAD_SUCCESS,
+            );
+            status_synced(&vm2);
+            status_consistent(&vm_old, &vm2)?;
+        }
+
+        // 记忆区同理
+        let memory = save_xxx_by_cmd(&mut vm, "memory_cbor_deflate", "");
+        let mut vm3 = default_vm();
+        load_xxx_by_cmd(
+            &mut vm3,
+            "memory_cbor",
+            memory,
+            "记忆区",
+            MESSAGE_MEMORY_LOAD_SUCCESS,
+        );
+        memory_synced(&vm3);
+        memory_consistent(&vm_old, &vm3)?;
+
+        // 非法数据：JSON、未知版本
+        assert!(vm3.reasoner.load_status_from_binary(&json).is_err());
+        let unknown_version = {
+            use base64::Engine;
+            let engine = base64::engine::general_purpose::STANDARD;
+            let mut bytes = b"NARUST158".to_vec();
+            bytes.extend([u8::MAX, 0]);
+            engine.encode(bytes)
+        };
+        let error = vm3.reasoner.load_status_from_binary(unknown_version);
+        assert!(error.is_err_and(|e| e.to_string().contains("version")));
+        memory_consistent(&vm_old, &vm3)?;
+        ok!()
+    }
+
+    /// 导出Narsese脚本，并重新输入
+    /// * 🎯重新输入后，原有的信念与问题均应存在
+    #[test]
+    fn reinput_exported_narsese() -> AResult {
+        let mut vm = vm_after_inputs(SAMPLE_INPUTS);
+        let script = save_xxx_by_cmd(&mut vm, "narsese_with_questions", "");
+        let beliefs_only = save_xxx_by_cmd(&mut vm, "narsese", "");
+        assert!(!beliefs_only.contains('?'), "不应导出问题");
+        assert!(script.contains('?'), "应导出问题");
+
+        // 逐行作为Narsese任务输入
+        let mut vm2 = default_vm();
+        let inputs = script.lines().map(|line| format!("nse {line}")).join_to_new("\n");
+        vm2.input_cmds(inputs + "\ncyc 1");
+        let script2 = save_xxx_by_cmd(&mut vm2, "narsese_with_questions", "");
+
+        // 比对：去掉预算值后的语句应全部保留
+        let strip_budget = |line: &str| line.rsplit_once("$ ").map_or("", |(_, s)| s).to_owned();
+        let sentences2 = script2.lines().map(strip_budget).collect::<Vec<_>>();
+        for sentence in script.lines().map(strip_budget) {
+            assert!(!sentence.is_empty(), "导出的任务应带有预算值");
+            assert!(sentences2.contains(&sentence), "丢失了 {sentence:?}");
+        }
+        ok!()
+    }
+
+    /// 🆕导出的Narsese脚本中，「定义」重新输入后仍为定义
+    #[test]
+    fn reinput_exported_definition() -> AResult {
+        let mut vm = vm_after_inputs("def <square --> rectangle>.\nnse <A --> B>.\ncyc 1");
+        let script = save_xxx_by_cmd(&mut vm, "narsese", "");
+        let definitions = script.lines().filter(|line| line.starts_with("DEF ")).collect::<Vec<_>>();
+        assert_eq!(definitions.len(), 1, "{script}");
+        assert!(definitions[0].contains("<square --> rectangle>"));
+
+        // 逐行输入：`DEF`行保持原样
+        let mut vm2 = default_vm();
+        let inputs = script
+            .lines()
+            .map(|line| match line.starts_with("DEF ") {
+                true => line.to_string(),
+                false => format!("nse {line}"),
+            })
+            .join_to_new("\n");
+        vm2.input_cmds(inputs + "\ncyc 1");
+        let concept = vm2
+            .reasoner
+            .memory()
+            .term_to_concept(&term!("<square --> rectangle>"))
+            .expect("应有定义的概念");
+        assert!(concept.has_definition());
+        let concept = vm2
+            .reasoner
+            .memory()
+            .term_to_concept(&term!("<A --> B>"))
+            .expect("应有信念的概念");
+        assert!(!concept.has_definition());
+        ok!()
+    }
+
+    /// 基础快照 + 增量快照 的重放
+    /// * 🎯重放后的状态应与原推理器一致
+    #[test]
+    fn replay_status_from_deltas() -> AResult {
+        // 基础快照
+        let mut vm = vm_after_inputs(SAMPLE_INPUTS);
+        let base = save_xxx_by_cmd(&mut vm, "status", "");
+        // 无变化时，增量快照应只有少量数据
+        let delta_empty = save_xxx_by_cmd(&mut vm, "delta", "");
+        assert!(
+            delta_empty.len() < base.len(),
+            "增量快照过大：{} >= {}",
+            delta_empty.len(),
+            base.len()
+        );
+        // 两轮变化
+        vm.input_cmds("nse <B --> D>.\ncyc 10");
+        let delta1 = save_xxx_by_cmd(&mut vm, "delta", "");
+        vm.input_cmds("nse <D --> E>.\nnse <A --> E>?\ncyc 15");
+        let delta2 = save_xxx_by_cmd(&mut vm, "delta", "");
+        status_synced(&vm);
+
+        // 逐个加载到新的推理器中
+        let mut vm2 = default_vm();
+        load_status_by_cmd(&mut vm2, base.clone());
+        for delta in [&delta_empty, &delta1, &delta2] {
+            load_xxx_by_cmd(
+                &mut vm2,
+                "delta",
+                delta.clone(),
+                "增量快照",
+                MESSAGE_DELTA_LOAD_SUCCESS,
+            );
+        }
+        status_synced(&vm2);
+        status_consistent(&vm, &vm2)?;
+
+        // 一次性重放：直接拼接各次保存的数据
+        let mut vm3 = default_vm();
+        let data = [base, delta_empty, delta1, delta2].concat();
+        load_xxx_by_cmd(&mut vm3, "replay", data, "重放状态", MESSAGE_REPLAY_SUCCESS);
+        status_synced(&vm3);
+        status_consistent(&vm, &vm3)?;
+
+        // 重放后仍可继续推理
+        vm3.input_cmds("nse <A --> D>?\ncyc 10");
+        vm3.print_outputs();
+        ok!()
+    }
+
+    /// 将记忆区合并到另一个已有知识的推理器中
+    /// * 🎯知识导入：不覆盖原有知识，同内容信念应被修正
+    #[test]
+    fn merge_memory_into_other_reasoner() -> AResult {
+        use crate::inference::Truth;
+        // 两个各自有知识的推理器
+        let mut vm = vm_after_inputs("nse <A --> B>.\ncyc 5");
+        let mut vm2 = vm_after_inputs("nse <A --> B>.\nnse <C --> D>.\nnse <C --> E>?\ncyc 5");
+        status_synced(&vm2);
+        // 记忆区序列化成JSON
+        let data = save_xxx_by_cmd(&mut vm2, "memory", "");
+        // 以指令形式合并到推理器1
+        load_xxx_by_cmd(
+            &mut vm,
+            "merge",
+            data,
+            "记忆区",
+            MESSAGE_MEMORY_MERGE_SUCCESS,
+        );
+        memory_synced(&vm);
+        // 外来的概念应该被并入
+        let memory = vm.reasoner.memory();
+        for key in ["(C --> D)", "(C --> E)", "C", "D"] {
+            assert!(memory.key_to_concept(key).is_some(), "概念{key}未被合并");
+        }
+        // 同内容的信念应该被修正：置信度高于单次输入
+        let concept = memory.key_to_concept("(A --> B)").expect("概念丢失");
+        let max_confidence = concept
+            .beliefs()
+            .map(|belief| belief.confidence().to_float())
+            .fold(0.0, f64::max);
+        assert!(max_confidence > 0.9, "信念未被修正：{max_confidence}");
+        // 合并后仍可继续推理
+        vm.input_cmds("nse <A --> D>?\ncyc 10");
+        vm.print_outputs();
+        memory_synced(&vm);
+        ok!()
+    }
+
     /// 共享引用序列号的稳定性
     /// * ❌杜绝「基于指针地址的、多次无法稳定复现的 序列号不稳定」问题
     ///
@@ -1,4 +1,8 @@
-use crate::control::Reasoner;
+use super::binary_save::{memory_to_binary, status_to_binary, Compression};
+use crate::{
+    control::{GraphExportOptions, Reasoner},
+    language::Term,
+};
 use nar_dev_utils::{macro_once, ResultS};
 
 /// 指令[`Cmd::SAV`]的入口函数
@@ -29,8 +33,83 @@ pub fn sav_dispatch(
         // 记忆区
         "memory" => generate_sav_callback(reasoner, query, path, memory_to_json)?
         // 推理器整体状态
-        "status" => generate_sav_callback(reasoner, query, path, status_to_json)?
+        // * 🚩保存后设置检查点，作为后续增量快照的基础
+        "status" => {
+            let output = generate_sav_callback(reasoner, query, path, status_to_json)?;
+            reasoner.checkpoint();
+            output
+        }
+        // 记忆区（二进制存档）
+        "memory_cbor" => generate_sav_callback(reasoner, query, path, memory_to_cbor)?
+        // 记忆区（压缩的二进制存档）
+        "memory_cbor_deflate" => generate_sav_callback(reasoner, query, path, memory_to_cbor_deflate)?
+        // 推理器整体状态（二进制存档）
+        "status_cbor" => {
+            let output = generate_sav_callback(reasoner, query, path, status_to_cbor)?;
+            reasoner.checkpoint();
+            output
+        }
+        // 推理器整体状态（压缩的二进制存档）
+        "status_cbor_deflate" => {
+            let output = generate_sav_callback(reasoner, query, path, status_to_cbor_deflate)?;
+            reasoner.checkpoint();
+            output
+        }
+        // 记忆区中的信念（Narsese脚本）
+        "narsese" => generate_sav_callback(reasoner, query, path, memory_to_narsese)?
+        // 记忆区中的信念与问题（Narsese脚本）
+        "narsese_with_questions" => generate_sav_callback(reasoner, query, path, memory_to_narsese_with_questions)?
+        // 推理器状态的增量快照：自上次检查点以来的变化
+        "delta" => {
+            let output = generate_sav_callback(reasoner, query, path, status_delta_to_json)?;
+            reasoner.checkpoint();
+            output
+        }
+        // 记忆区的概念图（Graphviz DOT）
+        // * 🚩路径后可附加选项：`<路径>;focus=<词项>;radius=<半径>`
+        "graph" => sav_graph(reasoner, path)?
+    }
+}
+
+/// 保存概念图
+/// * 🚩从路径中分离出导出选项，回调中只保留真正的路径
+/// * 📌焦点词项中不能有空格：如`focus=<A-->B>`
+/// * 📌指定焦点而未指定半径⇒半径为1
+fn sav_graph(reasoner: &Reasoner, path: impl AsRef<str>) -> ResultS<Output> {
+    let (path, options) = parse_graph_options(path.as_ref())?;
+    let data = reasoner.export_dot(&options);
+    Ok(Output::format_sav_callback(path, data))
+}
+
+/// 解析概念图的导出选项
+/// * 🚩格式：`<路径>;<键>=<值>;...`
+fn parse_graph_options(path: &str) -> ResultS<(&str, GraphExportOptions)> {
+    let mut parts = path.split(';');
+    let path = parts.next().unwrap_or_default();
+    let mut options = GraphExportOptions {
+        focus: None,
+        radius: 1,
+    };
+    for part in parts.filter(|part| !part.is_empty()) {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid graph option {part:?}, expected `key=value`"))?;
+        match key {
+            "focus" => {
+                let term = value
+                    .parse::<Term>()
+                    .map_err(|e| format!("Invalid focus term {value:?}: {e}"))?;
+                options.focus = Some(term);
+            }
+            "radius" => {
+                options.radius = value
+                    .parse()
+                    .map_err(|e| format!("Invalid graph radius {value:?}: {e}"))?;
+            }
+            other => return Err(format!("Unknown graph option {other:?}")),
+        }
     }
+    Ok((path, options))
 }
 
 /// 通用的「SAV」callback格式
@@ -222,3 +301,44 @@ fn status_to_json(reasoner: &Reasoner) -> anyhow::Result<String> {
     let json = String::from_utf8(writer)?;
     Ok(json)
 }
+
+/// 将「推理状态」的增量快照转换为JSON字符串
+/// * ⚠️可能失败：记忆区数据可能无法被序列化
+fn status_delta_to_json(reasoner: &Reasoner) -> anyhow::Result<String> {
+    let mut writer = Vec::<u8>::new();
+    let mut ser = serde_json::Serializer::new(&mut writer);
+    reasoner.serialize_status_delta(&mut ser)?;
+    let json = String::from_utf8(writer)?;
+    Ok(json)
+}
+
+/// 将记忆区中的信念转换为Narsese脚本
+/// * 📌每行一个任务，可直接被Shell或批处理程序重新输入
+fn memory_to_narsese(reasoner: &Reasoner) -> anyhow::Result<String> {
+    Ok(reasoner.export_narsese(false))
+}
+
+/// 将记忆区中的信念与问题转换为Narsese脚本
+fn memory_to_narsese_with_questions(reasoner: &Reasoner) -> anyhow::Result<String> {
+    Ok(reasoner.export_narsese(true))
+}
+
+/// 将记忆区转换为二进制存档
+fn memory_to_cbor(reasoner: &Reasoner) -> anyhow::Result<String> {
+    memory_to_binary(reasoner, Compression::None)
+}
+
+/// 将记忆区转换为压缩的二进制存档
+fn memory_to_cbor_deflate(reasoner: &Reasoner) -> anyhow::Result<String> {
+    memory_to_binary(reasoner, Compression::Deflate)
+}
+
+/// 将「推理状态」转换为二进制存档
+fn status_to_cbor(reasoner: &Reasoner) -> anyhow::Result<String> {
+    status_to_binary(reasoner, Compression::None)
+}
+
+/// 将「推理状态」转换为压缩的二进制存档
+fn status_to_cbor_deflate(reasoner: &Reasoner) -> anyhow::Result<String> {
+    status_to_binary(reasoner, Compression::Deflate)
+}
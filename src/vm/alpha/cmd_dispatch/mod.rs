@@ -1,6 +1,7 @@
 //! 集中管理有关「推理器分派处理指令」的函数
 
 use super::RuntimeAlpha;
+use narsese::conversion::string::impl_lexical::format_instances::FORMAT_ASCII;
 use navm::cmd::Cmd;
 
 /// 输入指令
@@ -31,6 +32,8 @@ impl RuntimeAlpha {
             REM { .. } => (),
             // * 🚩退出⇒处理完所有输出后直接退出
             EXI { reason } => self.cmd_exi(reason),
+            // * 🆕定义：输入不可修正、不被遗忘的判断
+            Custom { head, tail } if head.eq_ignore_ascii_case("DEF") => self.cmd_def(tail),
             // Custom { head, tail } => (),
             // * 🚩未知指令⇒输出提示
             _ => self.reasoner.report_error(format!("Unknown cmd: {cmd}")),
@@ -42,6 +45,21 @@ impl RuntimeAlpha {
         self.reasoner.input_task(narsese)
     }
 
+    /// 处理自定义指令`DEF`
+    /// * 🎯输入「定义」：格式同`NSE`，如`DEF <square --> rectangle>.`
+    fn cmd_def(&mut self, narsese: String) {
+        let task = FORMAT_ASCII
+            .parse(&narsese)
+            .map_err(|e| e.to_string())
+            .and_then(|value| value.try_into_task_compatible().map_err(|e| e.to_string()));
+        match task {
+            Ok(task) => self.reasoner.input_definition(task),
+            Err(e) => self
+                .reasoner
+                .report_error(format!("Narsese definition parse error: {e}")),
+        }
+    }
+
     /// 处理指令[`Cmd::VOL`]
     fn cmd_vol(&mut self, volume: usize) {
         self.reasoner
@@ -137,3 +155,6 @@ use cmd_sav::*;
 /// 专用于指令[`Cmd::LOA`]的处理函数
 mod cmd_loa;
 use cmd_loa::*;
+
+/// 专用于指令[`Cmd::SAV`]与[`Cmd::LOA`]的「二进制存档」编解码
+mod binary_save;
@@ -0,0 +1,213 @@
+//! 🆕前端程序共用的「指令预解释」与「存档拦截」
+//! * 🎯批处理、服务器等程序共用同一套逻辑
+//!   * 📄从一行文本解释出NAVM指令：`LOA`的路径⇒文件内容，Narsese⇒`NSE`
+//!   * 📄将「有路径的`SAV`」回调写入本地文件
+//! * 📌本地文件的读写受[`FileAccess`]约束
+//!   * ⚠️面向远程客户端的程序（如服务器）不可放任读写任意路径
+
+use super::SavCallback;
+use anyhow::{anyhow, Result};
+use narsese::conversion::string::impl_lexical::format_instances::FORMAT_ASCII;
+use navm::{cmd::Cmd, output::Output};
+use std::{
+    io::Write,
+    path::{Component, Path, PathBuf},
+};
+
+/// 前端程序对本地文件的访问权限
+/// * 🎯`LOA`读取文件、`SAV`写入文件时检查
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileAccess {
+    /// 不受限制：可读写任意路径
+    /// * 📌仅用于本地使用的程序（批处理、交互式终端）
+    Unrestricted,
+    /// 禁止读写文件
+    Denied,
+    /// 限定在某个目录之下
+    /// * 📌只接受相对路径，且不得包含`..`
+    /// * 📌目录本身已规范化
+    Confined(PathBuf),
+}
+
+impl FileAccess {
+    /// 限定在某个目录之下
+    /// * 🚩目录须已存在：预先规范化，以便检查符号链接
+    pub fn confined(root: impl AsRef<Path>) -> Result<Self> {
+        let root = root.as_ref();
+        let root = root
+            .canonicalize()
+            .map_err(|e| anyhow!("Invalid file root {root:?}: {e}"))?;
+        Ok(Self::Confined(root))
+    }
+
+    /// 将前端给出的路径解析为实际读写的路径
+    /// * 🚩不允许⇒报错
+    /// * 🚩限定目录⇒只接受目录内的相对路径，并检查符号链接是否指向目录外
+    pub fn resolve(&self, path: &str) -> Result<PathBuf> {
+        match self {
+            Self::Unrestricted => Ok(PathBuf::from(path)),
+            Self::Denied => Err(anyhow!("File access is disabled: {path:?}")),
+            Self::Confined(root) => {
+                let relative = Path::new(path);
+                let inside = relative
+                    .components()
+                    .all(|component| matches!(component, Component::Normal(..) | Component::CurDir));
+                if !inside || path.is_empty() {
+                    return Err(anyhow!("Path is outside the file root: {path:?}"));
+                }
+                let resolved = root.join(relative);
+                // * 🚩文件（或其所在目录）已存在⇒检查规范化后的位置
+                let existing = match resolved.exists() {
+                    true => Some(resolved.clone()),
+                    false => resolved.parent().map(Path::to_path_buf),
+                };
+                if let Some(existing) = existing {
+                    let canonical = existing.canonicalize()?;
+                    if !canonical.starts_with(root) {
+                        return Err(anyhow!("Path is outside the file root: {path:?}"));
+                    }
+                }
+                Ok(resolved)
+            }
+        }
+    }
+}
+
+/// 从输入中「提前解释」指令
+/// * 💡可以从中对指令作预处理
+///   * 📄绕过生硬的NAVM指令语法，像OpenNARS那样直接输入Narsese与推理步数
+///   * 📄`LOA`指令⇒读取本地文件⇒内联到新的`LOA`中⇒虚拟机Alpha实现内容加载
+/// * 📌解释失败⇒返回错误消息
+pub fn interpret_cmd(input: &str, files: &FileAccess) -> Result<Cmd, String> {
+    // 尝试作为普通NAVM指令解析
+    if let Ok(cmd) = Cmd::parse(input) {
+        match cmd {
+            // `LOA`指令转译：路径→文件内容
+            Cmd::LOA { target, path } => {
+                let data = try_load_file_content(path, files)
+                    .map_err(|err| format!("NAVM LOA cmd load error: {err}"))?;
+                return Ok(Cmd::LOA { target, path: data });
+            }
+            // 自定义指令`DEF`：输入定义
+            Cmd::Custom { ref head, .. } if head.eq_ignore_ascii_case("DEF") => return Ok(cmd),
+            // 其它自定义指令：忽略
+            // * 避免解析范围的扩大，导致输入`A.`不通过
+            Cmd::Custom { .. } => {}
+            // 其它⇒解析成功
+            _ => return Ok(cmd),
+        }
+    }
+    // 若能解析成词法Narsese任务⇒尝试默认成`NSE`指令
+    // * 🎯支持直接输入由`SAV narsese`导出的Narsese脚本
+    // * ⚠️此解析方法容易把范围扩大，因此放到后边
+    if let Ok(Ok(task)) = FORMAT_ASCII
+        .parse(input)
+        .map(|value| value.try_into_task_compatible())
+    {
+        return Ok(Cmd::NSE(task));
+    }
+    // 最终仍然解析失败
+    Err(format!("NAVM cmd parse error: {input:?}"))
+}
+
+/// 尝试读取本地文件，将内容作为`LOA`指令的path参数
+pub fn try_load_file_content(path: impl AsRef<str>, files: &FileAccess) -> Result<String> {
+    let path = path.as_ref();
+    let resolved = files.resolve(path)?;
+    if resolved.exists() {
+        let content = std::fs::read_to_string(resolved)?;
+        return Ok(content);
+    }
+    Err(anyhow!("File not found: {path}"))
+}
+
+/// 拦截输出
+/// * 🎯根据「有路径的SAV」输出文件
+/// * 🚩空路径⇒原样返回；有路径⇒保存，并替换为「已保存」或报错的消息
+pub fn intercept_sav_output(output: Output, files: &FileAccess) -> Output {
+    match output.try_into_sav_callback() {
+        // 空路径⇒不保存⇒重组回「消息」并继续
+        Ok((path, data)) if path.is_empty() => Output::format_sav_callback(path, data),
+        // 有路径⇒保存到文件
+        Ok((path, data)) => {
+            let message = match save_file(&path, &data, files) {
+                Ok(..) => format!("Data has been saved to {path:?} with {} bytes", data.len()),
+                Err(e) => format!("Failed to save data to {path:?}! Error: {e}"),
+            };
+            Output::INFO { message }
+        }
+        // 未消耗⇒继续
+        Err(output) => output,
+    }
+}
+
+/// 路径+数据→保存文件
+pub fn save_file(path: &str, data: &str, files: &FileAccess) -> Result<()> {
+    use std::fs::File;
+    let mut file = File::create(files.resolve(path)?)?;
+    file.write_all(data.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ok, util::AResult};
+
+    /// 临时目录：测试结束后删除
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("narust_158_{name}_{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn denied() {
+        assert!(FileAccess::Denied.resolve("memory.json").is_err());
+        let cmd = interpret_cmd("LOA memory memory.json", &FileAccess::Denied);
+        assert!(cmd.unwrap_err().contains("disabled"));
+        let output = Output::format_sav_callback("memory.json", "{}");
+        match intercept_sav_output(output, &FileAccess::Denied) {
+            Output::INFO { message } => assert!(message.starts_with("Failed"), "{message}"),
+            output => panic!("{output:?}"),
+        }
+    }
+
+    #[test]
+    fn confined() -> AResult {
+        let dir = TempDir::new("frontend_confined");
+        let files = FileAccess::confined(&dir.0)?;
+        // 目录内：可存可读
+        let output = Output::format_sav_callback("memory.json", "{}");
+        match intercept_sav_output(output, &files) {
+            Output::INFO { message } => assert!(message.starts_with("Data"), "{message}"),
+            output => panic!("{output:?}"),
+        }
+        assert_eq!(try_load_file_content("memory.json", &files)?, "{}");
+        // 目录外：一律拒绝
+        for path in ["", "../memory.json", "a/../../memory.json", "/etc/passwd"] {
+            assert!(files.resolve(path).is_err(), "{path:?}");
+        }
+        ok!()
+    }
+
+    #[test]
+    fn interpret() {
+        let files = FileAccess::Unrestricted;
+        assert!(matches!(interpret_cmd("cyc 5", &files), Ok(Cmd::CYC(5))));
+        assert!(matches!(interpret_cmd("<A --> B>.", &files), Ok(Cmd::NSE(..))));
+        assert!(matches!(interpret_cmd("DEF <A --> B>.", &files), Ok(Cmd::Custom { .. })));
+        assert!(interpret_cmd("<A --> B.", &files).is_err());
+    }
+}
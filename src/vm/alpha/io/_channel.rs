@@ -2,6 +2,8 @@
 //! * 🎯替代[推理器](crate::control::Reasoner)中的`removeXXXXXChannel`方法
 //!   * 不违反借用规则，同时也无需判等
 
+use navm::output::Output;
+
 /// 🆕统一「输入通道」「输出通道」的「通道」类型
 /// * 🎯替代并整合推理器中「移除通道」的方法——标记删除法
 ///   * 🚩核心逻辑：标记「待删除」然后让推理器自行决定
@@ -13,4 +15,12 @@ pub trait Channel {
     /// * 🎯替代`reasoner.removeInputChannel(this);`
     ///   * 📌动态分派的**特征对象难以判等**
     fn need_remove(&self) -> bool;
+
+    /// 🆕取出通道自身产生的报告
+    /// * 🎯通道运行中的错误（如文件读写失败）经推理器的输出交给前端，而非直接打印
+    /// * 🚩运行时在每次处理输入输出后取出，并作为推理器的输出报告
+    /// * 📌默认没有报告
+    fn take_reports(&mut self) -> Vec<Output> {
+        vec![]
+    }
 }
@@ -0,0 +1,383 @@
+//! 🆕跟踪文件的「输入通道」
+//! * 🎯让同一机器上的其它进程（如传感器）通过追加写入文件，实时向推理器输入
+//! * 🚩每次被推理器请求输入时，读取文件中新追加的完整行，并解释为NAVM指令
+//!   * 📌解释方式与交互式终端一致：纯数字⇒`CYC`，NAVM指令（含`DEF`），Narsese任务⇒`NSE`
+//!   * 📌未以换行结尾的行视作「尚未写完」，留待下次读取
+//! * 🚩普通文件：记录读取位置，并持久化到「位置文件」中，重启后从上次位置继续
+//!   * 📌文件变短（如被截断）⇒从头开始读取
+//!   * 📌文件被替换（如轮替）⇒从头开始读取，不论新文件长短
+//!     * 📍以文件标识判断：Unix下为设备号与inode，其它平台为创建时间
+//! * 🚩命名管道（仅Unix）：在后台线程中逐行读取，无需记录位置
+//! * 🚩读取、解释中的错误⇒作为`ERROR`输出报告，而非打印到标准错误
+//! * ⚠️写入者可以是任意进程：`LOA`读取本地文件的权限默认为[禁止](FileAccess::Denied)
+//!   * 📍可由[`with_file_access`](FileTailInputChannel::with_file_access)指定
+
+use super::{Channel, InputChannel};
+use crate::vm::alpha::{interpret_cmd, FileAccess};
+use navm::{cmd::Cmd, output::Output};
+use std::{
+    fs::{File, Metadata},
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+#[cfg(unix)]
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
+
+/// 跟踪文件的输入通道
+#[derive(Debug)]
+pub struct FileTailInputChannel {
+    /// 跟踪的来源
+    source: TailSource,
+    /// 尚未取出的报告
+    reports: Vec<Output>,
+    /// `LOA`指令读取本地文件的权限
+    files: FileAccess,
+}
+
+/// 跟踪的来源
+#[derive(Debug)]
+enum TailSource {
+    /// 普通文件
+    File {
+        /// 文件路径
+        path: PathBuf,
+        /// 持久化读取位置的文件路径
+        offset_path: PathBuf,
+        /// 当前读取位置（字节）
+        offset: u64,
+        /// 读取位置所对应的文件标识
+        /// * 📌未知（如旧版位置文件）⇒只按长度判断
+        identity: Option<String>,
+    },
+    /// 命名管道：由后台线程读取的行
+    #[cfg(unix)]
+    Pipe {
+        lines: Receiver<String>,
+        /// 后台线程已结束（管道无法再打开），且所有行均已读取
+        closed: bool,
+    },
+}
+
+impl FileTailInputChannel {
+    /// 构造函数
+    /// * 🚩位置文件默认为`<路径>.offset`
+    pub fn new(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let mut offset_path = path.as_os_str().to_owned();
+        offset_path.push(".offset");
+        Self::with_offset_path(path, offset_path)
+    }
+
+    /// 构造函数，并指定位置文件
+    /// * 🚩位置文件存在⇒从记录的位置继续读取
+    /// * 📌文件可以尚未创建：创建后再开始读取
+    /// * 📌命名管道⇒忽略位置文件
+    pub fn with_offset_path(
+        path: impl AsRef<Path>,
+        offset_path: impl AsRef<Path>,
+    ) -> std::io::Result<Self> {
+        let path = path.as_ref().to_owned();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::FileTypeExt;
+            let is_fifo = std::fs::metadata(&path).is_ok_and(|m| m.file_type().is_fifo());
+            if is_fifo {
+                return Ok(Self {
+                    source: spawn_pipe_reader(path),
+                    reports: vec![],
+                    files: FileAccess::Denied,
+                });
+            }
+        }
+        let offset_path = offset_path.as_ref().to_owned();
+        // * 🚩位置文件：首行为读取位置，次行（可选）为文件标识
+        let saved = std::fs::read_to_string(&offset_path).unwrap_or_default();
+        let mut saved = saved.lines().map(str::trim);
+        let offset = saved.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+        let identity = saved.next().filter(|s| !s.is_empty()).map(str::to_owned);
+        Ok(Self {
+            source: TailSource::File {
+                path,
+                offset_path,
+                offset,
+                identity,
+            },
+            reports: vec![],
+            files: FileAccess::Denied,
+        })
+    }
+
+    /// 指定`LOA`指令读取本地文件的权限
+    /// * 📌默认[禁止](FileAccess::Denied)
+    pub fn with_file_access(self, files: FileAccess) -> Self {
+        Self { files, ..self }
+    }
+
+    /// 报告错误
+    fn report_error(&mut self, description: impl Into<String>) {
+        self.reports.push(Output::ERROR {
+            description: description.into(),
+        });
+    }
+
+    /// 读取所有新的完整行
+    fn read_new_lines(&mut self) -> Vec<String> {
+        match &mut self.source {
+            TailSource::File {
+                path,
+                offset_path,
+                offset,
+                identity,
+            } => match read_appended_lines(path, offset, identity) {
+                Ok(lines) => {
+                    if !lines.is_empty() {
+                        // * 🚩持久化失败不影响读取
+                        let saved = format!("{offset}\n{}", identity.as_deref().unwrap_or(""));
+                        if let Err(e) = std::fs::write(&*offset_path, saved) {
+                            let message = format!("Failed to save offset to {offset_path:?}: {e}");
+                            self.report_error(message);
+                        }
+                    }
+                    lines
+                }
+                // * 🚩文件暂不可读（如尚未创建）⇒下次再试
+                Err(..) => vec![],
+            },
+            #[cfg(unix)]
+            TailSource::Pipe { lines, closed } => {
+                let mut new_lines = vec![];
+                loop {
+                    match lines.try_recv() {
+                        Ok(line) => new_lines.push(line),
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => {
+                            *closed = true;
+                            break;
+                        }
+                    }
+                }
+                new_lines
+            }
+        }
+    }
+}
+
+/// 从指定位置读取文件中新追加的完整行，并更新位置
+/// * 🚩文件比记录的位置短⇒视作被截断，从头读取
+/// * 🚩文件标识与记录的不同⇒视作被替换，从头读取
+fn read_appended_lines(
+    path: &Path,
+    offset: &mut u64,
+    identity: &mut Option<String>,
+) -> std::io::Result<Vec<String>> {
+    let mut file = File::open(path)?;
+    let metadata = file.metadata()?;
+    let current_identity = file_identity(&metadata);
+    let replaced = matches!((&*identity, &current_identity), (Some(old), Some(new)) if old != new);
+    if replaced || metadata.len() < *offset {
+        *offset = 0;
+    }
+    *identity = current_identity;
+    file.seek(SeekFrom::Start(*offset))?;
+    let mut bytes = vec![];
+    file.read_to_end(&mut bytes)?;
+    // * 🚩只取到最后一个换行符为止
+    let Some(end) = bytes.iter().rposition(|&b| b == b'\n') else {
+        return Ok(vec![]);
+    };
+    let complete = &bytes[..=end];
+    *offset += complete.len() as u64;
+    Ok(String::from_utf8_lossy(complete)
+        .lines()
+        .map(str::to_owned)
+        .collect())
+}
+
+/// 文件标识
+/// * 🎯判断文件是否被替换（如日志轮替）
+/// * 📌Unix：设备号与inode
+#[cfg(unix)]
+fn file_identity(metadata: &Metadata) -> Option<String> {
+    use std::os::unix::fs::MetadataExt;
+    Some(format!("{}:{}", metadata.dev(), metadata.ino()))
+}
+
+/// 文件标识
+/// * 🎯判断文件是否被替换（如日志轮替）
+/// * 📌其它平台：创建时间；不支持⇒无标识，只按长度判断
+#[cfg(not(unix))]
+fn file_identity(metadata: &Metadata) -> Option<String> {
+    let created = metadata.created().ok()?;
+    let since_epoch = created.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(since_epoch.as_nanos().to_string())
+}
+
+/// 启动读取命名管道的后台线程
+/// * 📌打开命名管道会阻塞到有写入者为止，故放在后台线程中
+/// * 🚩所有写入者关闭后重新打开，以等待新的写入者
+#[cfg(unix)]
+fn spawn_pipe_reader(path: PathBuf) -> TailSource {
+    use std::io::{BufRead, BufReader};
+    let (sender, lines) = channel();
+    std::thread::spawn(move || loop {
+        let Ok(file) = File::open(&path) else { return };
+        for line in BufReader::new(file).lines() {
+            let Ok(line) = line else { break };
+            // * 🚩通道已被丢弃⇒结束线程
+            if sender.send(line).is_err() {
+                return;
+            }
+        }
+    });
+    TailSource::Pipe {
+        lines,
+        closed: false,
+    }
+}
+
+/// 将一行输入解释为NAVM指令
+/// * 📌与交互式终端的解释方式一致
+///   * 📄纯数字⇒`CYC`指令
+///   * 📄其它⇒交给[前端程序共用的解释](interpret_cmd)
+/// * 🚩空行⇒忽略；解释失败⇒返回错误消息
+fn interpret_line(line: &str, files: &FileAccess) -> Result<Option<Cmd>, String> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Ok(None);
+    }
+    // 纯数字⇒尝试默认成`CYC`指令
+    if let Ok(n) = line.parse::<usize>() {
+        return Ok(Some(Cmd::CYC(n)));
+    }
+    interpret_cmd(line, files).map(Some)
+}
+
+impl Channel for FileTailInputChannel {
+    /// 命名管道的读取线程已结束⇒移除
+    fn need_remove(&self) -> bool {
+        match self.source {
+            TailSource::File { .. } => false,
+            #[cfg(unix)]
+            TailSource::Pipe { closed, .. } => closed,
+        }
+    }
+
+    fn take_reports(&mut self) -> Vec<Output> {
+        std::mem::take(&mut self.reports)
+    }
+}
+
+impl InputChannel for FileTailInputChannel {
+    fn next_input(&mut self) -> (bool, Vec<Cmd>) {
+        let mut cmds = vec![];
+        for line in self.read_new_lines() {
+            match interpret_line(&line, &self.files) {
+                Ok(cmd) => cmds.extend(cmd),
+                // * 🚩解释失败⇒报告并跳过
+                Err(description) => self.report_error(description),
+            }
+        }
+        (!cmds.is_empty(), cmds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn tail_file() -> std::io::Result<()> {
+        let dir = std::env::temp_dir();
+        let path = dir.join("narust_158_file_tail_test.nal");
+        let offset_path = dir.join("narust_158_file_tail_test.nal.offset");
+        let _ = std::fs::remove_file(&offset_path);
+        std::fs::write(&path, "<A --> B>.\n5\nDEF <B --> C>.\n")?;
+        let mut channel = FileTailInputChannel::with_offset_path(&path, &offset_path)?;
+        // 初次读取：所有行
+        let (run, cmds) = channel.next_input();
+        assert!(run);
+        assert!(matches!(cmds[..], [Cmd::NSE(..), Cmd::CYC(5), Cmd::Custom { .. }]));
+        // 无新行
+        assert!(channel.next_input().1.is_empty());
+        // 追加：未写完的行留待下次
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path)?;
+        write!(file, "<B --> C>.\n<C --")?;
+        assert_eq!(channel.next_input().1.len(), 1);
+        writeln!(file, "> D>.")?;
+        assert_eq!(channel.next_input().1.len(), 1);
+        // 重启：从记录的位置继续
+        writeln!(file, "VOL 0")?;
+        let mut channel = FileTailInputChannel::with_offset_path(&path, &offset_path)?;
+        assert!(matches!(channel.next_input().1[..], [Cmd::VOL(0)]));
+        // 截断：从头读取
+        std::fs::write(&path, "1\n")?;
+        assert!(matches!(channel.next_input().1[..], [Cmd::CYC(1)]));
+        std::fs::remove_file(&path)?;
+        std::fs::remove_file(&offset_path)
+    }
+
+    /// 文件被替换为更长的新文件⇒从头读取
+    #[test]
+    fn tail_rotated() -> std::io::Result<()> {
+        let dir = std::env::temp_dir();
+        let path = dir.join("narust_158_file_tail_rotated.nal");
+        let rotated = dir.join("narust_158_file_tail_rotated.nal.new");
+        let offset_path = dir.join("narust_158_file_tail_rotated.nal.offset");
+        let _ = std::fs::remove_file(&offset_path);
+        std::fs::write(&path, "1\n")?;
+        let mut channel = FileTailInputChannel::with_offset_path(&path, &offset_path)?;
+        assert!(matches!(channel.next_input().1[..], [Cmd::CYC(1)]));
+        // * 🚩新文件先写好，再替换旧文件
+        std::fs::write(&rotated, "2\n3\n")?;
+        std::fs::rename(&rotated, &path)?;
+        assert!(matches!(channel.next_input().1[..], [Cmd::CYC(2), Cmd::CYC(3)]));
+        // * 🚩重启后亦能识别
+        std::fs::write(&rotated, "4\n5\n6\n")?;
+        std::fs::rename(&rotated, &path)?;
+        let mut channel = FileTailInputChannel::with_offset_path(&path, &offset_path)?;
+        assert_eq!(channel.next_input().1.len(), 3);
+        std::fs::remove_file(&path)?;
+        std::fs::remove_file(&offset_path)
+    }
+
+    /// 解释失败⇒作为`ERROR`输出报告
+    #[test]
+    fn tail_reports() -> std::io::Result<()> {
+        let dir = std::env::temp_dir();
+        let path = dir.join("narust_158_file_tail_reports.nal");
+        let offset_path = dir.join("narust_158_file_tail_reports.nal.offset");
+        let _ = std::fs::remove_file(&offset_path);
+        std::fs::write(&path, "<A --> B>.\n<A --> B\n")?;
+        let mut channel = FileTailInputChannel::with_offset_path(&path, &offset_path)?;
+        assert_eq!(channel.next_input().1.len(), 1);
+        let reports = channel.take_reports();
+        assert!(matches!(reports[..], [Output::ERROR { .. }]), "{reports:?}");
+        assert!(channel.take_reports().is_empty());
+        std::fs::remove_file(&path)?;
+        std::fs::remove_file(&offset_path)
+    }
+
+    /// `LOA`读取本地文件：默认禁止，可限定在目录之下
+    #[test]
+    fn tail_file_access() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("narust_158_tail_access_{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("input.nal");
+        let offset_path = dir.join("input.nal.offset");
+        std::fs::write(dir.join("memory.json"), "{}")?;
+        std::fs::write(&path, "LOA memory memory.json\n")?;
+        // 默认禁止
+        let mut channel = FileTailInputChannel::with_offset_path(&path, &offset_path)?;
+        assert!(channel.next_input().1.is_empty());
+        assert!(matches!(channel.take_reports()[..], [Output::ERROR { .. }]));
+        // 限定在目录之下
+        std::fs::remove_file(&offset_path)?;
+        let mut channel = FileTailInputChannel::with_offset_path(&path, &offset_path)?
+            .with_file_access(FileAccess::confined(&dir)?);
+        let cmds = channel.next_input().1;
+        assert!(matches!(&cmds[..], [Cmd::LOA { path, .. }] if path == "{}"), "{cmds:?}");
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+//! 🆕现成的输出通道
+//! * 🎯覆盖常见的输出去向，无需外部自行实现[`OutputChannel`]
+//!   * 📍文件：[`FileOutputChannel`]
+//!   * 📍内存环形缓冲区：[`RingBufferOutputChannel`]
+//!   * 📍回调函数：[`CallbackOutputChannel`]
+//! * 📌可与[过滤器](super::OutputFilter)搭配使用
+
+use super::{Channel, OutputChannel};
+use crate::global::RC;
+use nar_dev_utils::RefCount;
+use navm::output::Output;
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter},
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// 写入文件的输出通道
+/// * 🚩每个输出一行JSON，格式同[`Output::to_json_string`]
+/// * 🚩以追加模式打开文件
+/// * ⚠️写入失败⇒报告错误，并标记「待移除」
+///   * 📌错误以`ERROR`输出经推理器发往其它输出通道
+#[derive(Debug)]
+pub struct FileOutputChannel {
+    /// 带缓冲的文件写入者
+    writer: BufWriter<File>,
+    /// 是否已写入失败
+    failed: bool,
+    /// 尚未取出的报告
+    reports: Vec<Output>,
+}
+
+impl FileOutputChannel {
+    /// 构造函数
+    /// * 🚩文件不存在⇒创建
+    pub fn new(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+            failed: false,
+            reports: vec![],
+        })
+    }
+
+    /// 写入一批输出，并立即刷新
+    fn write_outputs(&mut self, outputs: &[Output]) -> std::io::Result<()> {
+        for output in outputs {
+            writeln!(self.writer, "{}", output.to_json_string())?;
+        }
+        self.writer.flush()
+    }
+}
+
+impl Channel for FileOutputChannel {
+    /// 写入失败⇒移除
+    fn need_remove(&self) -> bool {
+        self.failed
+    }
+
+    fn take_reports(&mut self) -> Vec<Output> {
+        std::mem::take(&mut self.reports)
+    }
+}
+
+impl OutputChannel for FileOutputChannel {
+    fn next_output(&mut self, outputs: &[Output]) {
+        // * 🚩已失败⇒不再写入
+        if self.failed {
+            return;
+        }
+        if let Err(e) = self.write_outputs(outputs) {
+            self.reports.push(Output::ERROR {
+                description: format!("Failed to write outputs to file: {e}"),
+            });
+            self.failed = true;
+        }
+    }
+}
+
+/// 内存中的环形缓冲区输出通道
+/// * 🎯保留最近的若干条输出，供界面等随时查阅
+/// * 🚩缓冲区满⇒丢弃最早的输出
+/// * 📌与[`ChannelOut`](super::ChannelOut)类似，可通过[`RC`]与外部共享
+#[derive(Debug, Clone)]
+pub struct RingBufferOutputChannel {
+    /// 容量
+    capacity: usize,
+    /// 缓存的输出
+    buffer: VecDeque<Output>,
+}
+
+impl RingBufferOutputChannel {
+    /// 构造函数
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            buffer: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// 容量
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 当前缓存的输出数
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// 是否没有缓存的输出
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// 遍历缓存的输出
+    /// * 🚩从早到晚
+    pub fn iter(&self) -> impl Iterator<Item = &Output> {
+        self.buffer.iter()
+    }
+
+    /// 拉取最早的输出
+    /// * 🚩先进先出
+    pub fn fetch(&mut self) -> Option<Output> {
+        self.buffer.pop_front()
+    }
+
+    /// 清空并取出所有输出
+    pub fn drain(&mut self) -> Vec<Output> {
+        self.buffer.drain(..).collect()
+    }
+
+    /// 放入一个输出
+    fn push(&mut self, output: Output) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.buffer.len() >= self.capacity {
+            self.buffer.pop_front();
+        }
+        self.buffer.push_back(output);
+    }
+}
+
+impl Channel for RingBufferOutputChannel {
+    /// 始终无需移除
+    fn need_remove(&self) -> bool {
+        false
+    }
+}
+
+impl OutputChannel for RingBufferOutputChannel {
+    fn next_output(&mut self, outputs: &[Output]) {
+        for output in outputs {
+            self.push(output.clone());
+        }
+    }
+}
+
+impl Channel for RC<RingBufferOutputChannel> {
+    /// 委托到内部值
+    fn need_remove(&self) -> bool {
+        self.get_().need_remove()
+    }
+}
+
+/// 对Rc<RefCell>自身实现
+impl OutputChannel for RC<RingBufferOutputChannel> {
+    fn next_output(&mut self, outputs: &[Output]) {
+        self.mut_().next_output(outputs)
+    }
+}
+
+/// 回调函数输出通道
+/// * 🚩每个输出调用一次回调函数
+pub struct CallbackOutputChannel {
+    /// 回调函数
+    callback: Box<dyn FnMut(&Output)>,
+}
+
+impl CallbackOutputChannel {
+    /// 构造函数
+    pub fn new(callback: impl FnMut(&Output) + 'static) -> Self {
+        Self {
+            callback: Box::new(callback),
+        }
+    }
+}
+
+/// 手动实现：回调函数未实现[`Debug`]
+impl Debug for CallbackOutputChannel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallbackOutputChannel")
+            .field("callback", &"Box<dyn FnMut(&Output)>")
+            .finish()
+    }
+}
+
+impl Channel for CallbackOutputChannel {
+    /// 始终无需移除
+    fn need_remove(&self) -> bool {
+        false
+    }
+}
+
+impl OutputChannel for CallbackOutputChannel {
+    fn next_output(&mut self, outputs: &[Output]) {
+        outputs.iter().for_each(&mut self.callback);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    fn outputs(n: usize) -> Vec<Output> {
+        (0..n)
+            .map(|i| Output::INFO {
+                message: i.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ring_buffer() {
+        let mut channel = RingBufferOutputChannel::new(3);
+        channel.next_output(&outputs(5));
+        assert_eq!(channel.len(), 3);
+        // 只保留最近的输出
+        let messages = channel
+            .drain()
+            .iter()
+            .map(|o| o.get_content().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["2", "3", "4"]);
+        assert!(channel.is_empty());
+    }
+
+    #[test]
+    fn callback() {
+        let count = Rc::new(RefCell::new(0));
+        let count_inner = count.clone();
+        let mut channel = CallbackOutputChannel::new(move |_| *count_inner.borrow_mut() += 1);
+        channel.next_output(&outputs(4));
+        assert_eq!(*count.borrow(), 4);
+    }
+
+    /// 写入失败⇒以`ERROR`输出报告，并经推理器发往其它通道
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn file_failed() -> anyhow::Result<()> {
+        use crate::{inference::InferenceEngine, parameters::DEFAULT_PARAMETERS, vm::alpha::RuntimeAlpha};
+        use navm::{cmd::Cmd, vm::VmRuntime};
+        // * 📝写入`/dev/full`总是失败
+        let mut channel = FileOutputChannel::new("/dev/full")?;
+        channel.next_output(&outputs(1));
+        assert!(channel.need_remove());
+        let reports = channel.take_reports();
+        assert!(matches!(reports[..], [Output::ERROR { .. }]), "{reports:?}");
+        // 经运行时转交
+        let mut runtime = RuntimeAlpha::new("test", DEFAULT_PARAMETERS, InferenceEngine::VOID);
+        runtime.add_output_channel(Box::new(FileOutputChannel::new("/dev/full")?));
+        VmRuntime::input_cmd(&mut runtime, Cmd::parse("NSE <A --> B>.")?)?;
+        VmRuntime::input_cmd(&mut runtime, Cmd::parse("NSE <B --> C>.")?)?;
+        let mut errors = vec![];
+        while let Some(output) = runtime.try_fetch_output()? {
+            if let Output::ERROR { description } = output {
+                errors.push(description);
+            }
+        }
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn file() -> std::io::Result<()> {
+        let path = std::env::temp_dir().join("narust_158_file_output_channel_test.jsonl");
+        let _ = std::fs::remove_file(&path);
+        let mut channel = FileOutputChannel::new(&path)?;
+        channel.next_output(&outputs(2));
+        assert!(!channel.need_remove());
+        let content = std::fs::read_to_string(&path)?;
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], Output::INFO { message: "0".into() }.to_json_string());
+        std::fs::remove_file(&path)
+    }
+}
@@ -52,6 +52,12 @@ impl RuntimeAlpha {
                 input_cmds.extend(cmds);
             }
         }
+        // * 🚩🆕转交通道自身的报告
+        for channel_in in channels.iter_mut() {
+            for output in channel_in.take_reports() {
+                self.reasoner.report(output);
+            }
+        }
         // * 🚩放回
         self.io_channels.input_channels.extend(channels);
         // * 🚩返回
@@ -78,6 +84,12 @@ impl RuntimeAlpha {
                 // * 🚩在此过程中解读输出
                 channel_out.next_output(/* self,  */ &outputs);
             }
+            // * 🚩🆕转交通道自身的报告：留待下次处理输出时发出
+            for channel_out in channels.iter_mut() {
+                for output in channel_out.take_reports() {
+                    self.reasoner.report(output);
+                }
+            }
             // * 🚩放回
             self.io_channels.output_channels.extend(channels);
         }
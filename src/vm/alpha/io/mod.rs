@@ -29,9 +29,18 @@ mods! {
     // 输入通道实现
     pub use channel_in;
 
+    // 跟踪文件的输入通道
+    pub use channel_file_tail;
+
     // 输出通道实现
     pub use channel_out;
 
+    // 现成的输出通道：文件、环形缓冲区、回调
+    pub use channel_out_presets;
+
+    // 输出过滤器
+    pub use output_filter;
+
     // IO通道 数据结构
     pub use channels;
 }
@@ -0,0 +1,281 @@
+//! 🆕输出过滤器与「带过滤的输出通道」
+//! * 🎯让不同的输出通道只接收各自关心的输出
+//!   * 📄界面只需要回答，日志需要所有输出
+//! * 🚩在注册通道时附加过滤器：[`RuntimeAlpha::add_output_channel_filtered`]
+
+use super::{Channel, OutputChannel};
+use crate::{
+    global::Float,
+    language::{variable_process::has_unification_q, Term},
+    vm::alpha::RuntimeAlpha,
+};
+use narsese::{api::GetTerm, lexical::Narsese};
+use navm::output::Output;
+
+/// 输出过滤器
+/// * 🎯描述「一个输出通道接收哪些输出」
+/// * 🚩可通过[`and`](Self::and)、[`or`](Self::or)、[`not`](Self::not)组合
+///
+/// ## 例
+///
+/// * 📄只要回答与报错：`OutputFilter::types([ANSWER, ERROR])`
+/// * 📄只要置信度不低于0.5的导出：`OutputFilter::types([OUT]).and(OutputFilter::MinConfidence(0.5))`
+#[derive(Debug, Clone, Default)]
+pub enum OutputFilter {
+    /// 接收所有输出
+    #[default]
+    All,
+
+    /// 只接收指定类型的输出
+    /// * 📌类型名即[`Output::get_type`]，如`ANSWER`、`ERROR`
+    Types(Vec<String>),
+
+    /// 只接收「带真值，且置信度不低于指定值」的输出
+    /// * ⚠️不带真值的输出（如问题、报错）不会通过
+    MinConfidence(Float),
+
+    /// 🆕只接收「带真值，且期望不低于指定值」的输出
+    /// * ⚠️不带真值的输出（如问题、报错）不会通过
+    MinExpectation(Float),
+
+    /// 只接收「Narsese词项能与指定模式归一」的输出
+    /// * 📌模式中的查询变量可匹配任意词项
+    ///   * 📄`<?x --> animal>`可匹配`<robin --> animal>`
+    TermPattern(Term),
+
+    /// 所有过滤器均通过
+    And(Vec<OutputFilter>),
+
+    /// 任一过滤器通过
+    Or(Vec<OutputFilter>),
+
+    /// 过滤器不通过
+    Not(Box<OutputFilter>),
+}
+
+impl OutputFilter {
+    /// 只接收指定类型的输出
+    pub fn types(types: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::Types(types.into_iter().map(Into::into).collect())
+    }
+
+    /// 与另一过滤器同时满足
+    pub fn and(self, other: Self) -> Self {
+        match self {
+            Self::And(mut filters) => {
+                filters.push(other);
+                Self::And(filters)
+            }
+            this => Self::And(vec![this, other]),
+        }
+    }
+
+    /// 满足自身或另一过滤器
+    pub fn or(self, other: Self) -> Self {
+        match self {
+            Self::Or(mut filters) => {
+                filters.push(other);
+                Self::Or(filters)
+            }
+            this => Self::Or(vec![this, other]),
+        }
+    }
+
+    /// 取反
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+
+    /// 判断输出是否通过过滤器
+    pub fn accepts(&self, output: &Output) -> bool {
+        use OutputFilter::*;
+        match self {
+            All => true,
+            Types(types) => types.iter().any(|t| output.is_type(t)),
+            MinConfidence(min) => output_confidence(output).is_some_and(|c| c >= *min),
+            MinExpectation(min) => output_expectation(output).is_some_and(|e| e >= *min),
+            TermPattern(pattern) => output_term(output).is_some_and(|term| {
+                // * 🚩随机种子固定：过滤结果可重复
+                has_unification_q(pattern, &term, 0)
+            }),
+            And(filters) => filters.iter().all(|f| f.accepts(output)),
+            Or(filters) => filters.iter().any(|f| f.accepts(output)),
+            Not(filter) => !filter.accepts(output),
+        }
+    }
+}
+
+/// 获取输出中Narsese的词法真值
+fn output_truth(output: &Output) -> Option<&[String]> {
+    match output.get_narsese()? {
+        Narsese::Sentence(sentence) => Some(&sentence.truth),
+        Narsese::Task(task) => Some(&task.sentence.truth),
+        Narsese::Term(..) => None,
+    }
+}
+
+/// 获取输出中Narsese的置信度
+/// * 🚩词法真值的第二项
+fn output_confidence(output: &Output) -> Option<Float> {
+    output_truth(output)?.get(1)?.parse().ok()
+}
+
+/// 🆕获取输出中Narsese的期望
+/// * 📝期望 = 信度 × (频率 - 0.5) + 0.5
+fn output_expectation(output: &Output) -> Option<Float> {
+    let frequency: Float = output_truth(output)?.first()?.parse().ok()?;
+    let confidence = output_confidence(output)?;
+    Some(confidence * (frequency - 0.5) + 0.5)
+}
+
+/// 获取输出中Narsese的词项
+fn output_term(output: &Output) -> Option<Term> {
+    let term = output.get_narsese()?.get_term().clone();
+    Term::from_lexical(term).ok()
+}
+
+/// 带过滤的输出通道
+/// * 🚩只将通过过滤器的输出转交给内部通道
+/// * 📌「是否需要移除」委托到内部通道
+#[derive(Debug, Clone)]
+pub struct FilteredOutputChannel<C: OutputChannel> {
+    /// 过滤器
+    filter: OutputFilter,
+    /// 内部通道
+    inner: C,
+}
+
+impl<C: OutputChannel> FilteredOutputChannel<C> {
+    /// 构造函数
+    pub fn new(inner: C, filter: OutputFilter) -> Self {
+        Self { filter, inner }
+    }
+}
+
+impl<C: OutputChannel> Channel for FilteredOutputChannel<C> {
+    fn need_remove(&self) -> bool {
+        self.inner.need_remove()
+    }
+
+    /// 报告不经过滤
+    fn take_reports(&mut self) -> Vec<Output> {
+        self.inner.take_reports()
+    }
+}
+
+impl<C: OutputChannel> OutputChannel for FilteredOutputChannel<C> {
+    fn next_output(&mut self, outputs: &[Output]) {
+        let outputs = outputs
+            .iter()
+            .filter(|output| self.filter.accepts(output))
+            .cloned()
+            .collect::<Vec<_>>();
+        // * 🚩全被过滤⇒不打扰内部通道
+        if !outputs.is_empty() {
+            self.inner.next_output(&outputs);
+        }
+    }
+}
+
+/// 为「运行时」扩展方法
+impl RuntimeAlpha {
+    /// 添加带过滤器的输出通道
+    /// * 🎯注册时即指定「通道接收哪些输出」
+    pub fn add_output_channel_filtered(
+        &mut self,
+        channel: impl OutputChannel + 'static,
+        filter: OutputFilter,
+    ) {
+        let channel = FilteredOutputChannel::new(channel, filter);
+        self.add_output_channel(Box::new(channel));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        global::RC,
+        inference::InferenceEngine,
+        ok,
+        parameters::DEFAULT_PARAMETERS,
+        test_term as term,
+        util::AResult,
+        vm::alpha::ChannelOut,
+    };
+    use nar_dev_utils::RefCount;
+    use navm::{
+        cmd::Cmd,
+        output::type_names::{ANSWER, ERROR, IN, OUT},
+        vm::VmRuntime,
+    };
+
+    /// 构造一个带Narsese的输出
+    fn output_out(narsese: &str) -> Output {
+        use narsese::conversion::string::impl_lexical::format_instances::FORMAT_ASCII;
+        Output::OUT {
+            content_raw: narsese.into(),
+            narsese: Some(FORMAT_ASCII.parse(narsese).unwrap()),
+        }
+    }
+
+    #[test]
+    fn accepts() -> AResult {
+        let out = output_out("<robin --> animal>. %1.00;0.81%");
+        let error = Output::ERROR {
+            description: "error".into(),
+        };
+        // 类型
+        let types = OutputFilter::types([ANSWER, ERROR]);
+        assert!(!types.accepts(&out));
+        assert!(types.accepts(&error));
+        // 置信度
+        assert!(OutputFilter::MinConfidence(0.8).accepts(&out));
+        assert!(!OutputFilter::MinConfidence(0.9).accepts(&out));
+        assert!(!OutputFilter::MinConfidence(0.0).accepts(&error));
+        // 期望：0.81 × (1 - 0.5) + 0.5 = 0.905
+        assert!(OutputFilter::MinExpectation(0.9).accepts(&out));
+        assert!(!OutputFilter::MinExpectation(0.91).accepts(&out));
+        assert!(!OutputFilter::MinExpectation(0.0).accepts(&error));
+        // 词项模式
+        assert!(OutputFilter::TermPattern(term!("<?x --> animal>")).accepts(&out));
+        assert!(!OutputFilter::TermPattern(term!("<?x --> bird>")).accepts(&out));
+        // 组合
+        let out_confident = OutputFilter::types([OUT]).and(OutputFilter::MinConfidence(0.5));
+        assert!(out_confident.accepts(&out));
+        assert!(!out_confident.accepts(&error));
+        assert!(out_confident.clone().or(types).accepts(&error));
+        assert!(!out_confident.not().accepts(&out));
+        ok!()
+    }
+
+    /// 注册带过滤器的通道后，只收到所需的输出
+    #[test]
+    fn routing() -> AResult {
+        let mut runtime = RuntimeAlpha::new("test", DEFAULT_PARAMETERS, InferenceEngine::VOID);
+        let mut errors = RC::new_(ChannelOut::new());
+        runtime.add_output_channel_filtered(errors.clone(), OutputFilter::types([ERROR]));
+        VmRuntime::input_cmd(&mut runtime, Cmd::parse("NSE <A --> B>.")?)?;
+        VmRuntime::input_cmd(
+            &mut runtime,
+            Cmd::INF {
+                source: "not_a_query".into(),
+            },
+        )?;
+        // 默认通道：所有输出
+        let mut all = vec![];
+        while let Some(output) = runtime.try_fetch_output()? {
+            all.push(output);
+        }
+        assert!(all.iter().any(|o| o.is_type(IN)));
+        // 过滤的通道：只有报错
+        let mut filtered = vec![];
+        while let Some(output) = errors.mut_().fetch() {
+            filtered.push(output);
+        }
+        assert!(!filtered.is_empty());
+        assert!(filtered.iter().all(|o| o.is_type(ERROR)));
+        ok!()
+    }
+}
@@ -4,7 +4,11 @@
 //! * ✅【2024-05-15 17:01:58】完成初代实现：名称、超参数
 
 use super::RuntimeAlpha;
-use crate::{inference::InferenceEngine, parameters::Parameters};
+use crate::{
+    inference::{BudgetPolicyRef, InferenceEngine, StandardBudget, StandardNal, TruthCalculusRef},
+    language::Term,
+    parameters::Parameters,
+};
 use anyhow::Result;
 use navm::vm::VmLauncher;
 
@@ -19,6 +23,12 @@ pub struct LauncherAlpha {
     hyper_parameters: Parameters,
     /// 推理引擎
     inference_engine: InferenceEngine,
+    /// 真值演算
+    truth_calculus: TruthCalculusRef,
+    /// 预算策略
+    budget_policy: BudgetPolicyRef,
+    /// 订阅的「导出结论」词项模式
+    derived_output_pattern: Option<Term>,
 }
 
 impl LauncherAlpha {
@@ -32,6 +42,36 @@ impl LauncherAlpha {
             name: name.into(),
             hyper_parameters,
             inference_engine,
+            truth_calculus: StandardNal::REF,
+            budget_policy: StandardBudget::REF,
+            derived_output_pattern: None,
+        }
+    }
+
+    /// 🆕指定真值演算
+    /// * 🚩默认为[标准NAL演算](StandardNal)
+    pub fn with_truth_calculus(self, truth_calculus: TruthCalculusRef) -> Self {
+        Self {
+            truth_calculus,
+            ..self
+        }
+    }
+
+    /// 🆕指定预算策略
+    /// * 🚩默认为[标准预算策略](StandardBudget)
+    pub fn with_budget_policy(self, budget_policy: BudgetPolicyRef) -> Self {
+        Self {
+            budget_policy,
+            ..self
+        }
+    }
+
+    /// 🆕指定订阅的「导出结论」词项模式
+    /// * 🚩默认不订阅：报告所有导出结论
+    pub fn with_derived_output_pattern(self, pattern: Term) -> Self {
+        Self {
+            derived_output_pattern: Some(pattern),
+            ..self
         }
     }
 }
@@ -42,7 +82,13 @@ impl VmLauncher for LauncherAlpha {
 
     fn launch(self) -> Result<Self::Runtime> {
         // * 🚩创建新运行时
-        let runtime = RuntimeAlpha::new(self.name, self.hyper_parameters, self.inference_engine);
+        let mut runtime =
+            RuntimeAlpha::new(self.name, self.hyper_parameters, self.inference_engine);
+        runtime.reasoner.set_truth_calculus(self.truth_calculus);
+        runtime.reasoner.set_budget_policy(self.budget_policy);
+        runtime
+            .reasoner
+            .set_derived_output_pattern(self.derived_output_pattern);
         // * 🚩返回
         Ok(runtime)
     }
@@ -14,4 +14,10 @@ mods! {
 
     // 指令分派
     pub use cmd_dispatch;
+
+    // 后台自动步进
+    pub use auto_step;
+
+    // 前端程序共用的指令预解释
+    pub use frontend;
 }
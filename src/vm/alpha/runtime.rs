@@ -58,6 +58,13 @@ impl RuntimeAlpha {
             o_channel,
         }
     }
+
+    /// 获取内部推理器的只读引用
+    /// * 🎯供前端直接查阅推理器状态，而无需经由NAVM指令
+    ///   * 📄交互式终端：以记忆区中的概念补全词项
+    pub fn reasoner(&self) -> &Reasoner {
+        &self.reasoner
+    }
 }
 
 fn default_channels() -> (ReasonerChannels, RC<ChannelIn>, RC<ChannelOut>) {